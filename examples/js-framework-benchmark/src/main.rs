@@ -187,5 +187,6 @@ fn main() {
     #[cfg(debug_assertions)]
     console_error_panic_hook::set_once();
 
+    sycamore::delegate_events(&mount_el, &["click"]);
     sycamore::render_to(App, &mount_el);
 }
@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
 use sycamore::prelude::*;
-use sycamore::web::wasm_bindgen::prelude::*;
 use uuid::Uuid;
 use web_sys::{HtmlInputElement, KeyboardEvent};
 
@@ -11,19 +10,14 @@ pub struct Todo {
     id: Uuid,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Filter {
+    #[default]
     All,
     Active,
     Completed,
 }
 
-impl Default for Filter {
-    fn default() -> Self {
-        Self::All
-    }
-}
-
 impl Filter {
     fn url(self) -> &'static str {
         match self {
@@ -217,18 +211,14 @@ pub fn Item(todo: Signal<Todo>) -> View {
     let id = todo.with(|todo| todo.id);
 
     let is_editing = create_signal(false);
-    let input_ref = create_node_ref();
+    let input_ref = create_node_ref().cast::<HtmlInputElement>();
     let input_value = create_signal("".to_string());
 
     let toggle_completed = move |_| todo.update(|todo| todo.completed = !todo.completed);
 
     let handle_dblclick = move |_| {
         is_editing.set(true);
-        input_ref
-            .get()
-            .unchecked_into::<HtmlInputElement>()
-            .focus()
-            .unwrap();
+        input_ref.get().focus().unwrap();
         input_value.set(title());
     };
 
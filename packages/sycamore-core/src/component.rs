@@ -1,6 +1,7 @@
 //! Utilities for components and component properties.
 
 use std::fmt;
+use std::rc::Rc;
 
 use sycamore_reactive::*;
 
@@ -154,3 +155,76 @@ impl<V> Children<V> {
         Self { f: Box::new(f) }
     }
 }
+
+/// A property type for children that can be rendered more than once.
+///
+/// Unlike [`Children`], which can only be called once, [`ChildrenFn`] can be called any number of
+/// times and can be cheaply cloned. This is useful for components that need to render their
+/// children repeatedly, e.g. a repeater or a tab set that re-creates the content of the active tab.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// #[component(inline_props)]
+/// fn Repeat(count: usize, children: ChildrenFn) -> View {
+///     (0..count).map(|_| children.call()).collect::<Vec<_>>().into()
+/// }
+///
+/// # #[component]
+/// # fn App() -> View {
+/// view! {
+///     Repeat(count=3) {
+///         p { "This is rendered three times." }
+///     }
+/// }
+/// # }
+/// ```
+pub struct ChildrenFn<V> {
+    f: Rc<dyn Fn() -> V>,
+}
+impl<V> fmt::Debug for ChildrenFn<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChildrenFn").finish()
+    }
+}
+
+impl<V> Clone for ChildrenFn<V> {
+    fn clone(&self) -> Self {
+        Self { f: self.f.clone() }
+    }
+}
+
+impl<F, V> From<F> for ChildrenFn<V>
+where
+    F: Fn() -> V + 'static,
+{
+    fn from(f: F) -> Self {
+        Self { f: Rc::new(f) }
+    }
+}
+
+impl<V: Default + 'static> Default for ChildrenFn<V> {
+    fn default() -> Self {
+        Self {
+            f: Rc::new(V::default),
+        }
+    }
+}
+
+impl<V: 'static> From<ChildrenFn<V>> for Children<V> {
+    fn from(children: ChildrenFn<V>) -> Self {
+        Self::new(move || children.call())
+    }
+}
+
+impl<V> ChildrenFn<V> {
+    /// Instantiates a new copy of the child view.
+    pub fn call(&self) -> V {
+        (self.f)()
+    }
+
+    /// Create a new [`ChildrenFn`] from a closure.
+    pub fn new(f: impl Fn() -> V + 'static) -> Self {
+        Self { f: Rc::new(f) }
+    }
+}
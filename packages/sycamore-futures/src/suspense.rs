@@ -37,10 +37,11 @@ impl SuspenseScope {
     pub fn new(parent: Option<SuspenseScope>) -> Self {
         let tasks_remaining = create_signal(0);
         let global = use_global_scope().run_in(|| use_context_or_else(AllTasksRemaining::default));
-        global
-            .all_tasks_remaining
-            .update(|vec| vec.push(tasks_remaining));
-        // TODO: remove self from global if scope is disposed.
+        global.all_tasks_remaining.update(|vec| {
+            // Forget the scopes that have been disposed in the meantime.
+            vec.retain(|signal| signal.is_alive());
+            vec.push(tasks_remaining);
+        });
         Self {
             tasks_remaining,
             parent: parent.map(create_signal),
@@ -206,14 +207,15 @@ pub fn use_is_loading() -> ReadSignal<bool> {
 ///
 /// This is unlike [`use_is_loading`] in that it can be called outside of a suspense scope and does
 /// not apply to any suspense scope in particular.
+///
+/// When called inside an effect, the effect is also re-run when suspense scopes are created later
+/// on.
 pub fn use_is_loading_global() -> bool {
-    if let Some(global) = try_use_context::<AllTasksRemaining>() {
-        global
-            .all_tasks_remaining
-            .with(|vec| vec.iter().any(|signal| signal.get() > 0))
-    } else {
-        false
-    }
+    let global = use_global_scope().run_in(|| use_context_or_else(AllTasksRemaining::default));
+    global.all_tasks_remaining.with(|vec| {
+        vec.iter()
+            .any(|signal| signal.is_alive() && signal.get() > 0)
+    })
 }
 
 #[cfg(test)]
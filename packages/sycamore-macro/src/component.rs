@@ -210,6 +210,8 @@ impl ToTokens for ComponentFn {
                     ::sycamore::rt::WrapAsync(move || #inner_ident(#(#args),*))
                 }
             });
+        } else if cfg!(feature = "hot-reload") && sig.generics.params.is_empty() {
+            tokens.extend(hot_reload_component(f));
         } else {
             tokens.extend(quote! {
                 #[allow(non_snake_case)]
@@ -219,6 +221,76 @@ impl ToTokens for ComponentFn {
     }
 }
 
+/// Wraps the body of a component so that it is registered for hot reloading in development
+/// builds. Generic components are not supported because the registry needs a single function
+/// pointer per component.
+fn hot_reload_component(f: &ItemFn) -> TokenStream {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = f;
+    let ident = &sig.ident;
+    // The props are passed on to the body, so the pattern of the props is only needed there.
+    let inputs = sig
+        .inputs
+        .iter()
+        .map(|input| -> FnArg {
+            match input {
+                FnArg::Typed(pat_type) => {
+                    let ty = &pat_type.ty;
+                    parse_quote! { __props: #ty }
+                }
+                FnArg::Receiver(_) => unreachable!("components can't accept a receiver"),
+            }
+        })
+        .collect::<Punctuated<FnArg, Token![,]>>();
+    let args = inputs
+        .iter()
+        .map(|_| quote! { __props })
+        .collect::<Vec<_>>();
+    let outer_sig = Signature {
+        inputs,
+        ..sig.clone()
+    };
+    let body_ident = format_ident!("{}_body", ident);
+    let body_sig = Signature {
+        ident: body_ident.clone(),
+        ..sig.clone()
+    };
+    // Components without props are registered as taking `()` as props.
+    let (props, call) = match args.first() {
+        Some(arg) => (quote! { #arg }, quote! { |props| #body_ident(props) }),
+        None => (quote! { () }, quote! { |()| #body_ident() }),
+    };
+
+    quote! {
+        #(#attrs)*
+        #[allow(non_snake_case)]
+        #vis #outer_sig {
+            #[allow(non_snake_case)]
+            #body_sig #block
+
+            if ::std::cfg!(debug_assertions) {
+                #[allow(unused_imports)]
+                use ::sycamore::rt::hot_reload::{RenderComponent as _, RerunComponent as _};
+                let component = ::sycamore::rt::hot_reload::HotComponent::new(
+                    ::std::concat!(::std::module_path!(), "::", ::std::stringify!(#ident)),
+                    #call,
+                    #props,
+                );
+                // The reference selects `RenderComponent` if the props do not implement `Clone`.
+                #[allow(clippy::needless_borrow)]
+                let view = (&component).render();
+                ::std::convert::Into::into(view)
+            } else {
+                #body_ident(#(#args),*)
+            }
+        }
+    }
+}
+
 /// Arguments to the `component` attribute proc-macro.
 pub struct ComponentArgs {
     inline_props: Option<Ident>,
@@ -0,0 +1,21 @@
+//! The `css!` macro implementation.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use sycamore_view_parser::css::scope_css;
+use syn::{Error, Ident, LitStr, Result};
+
+pub fn css_impl(input: &LitStr) -> Result<TokenStream> {
+    let scoped = scope_css(&input.value()).map_err(|err| Error::new(input.span(), err))?;
+    let attribute = scoped.attribute;
+    let css = scoped.css;
+    let vars = scoped.vars.iter().map(|(name, property)| {
+        let ident = Ident::new(name, input.span());
+        quote! {
+            (#property, ::std::convert::Into::<::sycamore::web::StringAttribute>::into(#ident))
+        }
+    });
+    Ok(quote! {
+        ::sycamore::web::ScopedCss::new(#attribute, #css, ::std::vec![#(#vars),*])
+    })
+}
@@ -0,0 +1,74 @@
+//! The `CustomElementProps` derive macro implementation.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Error, Result};
+
+pub fn impl_derive_custom_element_props(ast: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            syn::Fields::Unnamed(_) => {
+                return Err(Error::new(
+                    ast.span(),
+                    "CustomElementProps is not supported for tuple structs",
+                ));
+            }
+            syn::Fields::Unit => {
+                return Err(Error::new(
+                    ast.span(),
+                    "CustomElementProps is not supported for unit structs",
+                ));
+            }
+        },
+        syn::Data::Enum(_) => {
+            return Err(Error::new(
+                ast.span(),
+                "CustomElementProps is not supported for enums",
+            ));
+        }
+        syn::Data::Union(_) => {
+            return Err(Error::new(
+                ast.span(),
+                "CustomElementProps is not supported for unions",
+            ));
+        }
+    };
+    if !ast.generics.params.is_empty() {
+        return Err(Error::new(
+            ast.generics.span(),
+            "CustomElementProps is not supported for generic structs",
+        ));
+    }
+
+    let name = &ast.ident;
+    let mut attribute_names = Vec::new();
+    let mut values = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let ident = field.ident.as_ref().unwrap();
+        // Attribute names are case-insensitive and conventionally use hyphens.
+        attribute_names.push(ident.unraw().to_string().replace('_', "-"));
+        values.push(quote! {
+            #ident: ::sycamore::web::FromAttribute::from_attribute(attributes[#i])
+        });
+    }
+
+    Ok(quote! {
+        impl ::sycamore::web::CustomElementProps for #name {
+            const OBSERVED_ATTRIBUTES: &'static [&'static ::std::primitive::str] =
+                &[#(#attribute_names),*];
+
+            fn from_attributes(
+                attributes: &[::sycamore::reactive::ReadSignal<
+                    ::std::option::Option<::std::string::String>,
+                >],
+            ) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,110 @@
+//! The `Form` derive macro implementation.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Attribute, DeriveInput, Error, Expr, Result};
+
+pub fn impl_derive_form(ast: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            syn::Fields::Unnamed(_) => {
+                return Err(Error::new(
+                    ast.span(),
+                    "Form is not supported for tuple structs",
+                ));
+            }
+            syn::Fields::Unit => {
+                return Err(Error::new(
+                    ast.span(),
+                    "Form is not supported for unit structs",
+                ));
+            }
+        },
+        syn::Data::Enum(_) => {
+            return Err(Error::new(ast.span(), "Form is not supported for enums"));
+        }
+        syn::Data::Union(_) => {
+            return Err(Error::new(ast.span(), "Form is not supported for unions"));
+        }
+    };
+    if !ast.generics.params.is_empty() {
+        return Err(Error::new(
+            ast.generics.span(),
+            "Form is not supported for generic structs",
+        ));
+    }
+
+    let vis = &ast.vis;
+    let name = &ast.ident;
+    let fields_name = format_ident!("{name}Fields");
+    let doc = format!("The fields of [`{name}`]. This is generated by the `Form` derive macro.");
+
+    let mut field_defs = Vec::new();
+    let mut create_fields = Vec::new();
+    let mut values = Vec::new();
+    let mut boxed_fields = Vec::new();
+    for field in fields {
+        let field_vis = &field.vis;
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let field_name = ident.unraw().to_string();
+        let validators = parse_validators(&field.attrs)?;
+
+        field_defs.push(quote! { #field_vis #ident: ::sycamore::web::Field<#ty> });
+        create_fields.push(quote! {
+            #ident: ::sycamore::web::Field::new(#field_name, initial.#ident)
+                #(.add_validator(#validators))*
+        });
+        values.push(quote! { #ident: fields.#ident.get() });
+        boxed_fields.push(quote! { ::std::boxed::Box::new(fields.#ident) });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #vis struct #fields_name {
+            #(#field_defs,)*
+        }
+
+        impl ::sycamore::web::Form for #name {
+            type Fields = #fields_name;
+
+            fn create_fields(initial: Self) -> Self::Fields {
+                #fields_name {
+                    #(#create_fields,)*
+                }
+            }
+
+            fn values(fields: &Self::Fields) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+
+            fn fields(
+                fields: &Self::Fields,
+            ) -> ::std::vec::Vec<::std::boxed::Box<dyn ::sycamore::web::FormField>> {
+                ::std::vec![#(#boxed_fields),*]
+            }
+        }
+    })
+}
+
+/// Parses the validators in the `#[form(validate = ...)]` attributes of a field.
+fn parse_validators(attrs: &[Attribute]) -> Result<Vec<Expr>> {
+    let mut validators = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("validate") {
+                validators.push(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown form attribute, expected `validate`"))
+            }
+        })?;
+    }
+    Ok(validators)
+}
@@ -8,6 +8,9 @@ use quote::quote;
 use syn::{DeriveInput, parse_macro_input};
 
 mod component;
+mod css;
+mod custom_element;
+mod form;
 mod inline_props;
 mod props;
 
@@ -17,9 +20,102 @@ mod props;
 /// macro](https://sycamore.dev/book/guide/view-dsl) in the Sycamore Book.
 #[proc_macro]
 pub fn view(input: TokenStream) -> TokenStream {
-    let root = parse_macro_input!(input as sycamore_view_parser::ir::Root);
+    let input = parse_macro_input!(input as sycamore_view_parser::ir::ViewInput);
+    expand_view(&input.root, input.css.as_ref()).into()
+}
 
-    sycamore_view_parser::codegen::Codegen {}.root(&root).into()
+/// Validate and generate the code for a view. This is shared between all the view syntaxes.
+fn expand_view(
+    root: &sycamore_view_parser::ir::Root,
+    css: Option<&syn::Expr>,
+) -> proc_macro2::TokenStream {
+    if let Err(err) =
+        sycamore_view_parser::validate::validate(root, cfg!(feature = "check-nesting"))
+    {
+        return err.to_compile_error();
+    }
+    let mode = if cfg!(feature = "template") {
+        sycamore_view_parser::codegen::Mode::Template
+    } else {
+        sycamore_view_parser::codegen::Mode::Builder
+    };
+    let codegen = sycamore_view_parser::codegen::Codegen {
+        mode,
+        prerender: true,
+        scoped_css: false,
+    };
+    match css {
+        Some(css) => codegen.scoped_root(css, root),
+        None => codegen.root(root),
+    }
+}
+
+/// A macro for creating views using an HTML-like syntax.
+///
+/// This is an alternative syntax for the [`view!`] macro with the same semantics. Text can be
+/// written as in HTML or as a string literal, and expressions are interpolated with braces.
+///
+/// Unquoted text must consist of valid Rust tokens. Text with quotes, `<` or `{`, or that starts
+/// with `if`, `match` or `for`, must be written as a string literal instead.
+///
+/// Rust tokens do not carry the whitespace between them, so the whitespace in unquoted text is
+/// recovered from the source locations of the tokens and collapsed into a single space. This only
+/// works if the tokens come from the source file: tokens that are passed in through another
+/// macro, e.g. a `macro_rules!` fragment, may not have useful locations, in which case spaces can
+/// go missing or be added between words. Use string literals to control the whitespace exactly.
+///
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn App() -> View {
+/// let name = "World";
+/// html! {
+///     <div class="container">
+///         <p>Hello, {name}!</p>
+///         <p>"Hello, " {name} "!"</p>
+///         <input r#type="checkbox" checked />
+///     </div>
+/// }
+/// # }
+/// ```
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let root = parse_macro_input!(input as sycamore_view_parser::html::HtmlRoot);
+    expand_view(&root.0, None).into()
+}
+
+/// A macro for writing CSS that only applies to the elements of the views it is attached to.
+///
+/// The macro takes a string literal of CSS and returns a `ScopedCss`. Every selector is scoped
+/// with an attribute that is derived from a hash of the CSS. The CSS is applied to a view with a
+/// `#![css(...)]` attribute at the start of the `view!` macro, which adds the attribute to every
+/// element of the view. The elements of child components are not affected, but the children that
+/// are passed to them are.
+///
+/// Selectors wrapped in `:global(...)` are not scoped. `$name` inserts the value of the local
+/// variable `name` as the custom property `--name`, which is set on the top-level elements of the
+/// view. The variable can be anything that converts into a `StringAttribute`, e.g. a
+/// `Signal<String>` or a closure returning a `String`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// #[component]
+/// fn Button() -> View {
+///     let color = create_signal("tomato".to_string());
+///     let styles = css!("button { color: $color; } button:hover { opacity: 0.8; }");
+///     view! {
+///         #![css(styles)]
+///         button(on:click=move |_| color.set("teal".to_string())) { "Click me" }
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::LitStr);
+
+    css::css_impl(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
 
 /// A macro for creating components from functions.
@@ -55,6 +151,30 @@ pub fn derive_props(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// The derive macro for `Form`. The macro creates a struct with a `Field` for every field, which
+/// is used by `create_form`.
+///
+/// Validators can be added to a field with `#[form(validate = path::to::validator)]`.
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    form::impl_derive_form(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// The derive macro for `CustomElementProps`. Every field of the struct is set from an attribute
+/// of the custom element, whose name is the name of the field with `_` replaced by `-`.
+#[proc_macro_derive(CustomElementProps)]
+pub fn derive_custom_element_props(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    custom_element::impl_derive_custom_element_props(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// A macro for feature gating code that should only be run on the server.
 ///
 /// By default, the target is used to determine the rendering mode. However, `--cfg
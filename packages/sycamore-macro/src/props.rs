@@ -257,6 +257,14 @@ mod struct_info {
                     impl #b_generics_impl ::sycamore::web::#base_trait_ident for #builder_name #b_generics_ty #b_generics_where {}
                     impl #b_generics_impl ::sycamore::web::tags::#tag_trait_ident for #builder_name #b_generics_ty #b_generics_where {}
 
+                    impl #b_generics_impl #builder_name #b_generics_ty #b_generics_where {
+                        /// Forward the attributes to the `attributes` prop of this component.
+                        pub fn spread(mut self, attributes: ::sycamore::web::Attributes) -> Self {
+                            self.attributes.merge(attributes);
+                            self
+                        }
+                    }
+
                     impl #b_generics_impl ::sycamore::web::SetAttribute for #builder_name #b_generics_ty #b_generics_where {
                         fn set_attribute(&mut self, name: &'static ::std::primitive::str, value: impl ::sycamore::web::AttributeValue) {
                             self.attributes.set_attribute(name, value);
@@ -268,6 +276,14 @@ mod struct_info {
                         ) {
                             self.attributes.set_event_handler(name, handler);
                         }
+                        fn set_event_handler_with_options(
+                            &mut self,
+                            name: &'static ::std::primitive::str,
+                            options: ::sycamore::web::events::EventOptions,
+                            handler: impl ::std::ops::FnMut(::sycamore::rt::Event) + 'static,
+                        ) {
+                            self.attributes.set_event_handler_with_options(name, options, handler);
+                        }
                     }
                 }
             } else {
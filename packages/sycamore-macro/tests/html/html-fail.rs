@@ -0,0 +1,12 @@
+use sycamore::prelude::*;
+
+fn compile_fail() {
+    let _ = create_root(|| {
+        let _: View = html! { <div> };
+        let _: View = html! { <div></span> };
+        let _: View = html! { <div class=my_class></div> };
+        let _: View = html! { <p>Hello (and {"world"})</p> };
+    });
+}
+
+fn main() {}
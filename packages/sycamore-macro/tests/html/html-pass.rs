@@ -0,0 +1,65 @@
+use sycamore::prelude::*;
+
+#[component(inline_props)]
+fn Counter(initial: i32, children: Children) -> View {
+    view! { (initial) (children) }
+}
+
+fn compile_pass() {
+    let _ = create_root(|| {
+        let _: View = html! { "Raw text nodes!" };
+        let _: View = html! { <p>Unquoted text, with <b>nested</b> elements.</p> };
+
+        let _: View = html! {
+            <p>"First"</p>
+            <p>"Second"</p>
+            "Third"
+        };
+
+        let _: View = html! {
+            <div class="container" id={"my-id"} aria-hidden="true" "custom-attr"="foo">
+                <input r#type="checkbox" checked disabled={false}>
+                <br />
+                <br></br>
+                <custom-element></custom-element>
+                <button on:click={|_| {}} class:active={true} style:color="red">"Click"</button>
+            </div>
+        };
+
+        let count = create_signal(0);
+        let _: View = html! {
+            <p>"Count: " {count.get()}</p>
+        };
+
+        let attributes = Attributes::default();
+        let node_ref = create_node_ref();
+        let _: View = html! { <p {..attributes} r#ref={node_ref} /> };
+
+        let _: View = html! {
+            <Counter initial={1} />
+            <Counter initial={2}>
+                <p>"Child"</p>
+            </Counter>
+        };
+
+        let list = create_signal(vec![1, 2, 3]);
+        let _: View = html! {
+            if count.get() > 0 {
+                <p>"Positive"</p>
+            } else {
+                <p>"Not positive"</p>
+            }
+            match count.get() {
+                0 => { "Zero" }
+                _ => {}
+            }
+            <ul>
+                for item in list.get_clone() key(*item) {
+                    <li>{item}</li>
+                }
+            </ul>
+        };
+    });
+}
+
+fn main() {}
@@ -81,6 +81,19 @@ pub async fn AsyncComponentWithPropsDestructuring(
     children.call()
 }
 
+#[component(inline_props)]
+pub fn ComponentWithChildrenFn(children: ChildrenFn) -> View {
+    (0..2).map(|_| children.call()).collect::<Vec<_>>().into()
+}
+
+#[component(inline_props)]
+pub fn ComponentWithSlots(header: Children, #[prop(default)] footer: ChildrenFn) -> View {
+    view! {
+        header { (header) }
+        footer { (footer.call()) }
+    }
+}
+
 #[derive(Props)]
 pub struct AttributesProps {
     #[prop(attributes(html, input))]
@@ -121,6 +134,20 @@ fn compile_pass() {
         let _: View = view! { ComponentWithChildren() };
         let _: View = view! { ComponentWithChildren() {} };
 
+        let _: View = view! { ComponentWithChildrenFn { div {} } };
+        let _: View = view! { ComponentWithChildrenFn {} };
+        let _: View = view! {
+            ComponentWithSlots {
+                slot:header { h1 { "Header" } }
+                slot:footer { "Footer" }
+            }
+        };
+        let _: View = view! {
+            ComponentWithSlots {
+                slot:header { "Header" }
+            }
+        };
+
         let _: View = view! { AttributesComponent(class="test") {} };
         let str_signal = create_signal(String::new());
         let _: View = view! { AttributesComponent(bind:value=str_signal) {} };
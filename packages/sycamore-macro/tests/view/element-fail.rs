@@ -11,6 +11,9 @@ fn compile_fail() {
         let _: View = view! { div(..unknown_attributes) {} };
         let _: View = view! { button(a.b.c="123") };
         let _: View = view! { button(bind:notbind=todo!()) };
+        let _: View = view! { button(on:click.unknown=|_| {}) };
+        let _: View = view! { input(on:keydown.enter.tab=|_| {}) };
+        let _: View = view! { input(bind:value.once=todo!()) };
         let _: View = view! { * };
 
         let _: View = view! {
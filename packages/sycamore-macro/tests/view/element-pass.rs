@@ -1,5 +1,9 @@
 use sycamore::prelude::*;
 
+mod actions {
+    pub fn focus(_: web_sys::Element, _: bool) {}
+}
+
 fn compile_pass() {
     let _ = create_root(|| {
         let _: View = view! { p {} };
@@ -14,6 +18,35 @@ fn compile_pass() {
 
         let _: View = view! { button(class="my-btn", on:click=|_| {}) };
         let _: View = view! { button(class="my-btn", aria-hidden="true") };
+        let _: View = view! { form(on:submit.prevent_default=|_| {}) };
+        let _: View = view! { div(on:click.self_only.stop_propagation.once=|_| {}) };
+        let _: View = view! { div(on:scroll.passive.capture=|_| {}) };
+        let _: View = view! { input(on:keydown.enter=|_| {}, on:keyup.esc=|_| {}) };
+
+        let active = create_signal(true);
+        let _: View = view! { p(class:active=active.get(), class:is-static=true) };
+        let _: View = view! { p(style:color="red", style:background-color=Some("blue")) };
+        let _: View = view! { p(prop:my-prop=1) };
+
+        let text = create_signal(String::new());
+        let selected = create_signal(Vec::<String>::new());
+        let numbers = create_signal(Vec::<i32>::new());
+        let files = create_signal(Vec::new());
+        let width = create_signal(0.0);
+        let _: View = view! { input(bind:value=text, r#type="text") };
+        let _: View = view! { select(multiple=true, bind:value=selected) };
+        let _: View = view! { input(r#type="radio", bind:group=text, value="a") };
+        let _: View = view! { input(r#type="checkbox", value="1", bind:group=numbers) };
+        let _: View = view! { input(r#type="file", bind:files=files) };
+        let _: View = view! { div(contenteditable="true", bind:textContent=text) };
+        let _: View = view! { div(bind:clientWidth=width, bind:scrollTop=width) };
+
+        fn tooltip(_: web_sys::Element, _: &str) {}
+        fn with_cleanup(_: web_sys::Element, _: i32) -> impl FnOnce() {
+            || {}
+        }
+        let _: View = view! { p(use:tooltip="Tooltip", use:with_cleanup=1) };
+        let _: View = view! { input(use:actions::focus=true, use:crate::actions::focus=false) };
 
         let _: View = view! { p(dangerously_set_inner_html="<span>Test</span>") };
 
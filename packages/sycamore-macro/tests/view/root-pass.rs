@@ -12,6 +12,35 @@ fn compile_pass() {
 
         let spliced = 123;
         let _: View = view! { (spliced) };
+
+        let cond = create_signal(true);
+        let _: View = view! {
+            if cond.get() {
+                "True"
+            } else if !cond.get() {
+                "False"
+            }
+            if spliced > 0 { "Static" } else { "Condition" }
+        };
+
+        let value = create_signal(Some(1));
+        let _: View = view! {
+            match value.get() {
+                Some(n) if n > 1 => { (n) }
+                Some(1) | Some(0) => { "Small" }
+                _ => {}
+            }
+        };
+
+        let list = create_signal(vec![(1, "one"), (2, "two")]);
+        let _: View = view! {
+            for (id, name) in list.get_clone() key(*id) {
+                p { (id) ": " (name) }
+            }
+            for item in vec![1, 2, 3] {
+                (item)
+            }
+        };
     });
 }
 
@@ -0,0 +1,12 @@
+use sycamore::prelude::*;
+
+fn compile_fail() {
+    let _ = create_root(|| {
+        let _: View = view! { a(hreff="/") };
+        let _: View = view! { div(foo="bar") };
+        let _: View = view! { dvi {} };
+        let _: View = html! { <input r#typ="text" /> };
+    });
+}
+
+fn main() {}
@@ -4,6 +4,7 @@
 #![warn(missing_docs)]
 
 mod parser;
+mod rank;
 mod route;
 
 use proc_macro::TokenStream;
@@ -12,7 +13,7 @@ use syn::{DeriveInput, parse_macro_input};
 /// The `Route` procedural macro.
 ///
 /// This macro derives the `Route` trait for the given `enum`.
-#[proc_macro_derive(Route, attributes(to, not_found))]
+#[proc_macro_derive(Route, attributes(to, not_found, lazy, params))]
 pub fn route(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
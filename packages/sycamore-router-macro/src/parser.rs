@@ -2,9 +2,32 @@
 pub enum SegmentAst {
     Param(String),
     DynParam(String),
+    /// A dynamic parameter with a type constraint, e.g. `<id:u32>`.
+    ConstrainedDynParam(String, String),
+    /// A dynamic parameter that can be omitted, e.g. `<id?>`.
+    OptionalDynParam(String),
     DynSegments(String),
 }
 
+impl SegmentAst {
+    /// How specific the segment is. Lower is more specific.
+    ///
+    /// Segments that always match exactly one url segment are more specific than the end of a
+    /// route, which is in turn more specific than segments that can also match no url segments.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::Param(_) => 0,
+            Self::ConstrainedDynParam(..) => 1,
+            Self::DynParam(_) => 2,
+            Self::OptionalDynParam(_) => Self::END_RANK + 1,
+            Self::DynSegments(_) => Self::END_RANK + 2,
+        }
+    }
+
+    /// The rank of the end of a route. See [`SegmentAst::rank`].
+    pub const END_RANK: u8 = 3;
+}
+
 #[derive(Debug)]
 pub struct RoutePathAst {
     pub(crate) segments: Vec<SegmentAst>,
@@ -14,7 +37,7 @@ impl RoutePathAst {
     pub fn dyn_segments(&self) -> Vec<SegmentAst> {
         self.segments
             .iter()
-            .filter(|x| matches!(x, SegmentAst::DynParam(_) | &SegmentAst::DynSegments(_)))
+            .filter(|x| !matches!(x, SegmentAst::Param(_)))
             .cloned()
             .collect()
     }
@@ -39,9 +62,7 @@ pub fn parse_route(i: &str) -> Result<RoutePathAst> {
                     segment[1..segment.len() - 3].to_string(),
                 ));
             } else if segment.ends_with('>') {
-                segments_ast.push(SegmentAst::DynParam(
-                    segment[1..segment.len() - 1].to_string(),
-                ));
+                segments_ast.push(parse_dyn_param(&segment[1..segment.len() - 1])?);
             } else {
                 return Err(ParseError {
                     message: "missing `>` in dynamic segment".to_string(),
@@ -62,6 +83,33 @@ pub fn parse_route(i: &str) -> Result<RoutePathAst> {
     })
 }
 
+/// Parses the inside of a dynamic parameter, i.e. `name`, `name?` or `name:Type`.
+fn parse_dyn_param(i: &str) -> Result<SegmentAst> {
+    match i.split_once(':') {
+        Some((name, ty)) => {
+            let (name, ty) = (name.trim(), ty.trim());
+            if name.ends_with('?') {
+                return Err(ParseError {
+                    message: "optional parameters cannot have a type constraint".to_string(),
+                });
+            }
+            if syn::parse_str::<syn::Type>(ty).is_err() {
+                return Err(ParseError {
+                    message: format!("invalid type constraint `{ty}`"),
+                });
+            }
+            Ok(SegmentAst::ConstrainedDynParam(
+                name.to_string(),
+                ty.to_string(),
+            ))
+        }
+        None => match i.strip_suffix('?') {
+            Some(name) => Ok(SegmentAst::OptionalDynParam(name.to_string())),
+            None => Ok(SegmentAst::DynParam(i.to_string())),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{Expect, expect};
@@ -193,6 +241,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn constrained_dyn_param() {
+        check(
+            "/id/<id:u32>",
+            expect![[r#"
+                RoutePathAst {
+                    segments: [
+                        Param(
+                            "id",
+                        ),
+                        ConstrainedDynParam(
+                            "id",
+                            "u32",
+                        ),
+                    ],
+                }"#]],
+        );
+    }
+
+    #[test]
+    fn optional_dyn_param() {
+        check(
+            "/id/<id?>",
+            expect![[r#"
+                RoutePathAst {
+                    segments: [
+                        Param(
+                            "id",
+                        ),
+                        OptionalDynParam(
+                            "id",
+                        ),
+                    ],
+                }"#]],
+        );
+    }
+
+    #[test]
+    fn optional_constrained_dyn_param() {
+        assert!(parse_route("/id/<id?:u32>").is_err());
+        assert!(parse_route("/id/<id:not a type>").is_err());
+    }
+
     #[test]
     fn dyn_segments() {
         check(
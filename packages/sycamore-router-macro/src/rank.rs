@@ -0,0 +1,145 @@
+//! Ranking of routes by specificity.
+
+use crate::parser::{RoutePathAst, SegmentAst};
+
+/// Returns the key for ranking a route by specificity. Routes with a lower key are more specific
+/// and are matched first.
+///
+/// Segments are compared from left to right using [`SegmentAst::rank`].
+pub fn rank_key(route: &RoutePathAst) -> Vec<u8> {
+    route
+        .segments
+        .iter()
+        .map(SegmentAst::rank)
+        .chain([SegmentAst::END_RANK])
+        .collect()
+}
+
+/// A segment of a sample path that is used for checking whether a route shadows another.
+#[derive(Clone, Copy)]
+enum Sample<'a> {
+    /// A static segment.
+    Literal(&'a str),
+    /// A segment that is only known to satisfy the given type constraint.
+    Typed(&'a str),
+    /// An arbitrary segment that is different from every static segment.
+    Any,
+}
+
+/// Returns whether `route` matches every path that is matched by `other`, which means that `other`
+/// can never match if `route` is tried first.
+///
+/// This is checked by generating sample paths for `other` and checking that `route` matches all of
+/// them. Type constraints are treated conservatively: a constrained segment only matches samples
+/// with the same constraint.
+pub fn shadows(route: &RoutePathAst, other: &RoutePathAst) -> bool {
+    // Samples for dynamic segments need to be longer than any path that `route` can match without
+    // dynamic segments of its own.
+    let max_dyn_segments_len = route.segments.len() + 1;
+    let mut samples = vec![Vec::new()];
+    for segment in &other.segments {
+        samples = samples
+            .into_iter()
+            .flat_map(|sample| {
+                let choices: Vec<Vec<Sample<'_>>> = match segment {
+                    SegmentAst::Param(param) => vec![vec![Sample::Literal(param)]],
+                    SegmentAst::DynParam(_) => vec![vec![Sample::Any]],
+                    SegmentAst::ConstrainedDynParam(_, ty) => vec![vec![Sample::Typed(ty)]],
+                    SegmentAst::OptionalDynParam(_) => vec![Vec::new(), vec![Sample::Any]],
+                    SegmentAst::DynSegments(_) => (0..=max_dyn_segments_len)
+                        .map(|len| vec![Sample::Any; len])
+                        .collect(),
+                };
+                choices.into_iter().map(move |choice| {
+                    let mut sample = sample.clone();
+                    sample.extend(choice);
+                    sample
+                })
+            })
+            .collect();
+    }
+    samples
+        .iter()
+        .all(|sample| matches_sample(&route.segments, sample))
+}
+
+/// Checks whether the `segments` of a route match the sample path.
+fn matches_sample(segments: &[SegmentAst], sample: &[Sample<'_>]) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        return sample.is_empty();
+    };
+    match segment {
+        SegmentAst::Param(param) => match sample.split_first() {
+            Some((Sample::Literal(literal), sample)) if literal == param => {
+                matches_sample(rest, sample)
+            }
+            _ => false,
+        },
+        SegmentAst::DynParam(_) => match sample.split_first() {
+            Some((_, sample)) => matches_sample(rest, sample),
+            None => false,
+        },
+        SegmentAst::ConstrainedDynParam(_, ty) => match sample.split_first() {
+            Some((Sample::Typed(other), sample)) if other == ty => matches_sample(rest, sample),
+            _ => false,
+        },
+        SegmentAst::OptionalDynParam(_) => {
+            matches_sample(rest, sample)
+                || sample
+                    .split_first()
+                    .is_some_and(|(_, sample)| matches_sample(rest, sample))
+        }
+        SegmentAst::DynSegments(_) => {
+            (0..=sample.len()).any(|len| matches_sample(rest, &sample[len..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_route;
+
+    #[track_caller]
+    fn check_shadows(route: &str, other: &str, expected: bool) {
+        let route = parse_route(route).unwrap();
+        let other = parse_route(other).unwrap();
+        assert_eq!(shadows(&route, &other), expected);
+    }
+
+    #[test]
+    fn rank_static_before_dynamic() {
+        let rank = |route| rank_key(&parse_route(route).unwrap());
+        assert!(rank("/about") < rank("/<name>"));
+        assert!(rank("/<id:u32>") < rank("/<name>"));
+        assert!(rank("/<name>") < rank("/<name?>"));
+        assert!(rank("/<name?>") < rank("/<path..>"));
+        assert!(rank("/page") < rank("/page/<path..>"));
+        assert!(rank("/<path..>/edit") < rank("/<path..>"));
+    }
+
+    #[test]
+    fn identical_routes_shadow_each_other() {
+        check_shadows("/<a>", "/<b>", true);
+        check_shadows("/about", "/about", true);
+        check_shadows("/<id:u32>", "/<other:u32>", true);
+    }
+
+    #[test]
+    fn more_general_route_shadows() {
+        check_shadows("/<path..>", "/<a>/<b..>", true);
+        check_shadows("/<a>", "/about", true);
+        check_shadows("/<a>", "/<id:u32>", true);
+        check_shadows("/<a..>", "/<b?>", true);
+    }
+
+    #[test]
+    fn does_not_shadow() {
+        check_shadows("/about", "/<a>", false);
+        check_shadows("/<id:u32>", "/<a>", false);
+        check_shadows("/<id:u32>", "/<id:i64>", false);
+        check_shadows("/<a?>/<b?>", "/<path..>", false);
+        check_shadows("/<a>", "/<a>/<b?>", false);
+        check_shadows("/page", "/page/<path..>", false);
+    }
+}
@@ -1,10 +1,11 @@
 use proc_macro2::TokenStream;
-use quote::{ToTokens, quote, quote_spanned};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{DeriveInput, Fields, Ident, LitStr, Token, Variant};
+use syn::{DeriveInput, Expr, Fields, Ident, LitStr, Token, Variant};
 
 use crate::parser::{RoutePathAst, SegmentAst, parse_route};
+use crate::rank::{rank_key, shadows};
 
 pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
     let mut quoted = TokenStream::new();
@@ -12,6 +13,13 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
     // When the `#[not_found]` handler is found, this will store its name so we can use that as the
     // `Default` implementation
     let mut error_handler_name = None;
+    // Match arms for prefetching the variants marked with `#[lazy(_)]`.
+    let mut prefetch_arms = TokenStream::new();
+    // The route patterns and the code for enumerating the paths of each `#[to(_)]` route.
+    let mut patterns = Vec::new();
+    let mut quote_paths = TokenStream::new();
+    // The `#[to(_)]` routes together with the code for matching them.
+    let mut routes = Vec::new();
 
     match &input.data {
         syn::Data::Enum(de) => {
@@ -24,6 +32,7 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                 let mut route_path_ast = None;
 
                 let mut is_to_route = false;
+                let mut params = None;
 
                 for attr in &variant.attrs {
                     let attr_name = match attr.path().get_ident() {
@@ -44,9 +53,21 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                             };
                             // endregion
                             quote_capture_vars.extend(impl_to(variant, variant_id, &route)?);
-                            route_path_ast = Some(route);
+                            route_path_ast = Some((route, route_litstr.clone()));
+                            patterns.push(route_litstr);
                             is_to_route = true;
                         }
+                        "params" => {
+                            params = Some(attr.parse_args::<syn::Path>()?);
+                        }
+                        "lazy" => {
+                            let lazy_route: Expr = attr.parse_args()?;
+                            prefetch_arms.extend(quote! {
+                                Self::#variant_id { .. } => {
+                                    ::sycamore_router::__prefetch_lazy_route!(#lazy_route);
+                                }
+                            });
+                        }
                         "not_found" => {
                             if error_handler_name.is_some() {
                                 return Err(syn::Error::new(
@@ -69,16 +90,45 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                     }
                 }
                 if is_to_route {
-                    let route_path_ast = route_path_ast.unwrap();
-                    quoted.extend(quote! {
+                    let (route_path_ast, route_litstr) = route_path_ast.unwrap();
+                    quote_paths.extend(impl_paths(variant, &route_path_ast, params)?);
+                    let quote_match = quote! {
                         let __route = #route_path_ast;
                         if let Some(__captures) = __route.match_path(__segments) {
                             // Try to capture variables.
                             #quote_capture_vars
                         }
-                    });
+                    };
+                    routes.push((route_path_ast, route_litstr, quote_match));
+                }
+            }
+
+            // Try the most specific routes first. The sort is stable so routes with the same rank
+            // are tried in declaration order.
+            routes.sort_by_key(|(route, ..)| rank_key(route));
+            for (i, (route, route_litstr, _)) in routes.iter().enumerate() {
+                for (other, other_litstr, _) in &routes[i + 1..] {
+                    if shadows(route, other) {
+                        let message = if shadows(other, route) {
+                            format!(
+                                "route `{}` is ambiguous with route `{}`",
+                                other_litstr.value(),
+                                route_litstr.value()
+                            )
+                        } else {
+                            format!(
+                                "route `{}` is shadowed by route `{}` and can never match",
+                                other_litstr.value(),
+                                route_litstr.value()
+                            )
+                        };
+                        return Err(syn::Error::new(other_litstr.span(), message));
+                    }
                 }
             }
+            for (_, _, quote_match) in routes {
+                quoted.extend(quote_match);
+            }
 
             if error_handler_name.is_none() {
                 return Err(syn::Error::new(
@@ -87,12 +137,36 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                 ));
             }
 
+            let prefetch = if prefetch_arms.is_empty() {
+                TokenStream::new()
+            } else {
+                quote! {
+                    fn prefetch(&self) {
+                        match self {
+                            #prefetch_arms
+                            _ => {}
+                        }
+                    }
+                }
+            };
+
             Ok(quote! {
                 impl ::sycamore_router::Route for #ty_name {
                     fn match_route(&self, __segments: &[&str]) -> Self {
                         #quoted
                         #err_quoted
                     }
+                    #prefetch
+
+                    fn patterns() -> &'static [&'static str] {
+                        &[#(#patterns),*]
+                    }
+
+                    fn paths() -> ::std::vec::Vec<::std::string::String> {
+                        let mut __paths = ::std::vec::Vec::new();
+                        #quote_paths
+                        __paths
+                    }
                 }
                 // We implement `Default` as well here for the `Router`/`RouterBase` distinction (`Router` needs to pass a default `impl Route` to `RouterBase`)
                 impl ::std::default::Default for #ty_name {
@@ -109,6 +183,92 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
     }
 }
 
+/// Generates the code for enumerating the paths of a `#[to(_)]` route. Routes without dynamic
+/// segments have exactly one path. The paths of dynamic routes are enumerated by calling the
+/// function in the `#[params(_)]` attribute, which returns the values of the fields of the variant.
+/// The values must have the types of the fields, which is checked by the compiler. The generated
+/// code panics if a value cannot be used in a path or if the path does not match the variant, e.g.
+/// because the value is not parsed back into the same variant.
+fn impl_paths(
+    variant: &Variant,
+    route: &RoutePathAst,
+    params: Option<syn::Path>,
+) -> Result<TokenStream, syn::Error> {
+    let variant_id = &variant.ident;
+    let dyn_segments = route.dyn_segments();
+    if dyn_segments.is_empty() {
+        if let Some(params) = params {
+            return Err(syn::Error::new(
+                params.span(),
+                "`#[params(_)]` can only be used on routes with dynamic segments",
+            ));
+        }
+        Ok(quote! {
+            __paths.push(#route.to_path::<&str>(&[]).unwrap());
+        })
+    } else if let Some(params) = params {
+        // The fields are in the same order as the dynamic segments, see `impl_to`.
+        let bindings = (0..variant.fields.len())
+            .map(|i| format_ident!("__param{i}"))
+            .collect::<Vec<_>>();
+        let tys = variant.fields.iter().map(|field| &field.ty);
+        // A single value is not wrapped in a tuple.
+        let binding = if bindings.len() == 1 {
+            quote!(#(#bindings: #tys)*)
+        } else {
+            quote!((#(#bindings),*): (#(#tys),*))
+        };
+        let to_params = bindings
+            .iter()
+            .zip(dyn_segments)
+            .map(|(binding, segment)| match segment {
+                SegmentAst::Param(_) => unreachable!("not a dynamic segment"),
+                SegmentAst::DynParam(_) | SegmentAst::ConstrainedDynParam(..) => quote! {
+                    ::sycamore_router::ToParam::to_param(&#binding)
+                },
+                SegmentAst::OptionalDynParam(_) => quote! {
+                    match &#binding {
+                        ::std::option::Option::Some(__value) => {
+                            ::sycamore_router::ToParam::to_param(__value)
+                        }
+                        ::std::option::Option::None => ::std::string::String::new(),
+                    }
+                },
+                SegmentAst::DynSegments(_) => quote! {
+                    ::sycamore_router::ToSegments::to_segments(&#binding).join("/")
+                },
+            });
+        Ok(quote_spanned! {params.span()=>
+            let __route = #route;
+            for __item in #params() {
+                let #binding = __item;
+                let __params = [#(#to_params),*];
+                let ::std::option::Option::Some(__path) = __route.to_path(&__params) else {
+                    ::std::panic!(
+                        "invalid `#[params(_)]` for `{}`: {:?}",
+                        ::std::stringify!(#variant_id),
+                        __params,
+                    );
+                };
+                if !::std::matches!(
+                    ::sycamore_router::Route::match_path(&<Self as ::std::default::Default>::default(), &__path),
+                    Self::#variant_id { .. }
+                ) {
+                    ::std::panic!(
+                        "`#[params(_)]` for `{}` produced the path `{}`, which does not match the route",
+                        ::std::stringify!(#variant_id),
+                        __path,
+                    );
+                }
+                __paths.push(__path);
+            }
+        })
+    } else {
+        // Dynamic routes without `#[params(_)]` are not enumerated.
+        Ok(TokenStream::new())
+    }
+}
+
 /// Implementation for `#[to(_)]` attribute.
 fn impl_to(
     variant: &Variant,
@@ -134,51 +294,28 @@ fn impl_to(
             let mut captures = Vec::new();
 
             for (i, (field, segment)) in f.named.iter().zip(dyn_segments.iter()).enumerate() {
-                match segment {
+                let param = match segment {
                     SegmentAst::Param(_) => unreachable!("not a dynamic segment"),
-                    SegmentAst::DynParam(param) => {
-                        if param != &field.ident.as_ref().unwrap().to_string() {
-                            return Err(syn::Error::new(
-                                field.ident.span(),
-                                format!(
-                                    "capture field name mismatch (expected `{}`, found `{}`)",
-                                    param,
-                                    field.ident.as_ref().unwrap()
-                                ),
-                            ));
-                        }
-                        let param_id: Ident = syn::parse_str(param)?;
-                        captures.push(quote! {
-                            let #param_id = match ::sycamore_router::TryFromParam::try_from_param(
-                                __captures[#i].as_dyn_param().unwrap()
-                            ) {
-                                ::std::option::Option::Some(__value) => __value,
-                                ::std::option::Option::None => break,
-                            };
-                        })
-                    }
-                    SegmentAst::DynSegments(param) => {
-                        if param != &field.ident.as_ref().unwrap().to_string() {
-                            return Err(syn::Error::new(
-                                field.ident.span(),
-                                format!(
-                                    "capture field name mismatch (expected `{}`, found `{}`)",
-                                    param,
-                                    field.ident.as_ref().unwrap()
-                                ),
-                            ));
-                        }
-                        let param_id: Ident = syn::parse_str(param)?;
-                        captures.push(quote! {
-                            let #param_id = match ::sycamore_router::TryFromSegments::try_from_segments(
-                                __captures[#i].as_dyn_segments().unwrap()
-                            ) {
-                                ::std::option::Option::Some(__value) => __value,
-                                ::std::option::Option::None => break,
-                            };
-                        })
-                    }
+                    SegmentAst::DynParam(param)
+                    | SegmentAst::ConstrainedDynParam(param, _)
+                    | SegmentAst::OptionalDynParam(param)
+                    | SegmentAst::DynSegments(param) => param,
+                };
+                if param != &field.ident.as_ref().unwrap().to_string() {
+                    return Err(syn::Error::new(
+                        field.ident.span(),
+                        format!(
+                            "capture field name mismatch (expected `{}`, found `{}`)",
+                            param,
+                            field.ident.as_ref().unwrap()
+                        ),
+                    ));
                 }
+                let param_id: Ident = syn::parse_str(param)?;
+                let value = capture_value(i, segment);
+                captures.push(quote! {
+                    let #param_id = #value;
+                })
             }
             let named: Punctuated<&Option<Ident>, Token![,]> =
                 f.named.iter().map(|x| &x.ident).collect();
@@ -198,25 +335,7 @@ fn impl_to(
             let mut captures = Vec::new();
 
             for (i, segment) in dyn_segments.iter().enumerate() {
-                match segment {
-                    SegmentAst::Param(_) => unreachable!("not a dynamic segment"),
-                    SegmentAst::DynParam(_) => captures.push(quote! {{
-                        match ::sycamore_router::TryFromParam::try_from_param(
-                            __captures[#i].as_dyn_param().unwrap()
-                        ) {
-                            ::std::option::Option::Some(__value) => __value,
-                            ::std::option::Option::None => break,
-                        }
-                    }}),
-                    SegmentAst::DynSegments(_) => captures.push(quote! {{
-                        match ::sycamore_router::TryFromSegments::try_from_segments(
-                            __captures[#i].as_dyn_segments().unwrap()
-                        ) {
-                            ::std::option::Option::Some(__value) => __value,
-                            ::std::option::Option::None => break,
-                        }
-                    }}),
-                }
+                captures.push(capture_value(i, segment));
             }
             quote! {
                 // Run captures inside a loop in order to allow early break inside the expression.
@@ -231,6 +350,43 @@ fn impl_to(
     })
 }
 
+/// Generates the expression that converts the capture at index `i` into the value of the field.
+/// The expression `break`s out of the surrounding loop if the conversion fails.
+fn capture_value(i: usize, segment: &SegmentAst) -> TokenStream {
+    match segment {
+        SegmentAst::Param(_) => unreachable!("not a dynamic segment"),
+        SegmentAst::DynParam(_) | SegmentAst::ConstrainedDynParam(..) => quote! {
+            match ::sycamore_router::TryFromParam::try_from_param(
+                __captures[#i].as_dyn_param().unwrap()
+            ) {
+                ::std::option::Option::Some(__value) => __value,
+                ::std::option::Option::None => break,
+            }
+        },
+        SegmentAst::OptionalDynParam(_) => quote! {
+            match __captures[#i].as_optional_dyn_param().unwrap() {
+                ::std::option::Option::Some(__param) => {
+                    match ::sycamore_router::TryFromParam::try_from_param(__param) {
+                        ::std::option::Option::Some(__value) => {
+                            ::std::option::Option::Some(__value)
+                        }
+                        ::std::option::Option::None => break,
+                    }
+                }
+                ::std::option::Option::None => ::std::option::Option::None,
+            }
+        },
+        SegmentAst::DynSegments(_) => quote! {
+            match ::sycamore_router::TryFromSegments::try_from_segments(
+                __captures[#i].as_dyn_segments().unwrap()
+            ) {
+                ::std::option::Option::Some(__value) => __value,
+                ::std::option::Option::None => break,
+            }
+        },
+    }
+}
+
 impl ToTokens for SegmentAst {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
@@ -240,6 +396,18 @@ impl ToTokens for SegmentAst {
             SegmentAst::DynParam(_) => tokens.extend(quote! {
                 ::sycamore_router::Segment::DynParam
             }),
+            SegmentAst::ConstrainedDynParam(_, ty) => {
+                let ty: syn::Type = syn::parse_str(ty).expect("type is checked by the parser");
+                tokens.extend(quote! {
+                    ::sycamore_router::Segment::ConstrainedDynParam(|__param: &str| {
+                        <#ty as ::sycamore_router::TryFromParam>::try_from_param(__param)
+                            .is_some()
+                    })
+                })
+            }
+            SegmentAst::OptionalDynParam(_) => tokens.extend(quote! {
+                ::sycamore_router::Segment::OptionalDynParam
+            }),
             SegmentAst::DynSegments(_) => tokens.extend(quote! {
                 ::sycamore_router::Segment::DynSegments
             }),
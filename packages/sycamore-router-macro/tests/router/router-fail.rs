@@ -45,4 +45,45 @@ enum Routes7 {
     NotFound,
 }
 
+#[derive(Route)]
+enum Routes8 {
+    #[to("/account/<id>")]
+    Id { id: u32 },
+    #[to("/account/<name>")]
+    Name { name: String }, // Ambiguous with `Id`
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route)]
+enum Routes9 {
+    #[to("/<page?>/<rest..>")]
+    Page { page: Option<u32>, rest: Vec<String> },
+    #[to("/<path..>/edit")]
+    Edit { path: Vec<String> }, // Shadowed by `Page`
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route)]
+enum Routes10 {
+    #[to("/<id?:u32>")]
+    Path { id: Option<u32> }, // Optional params cannot be constrained
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route)]
+enum Routes11 {
+    #[to("/post/<id>")]
+    #[params(post_slugs)]
+    Post { id: u32 }, // The params must have the types of the fields
+    #[not_found]
+    NotFound,
+}
+
+fn post_slugs() -> Vec<&'static str> {
+    vec!["hello-world"]
+}
+
 fn main() {}
@@ -36,4 +36,27 @@ enum Routes4 {
     NotFound,
 }
 
+#[derive(Route)]
+enum Routes5 {
+    #[to("/hello/<name>/<age>")]
+    #[params(hello_params)]
+    Hello(String, u32),
+    #[to("/docs/<version?>/<path..>")]
+    #[params(docs_params)]
+    Docs {
+        version: Option<u32>,
+        path: Vec<String>,
+    },
+    #[not_found]
+    NotFound,
+}
+
+fn hello_params() -> Vec<(String, u32)> {
+    vec![("world".to_string(), 42)]
+}
+
+fn docs_params() -> Vec<(Option<u32>, Vec<String>)> {
+    vec![(None, vec!["intro".to_string()]), (Some(2), Vec::new())]
+}
+
 fn main() {}
@@ -0,0 +1,171 @@
+//! Lazily loaded route views.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use sycamore::futures::spawn_local;
+use sycamore::prelude::*;
+use sycamore::web::WrapAsync;
+
+thread_local! {
+    /// All the lazy routes that have been created, keyed by the key that was passed to
+    /// [`lazy_route`]. This makes sure that every call with the same key shares the same loaded
+    /// view.
+    static LAZY_ROUTES: RefCell<HashMap<String, LazyRoute>> = RefCell::default();
+}
+
+/// A route view that is produced by an async loader. Create one with [`lazy_route`].
+///
+/// The loader only runs once, either when the view is first rendered or when the route is
+/// prefetched, whichever happens first.
+#[derive(Clone)]
+pub struct LazyRoute {
+    future: Shared<LocalBoxFuture<'static, Rc<dyn Fn() -> View>>>,
+    started: Rc<Cell<bool>>,
+}
+
+impl std::fmt::Debug for LazyRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyRoute")
+            .field("is_loaded", &self.is_loaded())
+            .finish()
+    }
+}
+
+impl LazyRoute {
+    /// Starts running the loader in the background if it has not been started yet.
+    pub fn prefetch(&self) {
+        if !self.started.replace(true) {
+            spawn_local(self.future.clone().map(drop));
+        }
+    }
+
+    /// Returns whether the loader has finished running.
+    pub fn is_loaded(&self) -> bool {
+        self.future.peek().is_some()
+    }
+
+    /// Renders the view of the route.
+    ///
+    /// If the loader has not finished yet, this suspends the nearest `Suspense` until the view is
+    /// loaded.
+    pub fn view(&self) -> View {
+        if let Some(f) = self.future.peek() {
+            return f();
+        }
+        self.started.set(true);
+        let future = self.future.clone();
+        WrapAsync(move || async move { (future.await)() })
+    }
+}
+
+/// Creates a [`LazyRoute`] whose view is produced by the async `loader`.
+///
+/// The loader resolves to a function that renders the view. This can be used to split the code of
+/// a route out of the main bundle, e.g. by fetching a separate wasm module or JS snippet inside the
+/// loader.
+///
+/// Every call with the same `key` returns the same [`LazyRoute`], so it is fine to call this
+/// function every time the route is rendered. The loader is only used the first time a key is
+/// seen. The path of the route is usually a good key.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::{LazyRoute, lazy_route};
+/// fn about_page() -> LazyRoute {
+///     lazy_route("/about", || async {
+///         // Fetch the code for the page here.
+///         || view! { "About" }
+///     })
+/// }
+/// ```
+pub fn lazy_route<F, Fut, V>(key: &str, loader: F) -> LazyRoute
+where
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = V> + 'static,
+    V: Fn() -> View + 'static,
+{
+    LAZY_ROUTES.with(|routes| {
+        routes
+            .borrow_mut()
+            .entry(key.to_string())
+            .or_insert_with(|| LazyRoute {
+                future: async move { Rc::new(loader().await) as Rc<dyn Fn() -> View> }
+                    .boxed_local()
+                    .shared(),
+                started: Rc::new(Cell::new(false)),
+            })
+            .clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Route;
+
+    #[tokio::test]
+    async fn lazy_route_renders_inside_suspense() {
+        fn page() -> LazyRoute {
+            lazy_route("/suspense", || async { || view! { "Lazy" } })
+        }
+
+        let ssr = sycamore::render_to_string_await_suspense(|| {
+            view! {
+                sycamore::web::Suspense {
+                    (page().view())
+                }
+            }
+        })
+        .await;
+        assert!(ssr.contains("Lazy"));
+        assert!(page().is_loaded());
+    }
+
+    #[tokio::test]
+    async fn prefetch_lazy_variant() {
+        fn page() -> LazyRoute {
+            lazy_route("/lazy", || async { || view! { "Lazy" } })
+        }
+
+        #[derive(Route)]
+        enum Routes {
+            #[to("/lazy")]
+            #[lazy(page())]
+            Lazy,
+            #[not_found]
+            NotFound,
+        }
+
+        sycamore::futures::provide_executor_scope(async {
+            Routes::match_path(&Routes::default(), "/lazy").prefetch();
+            tokio::task::yield_now().await;
+            assert!(page().is_loaded());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn lazy_routes_with_the_same_loader() {
+        // The same closure type is used for both routes, so they must be told apart by their key.
+        fn page(path: &str, text: &'static str) -> LazyRoute {
+            lazy_route(path, move || async move { move || view! { (text) } })
+        }
+
+        let render = |route: LazyRoute| {
+            sycamore::render_to_string_await_suspense(move || {
+                view! {
+                    sycamore::web::Suspense {
+                        (route.view())
+                    }
+                }
+            })
+        };
+        assert!(render(page("/first", "First")).await.contains("First"));
+        assert!(render(page("/second", "Second")).await.contains("Second"));
+    }
+}
@@ -1,4 +1,9 @@
 //! The Sycamore Router.
+//!
+//! ## Feature Flags
+//!
+//! - `suspense` - Enables lazily loaded routes with `lazy_route`. Static site generation with
+//!   `render_static_site` awaits suspense when this is enabled.
 
 #![warn(missing_docs)]
 #![deny(missing_debug_implementations)]
@@ -6,13 +11,43 @@
 // Alias self to sycamore_router for proc-macros.
 extern crate self as sycamore_router;
 
+#[cfg(feature = "suspense")]
+mod lazy;
 mod router;
+mod ssg;
 
 use std::str::FromStr;
 
+#[cfg(feature = "suspense")]
+pub use lazy::*;
 pub use router::*;
+pub use ssg::*;
 pub use sycamore_router_macro::Route;
 
+/// Prefetches a [`LazyRoute`] for a variant that is marked with `#[lazy(_)]`. Used by the derive
+/// macro.
+#[doc(hidden)]
+#[cfg(feature = "suspense")]
+#[macro_export]
+macro_rules! __prefetch_lazy_route {
+    ($route:expr) => {
+        $crate::LazyRoute::prefetch(&($route))
+    };
+}
+
+/// Prefetches a lazy route for a variant that is marked with `#[lazy(_)]`. Used by the derive
+/// macro.
+#[doc(hidden)]
+#[cfg(not(feature = "suspense"))]
+#[macro_export]
+macro_rules! __prefetch_lazy_route {
+    ($route:expr) => {
+        ::std::compile_error!(
+            "`#[lazy]` routes require the `suspense` feature of `sycamore-router`"
+        )
+    };
+}
+
 /// Trait that is implemented for `enum`s that can match routes.
 ///
 /// This trait should not be implemented manually. Use the [`Route`](derive@Route) derive macro
@@ -32,6 +67,31 @@ pub trait Route: Sized + Default {
             .collect::<Vec<_>>();
         self.match_route(&segments)
     }
+
+    /// Starts loading the view of this route ahead of time. This is called by the [`Router`] when
+    /// the pointer enters a link to the route.
+    ///
+    /// The derive macro implements this for variants that are marked with `#[lazy(_)]`.
+    fn prefetch(&self) {}
+
+    /// Returns the patterns of all the routes in declaration order (e.g. `"/account/<id>"`). The
+    /// `#[not_found]` route is not included.
+    fn patterns() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns all the paths that can be matched by this route, e.g. for pre-rendering a static
+    /// site.
+    ///
+    /// Static routes are always included. The paths of dynamic routes are only included if the
+    /// values of the dynamic segments are enumerated with the `#[params(_)]` attribute.
+    ///
+    /// # Panics
+    /// Panics if a value returned by a `#[params(_)]` function cannot be used in a path, e.g.
+    /// because it is `..`, or if the path does not match the route again.
+    fn paths() -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// Represents an URL segment or segments.
@@ -41,6 +101,10 @@ pub enum Segment {
     Param(String),
     /// Match an arbitrary segment that is captured.
     DynParam,
+    /// Match an arbitrary segment that satisfies the constraint. The segment is captured.
+    ConstrainedDynParam(fn(&str) -> bool),
+    /// Match zero or one arbitrary segment that is captured.
+    OptionalDynParam,
     /// Match an arbitrary amount of segments that are captured.
     DynSegments,
 }
@@ -50,6 +114,8 @@ pub enum Segment {
 pub enum Capture<'a> {
     /// A dynamic parameter in the URL (i.e. matches a single url segment).
     DynParam(&'a str),
+    /// An optional dynamic parameter in the URL (i.e. matches zero or one url segment).
+    OptionalDynParam(Option<&'a str>),
     /// A dynamic segment in the URL (i.e. matches multiple url segments).
     DynSegments(Vec<&'a str>),
 }
@@ -64,6 +130,16 @@ impl<'a> Capture<'a> {
         }
     }
 
+    /// Attempts to cast the [`Capture`] to a [`Capture::OptionalDynParam`] with the matched url
+    /// param, if any.
+    pub fn as_optional_dyn_param(&self) -> Option<Option<&'a str>> {
+        if let Self::OptionalDynParam(v) = self {
+            Some(*v)
+        } else {
+            None
+        }
+    }
+
     /// Attempts to cast the [`Capture`] to a [`Capture::DynSegments`] with the matched url params.
     pub fn as_dyn_segments(&self) -> Option<&[&'a str]> {
         if let Self::DynSegments(v) = self {
@@ -94,55 +170,126 @@ impl RoutePath {
             // Get rid of everything after '?' and '#' in the last segment.
             *last = last.split('?').next().unwrap().split('#').next().unwrap();
         }
-        let mut paths = paths.iter();
-        let mut segments = self.segments.iter();
         let mut captures = Vec::new();
+        match_segments(&self.segments, &paths, &mut captures).then_some(captures)
+    }
 
-        while let Some(segment) = segments.next() {
-            match segment {
+    /// Creates a path from the current [`RoutePath`] by filling in the dynamic segments with
+    /// `params`, in order. A [`Segment::DynSegments`] is filled in by a single param which can
+    /// contain `/` characters. The params are percent-encoded.
+    ///
+    /// Returns `None` if the number of params does not match the number of dynamic segments, or if
+    /// a segment of a param is `.` or `..`.
+    pub fn to_path<T: AsRef<str>>(&self, params: &[T]) -> Option<String> {
+        let mut params = params.iter();
+        let mut path = String::new();
+        for segment in &self.segments {
+            let param = match segment {
                 Segment::Param(param) => {
-                    if paths.next() != Some(&param.as_str()) {
-                        return None;
+                    let param = param.trim_matches('/');
+                    if !param.is_empty() {
+                        path.push('/');
+                        path.push_str(param);
                     }
+                    continue;
                 }
-                Segment::DynParam => {
-                    let p = paths.next()?;
-                    captures.push(Capture::DynParam(p));
+                Segment::DynParam | Segment::ConstrainedDynParam(_) | Segment::OptionalDynParam => {
+                    params.next()?.as_ref()
                 }
                 Segment::DynSegments => {
-                    if let Some(next_segment) = segments.next() {
-                        // Capture until match with Segment::Param.
-                        match next_segment {
-                            Segment::Param(next_param) => {
-                                let mut capture = Vec::new();
-                                for next_path in &mut paths {
-                                    if next_path == next_param {
-                                        captures.push(Capture::DynSegments(capture));
-                                        break;
-                                    } else {
-                                        capture.push(next_path);
-                                    }
-                                }
-                            }
-                            _ => unreachable!("segment following DynSegments cannot be dynamic"),
+                    for segment in params.next()?.as_ref().split('/') {
+                        if !segment.is_empty() {
+                            push_param(&mut path, segment)?;
                         }
-                    } else {
-                        // All remaining segments are captured.
-                        let mut capture = Vec::new();
-                        for next_path in &mut paths {
-                            capture.push(*next_path);
-                        }
-                        captures.push(Capture::DynSegments(capture));
                     }
+                    continue;
                 }
+            };
+            if !param.is_empty() {
+                push_param(&mut path, param)?;
             }
         }
+        if params.next().is_some() {
+            return None; // Leftover params.
+        }
+        if path.is_empty() {
+            path.push('/');
+        }
+        Some(path)
+    }
+}
 
-        if paths.next().is_some() {
-            return None; // Leftover segments in paths.
+/// Appends `param` to `path` as a percent-encoded segment. Returns `None` if `param` is `.` or
+/// `..`, since these segments would change the meaning of the path.
+fn push_param(path: &mut String, param: &str) -> Option<()> {
+    if param == "." || param == ".." {
+        return None;
+    }
+    path.push('/');
+    for byte in param.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                path.push(byte as char)
+            }
+            _ => path.push_str(&format!("%{byte:02X}")),
         }
+    }
+    Some(())
+}
 
-        Some(captures)
+/// Matches the `segments` with the `paths`, pushing the captures into `captures`. If the match
+/// fails, `captures` is left unchanged.
+///
+/// Optional parameters try to capture a segment first. Dynamic segments are captured lazily, i.e.
+/// they capture as few segments as possible.
+fn match_segments<'a>(
+    segments: &[Segment],
+    paths: &[&'a str],
+    captures: &mut Vec<Capture<'a>>,
+) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        // Do not match if there are leftover segments in paths.
+        return paths.is_empty();
+    };
+    let mut try_capture = |capture: Option<Capture<'a>>, paths: &[&'a str]| {
+        let len = captures.len();
+        captures.extend(capture);
+        if match_segments(rest, paths, captures) {
+            true
+        } else {
+            captures.truncate(len);
+            false
+        }
+    };
+    match segment {
+        Segment::Param(param) => match paths.split_first() {
+            Some((path, paths)) if path == param => try_capture(None, paths),
+            _ => false,
+        },
+        Segment::DynParam => match paths.split_first() {
+            Some((path, paths)) => try_capture(Some(Capture::DynParam(path)), paths),
+            None => false,
+        },
+        Segment::ConstrainedDynParam(constraint) => match paths.split_first() {
+            Some((path, paths)) if constraint(path) => {
+                try_capture(Some(Capture::DynParam(path)), paths)
+            }
+            _ => false,
+        },
+        Segment::OptionalDynParam => {
+            if let Some((path, paths)) = paths.split_first()
+                && try_capture(Some(Capture::OptionalDynParam(Some(path))), paths)
+            {
+                return true;
+            }
+            try_capture(Some(Capture::OptionalDynParam(None)), paths)
+        }
+        Segment::DynSegments => (0..=paths.len()).any(|len| {
+            try_capture(
+                Some(Capture::DynSegments(paths[..len].to_vec())),
+                &paths[len..],
+            )
+        }),
     }
 }
 
@@ -195,6 +342,39 @@ impl<T: Route> TryFromSegments for T {
     }
 }
 
+/// Conversion of a value into a param. This is the inverse of [`TryFromParam`] and is used to
+/// create the paths of a route from the values returned by its `#[params(_)]` function.
+///
+/// Implemented for all types that implement [`Display`](std::fmt::Display) by default.
+pub trait ToParam {
+    /// Converts the value into a param.
+    fn to_param(&self) -> String;
+}
+
+impl<T> ToParam for T
+where
+    T: std::fmt::Display,
+{
+    fn to_param(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Conversion of a value into a list of segments. This is the inverse of [`TryFromSegments`].
+pub trait ToSegments {
+    /// Converts the value into a list of segments.
+    fn to_segments(&self) -> Vec<String>;
+}
+
+impl<T> ToSegments for Vec<T>
+where
+    T: ToParam,
+{
+    fn to_segments(&self) -> Vec<String> {
+        self.iter().map(ToParam::to_param).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use Segment::*;
@@ -353,6 +533,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dyn_segments_backtrack() {
+        check(
+            "/a/end/end",
+            RoutePath::new(vec![DynSegments, Param("end".to_string())]),
+            Some(vec![Capture::DynSegments(vec!["a", "end"])]),
+        );
+        check(
+            "/a/b",
+            RoutePath::new(vec![DynSegments, Param("end".to_string())]),
+            None,
+        );
+    }
+
+    #[test]
+    fn constrained_dyn_param() {
+        let route = RoutePath::new(vec![
+            Param("id".to_string()),
+            ConstrainedDynParam(|param| param.parse::<u32>().is_ok()),
+        ]);
+        check(
+            "/id/123",
+            route.clone(),
+            Some(vec![Capture::DynParam("123")]),
+        );
+        check("/id/abc", route, None);
+    }
+
+    #[test]
+    fn optional_dyn_param() {
+        let route = RoutePath::new(vec![Param("post".to_string()), OptionalDynParam]);
+        check(
+            "/post/123",
+            route.clone(),
+            Some(vec![Capture::OptionalDynParam(Some("123"))]),
+        );
+        check(
+            "/post",
+            route.clone(),
+            Some(vec![Capture::OptionalDynParam(None)]),
+        );
+        check("/post/123/456", route, None);
+    }
+
+    #[test]
+    fn optional_dyn_param_before_dyn_param() {
+        let route = RoutePath::new(vec![OptionalDynParam, DynParam]);
+        check(
+            "/a/b",
+            route.clone(),
+            Some(vec![
+                Capture::OptionalDynParam(Some("a")),
+                Capture::DynParam("b"),
+            ]),
+        );
+        check(
+            "/b",
+            route,
+            Some(vec![
+                Capture::OptionalDynParam(None),
+                Capture::DynParam("b"),
+            ]),
+        );
+    }
+
     #[test]
     fn ignore_query_params_static() {
         check(
@@ -389,6 +634,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_path() {
+        assert_eq!(
+            RoutePath::new(Vec::new()).to_path::<&str>(&[]),
+            Some("/".to_string())
+        );
+        assert_eq!(
+            RoutePath::new(vec![Param("id".to_string()), DynParam]).to_path(&["123"]),
+            Some("/id/123".to_string())
+        );
+        assert_eq!(
+            RoutePath::new(vec![DynSegments, Param("end".to_string())]).to_path(&["a/b"]),
+            Some("/a/b/end".to_string())
+        );
+        assert_eq!(
+            RoutePath::new(vec![DynSegments, Param("end".to_string())]).to_path(&[""]),
+            Some("/end".to_string())
+        );
+        assert_eq!(
+            RoutePath::new(vec![DynParam]).to_path(&["a b/c?"]),
+            Some("/a%20b%2Fc%3F".to_string())
+        );
+        assert_eq!(
+            RoutePath::new(vec![DynSegments]).to_path(&["caf\u{e9}/x"]),
+            Some("/caf%C3%A9/x".to_string())
+        );
+        assert_eq!(RoutePath::new(vec![DynParam]).to_path::<&str>(&[]), None);
+        assert_eq!(RoutePath::new(vec![]).to_path(&["extra"]), None);
+        assert_eq!(RoutePath::new(vec![DynParam]).to_path(&[".."]), None);
+        assert_eq!(RoutePath::new(vec![DynSegments]).to_path(&["a/../b"]), None);
+    }
+
     mod integration {
         use crate::*;
 
@@ -550,6 +827,125 @@ mod tests {
             );
         }
 
+        #[test]
+        fn router_ranks_by_specificity() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/<path..>")]
+                CatchAll { path: Vec<String> },
+                #[to("/<name>")]
+                Name { name: String },
+                #[to("/<id:u32>")]
+                Id { id: u32 },
+                #[to("/about")]
+                About,
+                #[not_found]
+                NotFound,
+            }
+
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["about"]),
+                Routes::About
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["123"]),
+                Routes::Id { id: 123 }
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["abc"]),
+                Routes::Name {
+                    name: "abc".to_string()
+                }
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["a", "b"]),
+                Routes::CatchAll {
+                    path: vec!["a".to_string(), "b".to_string()]
+                }
+            );
+        }
+
+        #[test]
+        fn router_optional_param() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/posts/<page?>")]
+                Posts { page: Option<u32> },
+                #[to("/tag/<tag>/<page?>")]
+                Tag(String, Option<u32>),
+                #[not_found]
+                NotFound,
+            }
+
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["posts"]),
+                Routes::Posts { page: None }
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["posts", "2"]),
+                Routes::Posts { page: Some(2) }
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["posts", "abc"]),
+                Routes::NotFound
+            );
+            assert_eq!(
+                Routes::match_route(&Routes::default(), &["tag", "rust", "3"]),
+                Routes::Tag("rust".to_string(), Some(3))
+            );
+        }
+
+        #[test]
+        fn router_patterns_and_paths() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/")]
+                Home,
+                #[to("/about")]
+                About,
+                #[to("/account/<id>")]
+                #[params(account_ids)]
+                Account { id: u32 },
+                #[to("/hello/<name>")]
+                Hello { name: String },
+                #[to("/tag/<name>/<page>")]
+                #[params(tag_pages)]
+                Tag { name: String, page: u32 },
+                #[not_found]
+                NotFound,
+            }
+
+            fn account_ids() -> [u32; 2] {
+                [1, 2]
+            }
+
+            fn tag_pages() -> Vec<(String, u32)> {
+                vec![("rust".to_string(), 1), ("a b".to_string(), 2)]
+            }
+
+            assert_eq!(
+                Routes::patterns(),
+                [
+                    "/",
+                    "/about",
+                    "/account/<id>",
+                    "/hello/<name>",
+                    "/tag/<name>/<page>"
+                ]
+            );
+            assert_eq!(
+                Routes::paths(),
+                [
+                    "/",
+                    "/about",
+                    "/account/1",
+                    "/account/2",
+                    "/tag/rust/1",
+                    "/tag/a%20b/2"
+                ]
+            );
+        }
+
         #[test]
         fn nested_router() {
             #[derive(Debug, PartialEq, Eq, Route)]
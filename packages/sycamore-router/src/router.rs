@@ -1,12 +1,15 @@
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::rc::Rc;
 
 use sycamore::prelude::*;
 use wasm_bindgen::prelude::*;
-use web_sys::js_sys::Array;
-use web_sys::{Element, Event, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent, UrlSearchParams};
+use web_sys::js_sys::{Array, Math, Object, Reflect, decode_uri_component};
+use web_sys::{
+    Element, Event, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent, ScrollRestoration,
+    UrlSearchParams,
+};
 
 use crate::Route;
 
@@ -16,28 +19,164 @@ pub trait Integration {
     /// Get the current pathname.
     fn current_pathname(&self) -> String;
 
+    /// Get the current query string, including the leading `?`.
+    ///
+    /// By default, this returns the query string of `window.location`.
+    fn current_search(&self) -> String {
+        window().location().search().unwrap_throw()
+    }
+
+    /// Get the current fragment, including the leading `#`.
+    ///
+    /// By default, this returns the fragment of `window.location`.
+    fn current_hash(&self) -> String {
+        window().location().hash().unwrap_throw()
+    }
+
+    /// Returns whether the location is shared with other routers, e.g. because it is stored in the
+    /// URL of the page. When a router navigates, the other routers with a shared location are
+    /// updated as well.
+    ///
+    /// By default, this returns `true`.
+    fn is_location_shared(&self) -> bool {
+        true
+    }
+
     /// Add a callback for listening to the `popstate` event.
+    ///
+    /// This is called inside the reactive scope of the router. Any listeners that are added should
+    /// be removed when the scope is disposed.
     fn on_popstate(&self, f: Box<dyn FnMut()>);
 
     /// Get the click handler that is run when links are clicked.
     fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)>;
-}
 
-thread_local! {
-    static PATHNAME: Cell<Option<Signal<String>>> = const { Cell::new(None) };
-    static QUERY: Cell<Option<Signal<()>>> = const { Cell::new(None) };
+    /// Get the click handler that is run when links inside the view of `router` are clicked.
+    ///
+    /// By default, this returns the [`click_handler`](Self::click_handler).
+    fn router_click_handler(&self, router: RouterHandle) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        let _ = router;
+        self.click_handler()
+    }
+
+    /// Get the handler that is run when the pointer enters an element inside the router. The
+    /// handler should call `prefetch` with the pathname of the element if it is a link, so that
+    /// the route can be loaded ahead of time.
+    fn hover_handler(&self, prefetch: Rc<dyn Fn(&str)>) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        Box::new(move |ev| {
+            let target = ev.target().unwrap_throw().unchecked_into::<Element>();
+            if target.matches("a[href]").unwrap_or(false) {
+                let a = target.unchecked_into::<HtmlAnchorElement>();
+                if a.rel() != "external" && window().location().origin() == Ok(a.origin()) {
+                    prefetch(&a.pathname());
+                }
+            }
+        })
+    }
+
+    /// Push a new entry with the given `url` onto the history stack.
+    ///
+    /// By default, this uses the
+    /// [HTML5 History API](https://developer.mozilla.org/en-US/docs/Web/API/History_API).
+    fn push_url(&self, url: &str) {
+        let history = window().history().unwrap_throw();
+        history
+            .push_state_with_url(&JsValue::UNDEFINED, "", Some(url))
+            .unwrap_throw();
+    }
+
+    /// Replace the current entry on the history stack with the given `url`.
+    ///
+    /// By default, this uses the
+    /// [HTML5 History API](https://developer.mozilla.org/en-US/docs/Web/API/History_API).
+    fn replace_url(&self, url: &str) {
+        let history = window().history().unwrap_throw();
+        history
+            .replace_state_with_url(&JsValue::UNDEFINED, "", Some(url))
+            .unwrap_throw();
+    }
+
+    /// Save the scroll position of the current page. This is called right before navigating away
+    /// from the current page so that the position can be restored when navigating back to it.
+    fn save_scroll_position(&self) {}
+
+    /// Scroll to the target of the page at `url` once it has been rendered.
+    ///
+    /// This is called on the animation frame after navigating, once the suspense scopes on the page
+    /// have finished loading. By default, this scrolls to the element targeted by the `#fragment`
+    /// of the url or to the top of the page if the url has no fragment.
+    fn scroll_to_target(&self, url: &str) {
+        scroll_to_fragment_or_top(url);
+    }
 }
 
 /// A router integration that uses the
 /// [HTML5 History API](https://developer.mozilla.org/en-US/docs/Web/API/History_API) to keep the
 /// UI in sync with the URL.
+///
+/// Scroll positions are stored in the history state of each entry and restored when navigating
+/// back and forward.
 #[derive(Default, Debug)]
 pub struct HistoryIntegration {
-    /// This field is to prevent downstream users from creating a new `HistoryIntegration` without
-    /// the `new` method.
     _internal: (),
 }
 
+thread_local! {
+    /// The scroll positions of the history entries. These are shared by all the routers that use
+    /// a [`HistoryIntegration`] since they all use the same history.
+    static SCROLL_POSITIONS: ScrollPositions = ScrollPositions::default();
+    /// The number of `popstate` listeners that were added by a [`HistoryIntegration`].
+    static POPSTATE_LISTENERS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Scroll positions of the history entries that were visited, keyed by the history entry key.
+#[derive(Default, Debug)]
+struct ScrollPositions {
+    /// The key of the history entry that is currently displayed.
+    current: Cell<Option<u64>>,
+    saved: RefCell<HashMap<u64, (f64, f64)>>,
+}
+
+impl ScrollPositions {
+    /// Save the current scroll position for the current history entry.
+    fn save(&self) {
+        let position = (
+            window().scroll_x().unwrap_throw(),
+            window().scroll_y().unwrap_throw(),
+        );
+        if let Some(key) = self.current.get() {
+            self.saved.borrow_mut().insert(key, position);
+        }
+    }
+
+    /// Save the scroll position of the history entry that was left in a `popstate` event. Every
+    /// router listens to the event, so this does nothing if the position was already saved.
+    fn save_on_popstate(&self) {
+        let key = history_entry_key();
+        if self.current.get() != Some(key) {
+            self.save();
+            self.current.set(Some(key));
+        }
+    }
+
+    /// Restore the scroll position of the current history entry. If there is no saved position,
+    /// scroll to the target of the current url instead.
+    fn restore(&self) {
+        let key = history_entry_key();
+        self.current.set(Some(key));
+        let position = self
+            .saved
+            .borrow()
+            .get(&key)
+            .copied()
+            .or_else(history_state_scroll_position);
+        match position {
+            Some((x, y)) => window().scroll_to_with_x_and_y(x, y),
+            None => scroll_to_fragment_or_top(&window().location().href().unwrap_throw()),
+        }
+    }
+}
+
 impl HistoryIntegration {
     /// Create a new [`HistoryIntegration`].
     pub fn new() -> Self {
@@ -50,16 +189,280 @@ impl Integration for HistoryIntegration {
         window().location().pathname().unwrap_throw()
     }
 
-    fn on_popstate(&self, f: Box<dyn FnMut()>) {
-        let closure = Closure::wrap(f);
+    fn on_popstate(&self, mut f: Box<dyn FnMut()>) {
+        // Scroll positions are restored by the routers instead of by the browser. The first
+        // listener takes over scroll restoration and the last one hands it back.
+        if POPSTATE_LISTENERS.with(|listeners| listeners.replace(listeners.get() + 1)) == 0 {
+            set_scroll_restoration(ScrollRestoration::Manual);
+            SCROLL_POSITIONS.with(|scroll| scroll.current.set(Some(history_entry_key())));
+        }
+
+        let closure = Closure::<dyn FnMut()>::new(move || {
+            // The browser has not scrolled yet so this is still the position of the previous
+            // page.
+            SCROLL_POSITIONS.with(ScrollPositions::save_on_popstate);
+            f();
+            // All the routers schedule the same pending scroll, so it only happens once.
+            scroll_after_render(|| SCROLL_POSITIONS.with(ScrollPositions::restore));
+        });
         window()
             .add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
             .unwrap_throw();
-        closure.forget();
+        on_cleanup(move || {
+            window()
+                .remove_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
+                .unwrap_throw();
+            if POPSTATE_LISTENERS.with(|listeners| listeners.replace(listeners.get() - 1)) == 1 {
+                set_scroll_restoration(ScrollRestoration::Auto);
+            }
+        });
     }
 
     fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)> {
         Box::new(|ev| {
+            if let Some(router) = current_router() {
+                handle_link_click(&router, ev);
+            }
+        })
+    }
+
+    fn router_click_handler(&self, router: RouterHandle) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        Box::new(move |ev| handle_link_click(&router, ev))
+    }
+
+    fn push_url(&self, url: &str) {
+        let key = new_history_entry_key();
+        window()
+            .history()
+            .unwrap_throw()
+            .push_state_with_url(&history_state(key, None), "", Some(url))
+            .unwrap_throw();
+        SCROLL_POSITIONS.with(|scroll| scroll.current.set(Some(key)));
+    }
+
+    fn replace_url(&self, url: &str) {
+        let key = history_entry_key();
+        window()
+            .history()
+            .unwrap_throw()
+            .replace_state_with_url(&history_state(key, None), "", Some(url))
+            .unwrap_throw();
+        SCROLL_POSITIONS.with(|scroll| scroll.current.set(Some(key)));
+    }
+
+    fn save_scroll_position(&self) {
+        SCROLL_POSITIONS.with(ScrollPositions::save);
+        let position = (
+            window().scroll_x().unwrap_throw(),
+            window().scroll_y().unwrap_throw(),
+        );
+        window()
+            .history()
+            .unwrap_throw()
+            .replace_state(&history_state(history_entry_key(), Some(position)), "")
+            .unwrap_throw();
+    }
+}
+
+/// Handles a click on a link inside the view of a [`Router`] that uses the
+/// [`HistoryIntegration`].
+fn handle_link_click(router: &RouterHandle, ev: web_sys::MouseEvent) {
+    // The link was already handled by a nested router.
+    if ev.default_prevented() {
+        return;
+    }
+    if let Some(a) = ev
+        .target()
+        .unwrap_throw()
+        .unchecked_into::<Element>()
+        .closest("a[href]")
+        .unwrap_throw()
+    {
+        let location = window().location();
+
+        let a = a.unchecked_into::<HtmlAnchorElement>();
+
+        // Check if a has `rel="external"`.
+        if a.rel() == "external" {
+            // Use default browser behavior.
+            return;
+        }
+
+        let origin = a.origin();
+        let a_pathname = a.pathname();
+        let hash = a.hash();
+        let query = a.search();
+
+        let meta_keys_pressed = meta_keys_pressed(ev.unchecked_ref::<KeyboardEvent>());
+        if !meta_keys_pressed && location.origin() == Ok(origin) {
+            if location.pathname().as_ref() != Ok(&a_pathname) {
+                // Same origin, different path. Navigate to new page.
+                ev.prevent_default();
+                router.navigate_with(
+                    &format!("{a_pathname}{query}{hash}"),
+                    NavigateOptions {
+                        scroll: !a.has_attribute("data-noscroll"),
+                        ..Default::default()
+                    },
+                );
+            } else if location.search().as_ref() != Ok(&query) {
+                // Same origin, same pathname, different query.
+                ev.prevent_default();
+                router.navigate_with(
+                    &format!("{a_pathname}{query}{hash}"),
+                    NavigateOptions {
+                        scroll: false,
+                        ..Default::default()
+                    },
+                );
+            } else if location.hash().as_ref() != Ok(&hash) {
+                // Same origin, same pathname, same query, different hash. Use default
+                // browser behavior.
+                if hash.is_empty() {
+                    ev.prevent_default();
+                    router.integration.save_scroll_position();
+                    router.integration.push_url(&a.href());
+                    window()
+                        .dispatch_event(&Event::new("hashchange").unwrap())
+                        .unwrap_throw();
+                }
+            } else {
+                // Same page. Do nothing.
+                ev.prevent_default();
+            }
+        }
+    }
+}
+
+/// Gets the key of the current history entry. If the entry does not have a key yet, a new key is
+/// generated and stored in the history state.
+fn history_entry_key() -> u64 {
+    let history = window().history().unwrap_throw();
+    let state = history.state().unwrap_throw();
+    if !state.is_object() {
+        let key = new_history_entry_key();
+        history
+            .replace_state(&history_state(key, None), "")
+            .unwrap_throw();
+        return key;
+    }
+    match Reflect::get(&state, &"key".into()).unwrap_throw().as_f64() {
+        Some(key) => key as u64,
+        None => {
+            let key = new_history_entry_key();
+            Reflect::set(&state, &"key".into(), &(key as f64).into()).unwrap_throw();
+            history.replace_state(&state, "").unwrap_throw();
+            key
+        }
+    }
+}
+
+/// Generates a new random key for a history entry.
+fn new_history_entry_key() -> u64 {
+    // Keys are stored as JS numbers so they must fit in the 53 bit mantissa.
+    (Math::random() * (1u64 << 53) as f64) as u64
+}
+
+/// Gets the scroll position that is stored in the state of the current history entry.
+fn history_state_scroll_position() -> Option<(f64, f64)> {
+    let state = window().history().unwrap_throw().state().unwrap_throw();
+    if !state.is_object() {
+        return None;
+    }
+    let x = Reflect::get(&state, &"scrollX".into()).ok()?.as_f64()?;
+    let y = Reflect::get(&state, &"scrollY".into()).ok()?.as_f64()?;
+    Some((x, y))
+}
+
+/// Creates the state object that is stored in a history entry.
+fn history_state(key: u64, scroll_position: Option<(f64, f64)>) -> JsValue {
+    let state = Object::new();
+    Reflect::set(&state, &"key".into(), &(key as f64).into()).unwrap_throw();
+    if let Some((x, y)) = scroll_position {
+        Reflect::set(&state, &"scrollX".into(), &x.into()).unwrap_throw();
+        Reflect::set(&state, &"scrollY".into(), &y.into()).unwrap_throw();
+    }
+    state.into()
+}
+
+/// Sets `history.scrollRestoration`.
+fn set_scroll_restoration(value: ScrollRestoration) {
+    window()
+        .history()
+        .unwrap_throw()
+        .set_scroll_restoration(value)
+        .unwrap_throw();
+}
+
+/// Scrolls to the element targeted by the `#fragment` of `url`. If there is no fragment or no
+/// element with a matching `id`, scrolls to the top of the page instead.
+fn scroll_to_fragment_or_top(url: &str) {
+    let target = url
+        .split_once('#')
+        .map(|(_, fragment)| fragment)
+        .filter(|fragment| !fragment.is_empty())
+        .and_then(|fragment| {
+            let fragment = decode_uri_component(fragment)
+                .ok()
+                .and_then(|fragment| fragment.as_string())
+                .unwrap_or_else(|| fragment.to_string());
+            document().get_element_by_id(&fragment)
+        });
+    match target {
+        Some(target) => target.scroll_into_view(),
+        None => window().scroll_to_with_x_and_y(0.0, 0.0),
+    }
+}
+
+/// A router integration that keeps the current route in memory instead of in the URL.
+///
+/// This is useful for routers that should be independent of the page URL, such as a router inside
+/// a modal or a side panel. Links inside the router navigate the router without touching the
+/// browser history.
+#[derive(Debug)]
+pub struct MemoryIntegration {
+    /// The current url, including the query string and the fragment.
+    url: RefCell<String>,
+}
+
+impl MemoryIntegration {
+    /// Create a new [`MemoryIntegration`] that starts at the given `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: RefCell::new(url.into()),
+        }
+    }
+}
+
+impl Integration for MemoryIntegration {
+    fn current_pathname(&self) -> String {
+        split_url(&self.url.borrow()).0.to_string()
+    }
+
+    fn current_search(&self) -> String {
+        split_url(&self.url.borrow()).1.to_string()
+    }
+
+    fn current_hash(&self) -> String {
+        split_url(&self.url.borrow()).2.to_string()
+    }
+
+    fn is_location_shared(&self) -> bool {
+        false
+    }
+
+    fn on_popstate(&self, _f: Box<dyn FnMut()>) {}
+
+    fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        // A memory router only handles the links inside of its own view.
+        Box::new(|_| {})
+    }
+
+    fn router_click_handler(&self, router: RouterHandle) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        Box::new(move |ev| {
+            if ev.default_prevented() {
+                return;
+            }
             if let Some(a) = ev
                 .target()
                 .unwrap_throw()
@@ -67,80 +470,43 @@ impl Integration for HistoryIntegration {
                 .closest("a[href]")
                 .unwrap_throw()
             {
-                let location = window().location();
-
                 let a = a.unchecked_into::<HtmlAnchorElement>();
-
-                // Check if a has `rel="external"`.
                 if a.rel() == "external" {
-                    // Use default browser behavior.
                     return;
                 }
 
-                let origin = a.origin();
-                let a_pathname = a.pathname();
-                let hash = a.hash();
-                let query = a.search();
-
                 let meta_keys_pressed = meta_keys_pressed(ev.unchecked_ref::<KeyboardEvent>());
-                if !meta_keys_pressed && location.origin() == Ok(origin) {
-                    if location.pathname().as_ref() != Ok(&a_pathname) {
-                        // Same origin, different path. Navigate to new page.
-                        ev.prevent_default();
-                        PATHNAME.with(|pathname| {
-                            // Update History API.
-                            let history = window().history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a_pathname))
-                                .unwrap_throw();
-                            window().scroll_to_with_x_and_y(0.0, 0.0);
-
-                            let pathname = pathname.get().unwrap_throw();
-                            let path = a_pathname
-                                .strip_prefix(&base_pathname())
-                                .unwrap_or(&a_pathname);
-                            pathname.set(path.to_string());
-                        });
-                    } else if location.search().as_ref() != Ok(&query) {
-                        // Same origin, same pathname, different query.
-                        ev.prevent_default();
-                        let history = window().history().unwrap_throw();
-                        if query.is_empty() {
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a.href()))
-                                .unwrap_throw();
-                        } else {
-                            let history = window().history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&query))
-                                .unwrap_throw();
-                        }
-                        QUERY.with(|query| query.get().unwrap_throw().update(|_| {}));
-                    } else if location.hash().as_ref() != Ok(&hash) {
-                        // Same origin, same pathname, same query, different hash. Use default
-                        // browser behavior.
-                        if hash.is_empty() {
-                            ev.prevent_default();
-                            let history = window().history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a.href()))
-                                .unwrap_throw();
-                            window()
-                                .dispatch_event(&Event::new("hashchange").unwrap())
-                                .unwrap_throw();
-                        }
-                    } else {
-                        // Same page. Do nothing.
-                        ev.prevent_default();
-                    }
+                if !meta_keys_pressed && window().location().origin() == Ok(a.origin()) {
+                    ev.prevent_default();
+                    router.navigate(&format!("{}{}{}", a.pathname(), a.search(), a.hash()));
                 }
             }
         })
     }
+
+    fn push_url(&self, url: &str) {
+        *self.url.borrow_mut() = url.to_string();
+    }
+
+    fn replace_url(&self, url: &str) {
+        *self.url.borrow_mut() = url.to_string();
+    }
+
+    fn scroll_to_target(&self, _url: &str) {}
+}
+
+/// Splits `url` into its pathname, query string and fragment.
+fn split_url(url: &str) -> (&str, &str, &str) {
+    let (rest, hash) = url.split_at(url.find('#').unwrap_or(url.len()));
+    let (pathname, search) = rest.split_at(rest.find('?').unwrap_or(rest.len()));
+    (pathname, search, hash)
 }
 
 /// Gets the base pathname from `document.baseURI`.
 fn base_pathname() -> String {
+    if is_ssr!() {
+        return String::new();
+    }
     match document().query_selector("base[href]") {
         Ok(Some(base)) => {
             let base = base.unchecked_into::<HtmlBaseElement>().href();
@@ -156,6 +522,216 @@ fn base_pathname() -> String {
     }
 }
 
+/// A handle to a [`Router`]. This can be obtained with [`use_router`] from anywhere inside the
+/// router's view.
+///
+/// The handle can be freely cloned and moved into event handlers in order to navigate
+/// imperatively.
+#[derive(Clone)]
+pub struct RouterHandle {
+    pathname: Signal<String>,
+    query: Signal<()>,
+    base_pathname: Rc<str>,
+    integration: Rc<dyn Integration>,
+}
+
+impl std::fmt::Debug for RouterHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouterHandle")
+            .field("pathname", &self.pathname)
+            .field("base_pathname", &self.base_pathname)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RouterHandle {
+    /// Get the current pathname of the router. The base pathname is already stripped off.
+    pub fn pathname(&self) -> ReadSignal<String> {
+        *self.pathname
+    }
+
+    /// Navigates the router to the specified `url`. The url should have the same origin as the
+    /// app.
+    pub fn navigate(&self, url: &str) {
+        self.navigate_with(url, NavigateOptions::default());
+    }
+
+    /// Navigates the router to the specified `url` without adding a new history entry. Instead,
+    /// this replaces the current location with the new `url`.
+    pub fn navigate_replace(&self, url: &str) {
+        self.navigate_with(
+            url,
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Navigates the router to the specified `url` with the given [`NavigateOptions`].
+    pub fn navigate_with(&self, url: &str, options: NavigateOptions) {
+        if options.replace {
+            self.integration.replace_url(url);
+        } else {
+            self.integration.save_scroll_position();
+            self.integration.push_url(url);
+        }
+        self.update_pathname(url);
+        if self.integration.is_location_shared() {
+            for router in ROUTERS.with(|routers| routers.borrow().clone()) {
+                if !router.is(self) && router.integration.is_location_shared() {
+                    router.sync();
+                }
+            }
+        }
+        if options.scroll {
+            self.scroll_to_target(url);
+        }
+    }
+
+    /// Navigates the router to the specified `url` without touching the history.
+    ///
+    /// This means that the url will not be updated and will continue to show the previous value.
+    pub fn navigate_no_history(&self, url: &str) {
+        self.update_pathname(url);
+        self.scroll_to_target(url);
+    }
+
+    /// Preform a "soft" refresh of the current page. See [`refresh`] for more details.
+    pub fn refresh(&self) {
+        // The page stays where it is, like the page that was refreshed.
+        self.pathname.update(|_| {});
+    }
+
+    /// Scrolls to the target of `url` once the new page has been rendered.
+    fn scroll_to_target(&self, url: &str) {
+        let integration = Rc::clone(&self.integration);
+        let url = url.to_string();
+        scroll_after_render(move || integration.scroll_to_target(&url));
+    }
+
+    /// Updates the pathname with the path of `url`. If only the query or the fragment changed, the
+    /// query signal is triggered instead.
+    fn update_pathname(&self, url: &str) {
+        let path = self.strip_base(url);
+        let path = path.split(['?', '#']).next().unwrap_throw();
+        if self.pathname.with(|pathname| pathname != path) {
+            self.pathname.set(path.to_string());
+        } else {
+            self.query.update(|_| {});
+        }
+    }
+
+    /// Updates the pathname and the query with the current location of the integration.
+    fn sync(&self) {
+        let path = self.integration.current_pathname();
+        let path = self.strip_base(&path);
+        if self.pathname.with(|pathname| pathname != path) {
+            self.pathname.set(path.to_string());
+        } else {
+            // Only the query or the hash changed.
+            self.query.update(|_| {});
+        }
+    }
+
+    /// Returns whether `self` and `other` are handles to the same router.
+    fn is(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.integration, &other.integration)
+    }
+
+    /// Strips the base pathname from `path`.
+    fn strip_base<'a>(&self, path: &'a str) -> &'a str {
+        path.strip_prefix(&*self.base_pathname).unwrap_or(path)
+    }
+}
+
+/// Get the [`RouterHandle`] of the nearest [`Router`].
+///
+/// # Panics
+/// This function will `panic!()` if it is not called inside a [`Router`].
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn use_router() -> RouterHandle {
+    try_use_router().expect("cannot use router outside of a Router")
+}
+
+/// Get the [`RouterHandle`] of the nearest [`Router`], or `None` if there is no router.
+pub fn try_use_router() -> Option<RouterHandle> {
+    try_use_context()
+}
+
+thread_local! {
+    /// All the routers that are currently mounted, in the order that they were created.
+    static ROUTERS: RefCell<Vec<RouterHandle>> = const { RefCell::new(Vec::new()) };
+    /// The scroll that is done once the new page has been rendered. Routers that are updated
+    /// together replace each other's pending scroll, so that the page is only scrolled once.
+    static PENDING_SCROLL: RefCell<Option<Box<dyn FnOnce()>>> = const { RefCell::new(None) };
+    /// Whether a suspense scope on the page is loading. Scrolling waits until it has finished.
+    static SCROLL_BLOCKED: Cell<bool> = const { Cell::new(false) };
+    /// Whether an animation frame was requested for the pending scroll.
+    static SCROLL_FRAME_REQUESTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Scrolls with `f` once the new page has been rendered, i.e. on the next animation frame and once
+/// the suspense scopes on the page have finished loading.
+fn scroll_after_render(f: impl FnOnce() + 'static) {
+    PENDING_SCROLL.with(|pending| *pending.borrow_mut() = Some(Box::new(f)));
+    request_scroll_frame();
+}
+
+/// Runs the pending scroll on the next animation frame.
+fn request_scroll_frame() {
+    if is_ssr!() {
+        flush_pending_scroll();
+    } else if !SCROLL_FRAME_REQUESTED.replace(true) {
+        let closure = Closure::once_into_js(|| {
+            SCROLL_FRAME_REQUESTED.set(false);
+            flush_pending_scroll();
+        });
+        window()
+            .request_animation_frame(closure.unchecked_ref())
+            .unwrap_throw();
+    }
+}
+
+/// Runs the pending scroll unless a suspense scope is still loading.
+fn flush_pending_scroll() {
+    if SCROLL_BLOCKED.get() {
+        return;
+    }
+    if let Some(f) = PENDING_SCROLL.with(|pending| pending.borrow_mut().take()) {
+        f();
+    }
+}
+
+/// Get the [`RouterHandle`] of the nearest [`Router`]. If this is not called inside a router, e.g.
+/// in an event handler of an element outside of the router, this returns the first router that was
+/// created instead.
+fn current_router() -> Option<RouterHandle> {
+    try_use_router().or_else(|| ROUTERS.with(|routers| routers.borrow().first().cloned()))
+}
+
+/// Options for [`RouterHandle::navigate_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct NavigateOptions {
+    /// Whether the current history entry should be replaced instead of pushing a new one. Defaults
+    /// to `false`.
+    pub replace: bool,
+    /// Whether to scroll to the `#fragment` target of the new url (or to the top of the page)
+    /// after navigating. Defaults to `true`.
+    ///
+    /// Links can opt out of scrolling with the `data-noscroll` attribute.
+    pub scroll: bool,
+}
+
+impl Default for NavigateOptions {
+    fn default() -> Self {
+        Self {
+            replace: false,
+            scroll: true,
+        }
+    }
+}
+
 /// Props for [`Router`].
 #[derive(Props, Debug)]
 pub struct RouterProps<R, F, I>
@@ -217,6 +793,9 @@ where
 
 /// The sycamore router component. This component expects to be used inside a browser environment.
 /// For server environments, see [`StaticRouter`].
+///
+/// Multiple routers can be used at the same time. Each router provides a [`RouterHandle`] to its
+/// view which can be accessed with [`use_router`].
 #[component]
 pub fn Router<R, F, I>(props: RouterProps<R, F, I>) -> View
 where
@@ -250,49 +829,131 @@ where
         integration,
         route,
     } = props;
-    let integration = Rc::new(integration);
-    let base_pathname = base_pathname();
+    let integration: Rc<dyn Integration> = Rc::new(integration);
+    let route = Rc::new(route);
+    let base_pathname: Rc<str> = base_pathname().into();
 
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_none(),
-            "cannot have more than one Router component initialized"
-        );
-        // Get initial url from window.location.
-        let path = integration.current_pathname();
-        let path = path.strip_prefix(&base_pathname).unwrap_or(&path);
-        pathname.set(Some(create_signal(path.to_string())));
-        QUERY.set(Some(create_signal(())));
+    // Get initial url from the integration.
+    let path = integration.current_pathname();
+    let path = path.strip_prefix(&*base_pathname).unwrap_or(&path);
+    let router = RouterHandle {
+        pathname: create_signal(path.to_string()),
+        query: create_signal(()),
+        base_pathname,
+        integration,
+    };
+    let pathname = router.pathname;
+
+    ROUTERS.with(|routers| routers.borrow_mut().push(router.clone()));
+    on_cleanup({
+        let router = router.clone();
+        move || ROUTERS.with(|routers| routers.borrow_mut().retain(|r| !r.is(&router)))
     });
-    let pathname = PATHNAME.with(|p| p.get().unwrap_throw());
-
-    // Set PATHNAME to None when the Router is destroyed.
-    on_cleanup(|| PATHNAME.with(|pathname| pathname.set(None)));
-
-    // Listen to popstate event.
-    integration.on_popstate(Box::new({
-        let integration = integration.clone();
-        move || {
-            let path = integration.current_pathname();
-            let path = path.strip_prefix(&base_pathname).unwrap_or(&path);
-            if pathname.with(|pathname| pathname != path) {
-                pathname.set(path.to_string());
-            }
+    // Hold back the pending scroll while a lazy route or any other suspense scope is loading.
+    #[cfg(feature = "suspense")]
+    create_effect(|| {
+        let loading = sycamore::futures::use_is_loading_global();
+        SCROLL_BLOCKED.set(loading);
+        if !loading && PENDING_SCROLL.with(|pending| pending.borrow().is_some()) {
+            request_scroll_frame();
         }
-    }));
-    let route_signal = create_memo(move || pathname.with(|pathname| route.match_path(pathname)));
-    let view = view(route_signal);
-    let nodes = view.as_web_sys();
-    on_mount(move || {
-        for node in nodes {
-            let handler: Closure<dyn FnMut(web_sys::MouseEvent)> =
-                Closure::new(integration.click_handler());
-            node.add_event_listener_with_callback("click", handler.into_js_value().unchecked_ref())
-                .unwrap(); // TODO: manage in scope
-        }
-        // TODO: this does not work for dynamic views
     });
-    view
+
+    // Create a new scope so that nested routers each get their own context.
+    provide_context_in_new_scope(router.clone(), move || {
+        // Listen to popstate event.
+        router.integration.on_popstate(Box::new({
+            let router = router.clone();
+            move || router.sync()
+        }));
+        let route_signal = create_memo({
+            let route = Rc::clone(&route);
+            move || pathname.with(|pathname| route.match_path(pathname))
+        });
+        let view = view(route_signal);
+        is_not_ssr! {
+            let nodes = view.as_web_sys();
+            on_mount(move || {
+                // The listeners are added to the parent of the view so that they also cover the
+                // nodes that a dynamic view inserts later on. A dynamic view starts and ends with
+                // a marker node, so `first` and `last` stay the bounds of the view.
+                let (Some(first), Some(last)) = (nodes.first(), nodes.last()) else {
+                    return;
+                };
+                let Some(parent) = first.parent_node() else {
+                    return;
+                };
+                let bounds = Rc::new((first.clone(), last.clone()));
+                let in_view = move |handler: Box<dyn Fn(web_sys::MouseEvent)>| {
+                    let bounds = Rc::clone(&bounds);
+                    Closure::<dyn FnMut(_)>::new(move |ev: web_sys::MouseEvent| {
+                        if is_event_between(&bounds.0, &bounds.1, &ev) {
+                            handler(ev);
+                        }
+                    })
+                };
+                let prefetch: Rc<dyn Fn(&str)> = Rc::new({
+                    let router = router.clone();
+                    move |path| route.match_path(router.strip_base(path)).prefetch()
+                });
+                // `pointerenter` does not bubble, so it is listened to in the capture phase.
+                // Unlike `mouseover`, it is only fired once when the pointer enters a link.
+                let handlers = [
+                    (
+                        "click",
+                        false,
+                        in_view(router.integration.router_click_handler(router.clone())),
+                    ),
+                    (
+                        "pointerenter",
+                        true,
+                        in_view(router.integration.hover_handler(prefetch)),
+                    ),
+                ];
+                for (event, capture, handler) in &handlers {
+                    parent
+                        .add_event_listener_with_callback_and_bool(
+                            event,
+                            handler.as_ref().unchecked_ref(),
+                            *capture,
+                        )
+                        .unwrap_throw();
+                }
+                on_cleanup(move || {
+                    for (event, capture, handler) in &handlers {
+                        parent
+                            .remove_event_listener_with_callback_and_bool(
+                                event,
+                                handler.as_ref().unchecked_ref(),
+                                *capture,
+                            )
+                            .unwrap_throw();
+                    }
+                });
+            });
+        }
+        view
+    })
+}
+
+/// Returns whether the target of `ev` is one of the sibling nodes from `first` to `last` or inside
+/// of one of them.
+#[cfg_not_ssr]
+fn is_event_between(first: &web_sys::Node, last: &web_sys::Node, ev: &Event) -> bool {
+    let Some(target) = ev.target().and_then(|target| target.dyn_into().ok()) else {
+        return false;
+    };
+    let mut node = Some(first.clone());
+    while let Some(current) = node {
+        if current.contains(Some(&target)) {
+            return true;
+        }
+        if current == *last {
+            break;
+        }
+        node = current.next_sibling();
+    }
+    false
 }
 
 /// Props for [`StaticRouter`].
@@ -350,14 +1011,20 @@ where
 /// This is useful for imperatively navigating to an url when using an anchor tag (`<a>`) is not
 /// possible/suitable (e.g. when submitting a form).
 ///
-/// # Panics
-/// This function will `panic!()` if a [`Router`] has not yet been created.
+/// This navigates the nearest [`Router`]. If this is not called inside a router, e.g. in an event
+/// handler of an element outside of the router, the first router that was created is navigated
+/// instead. Other routers that use the [`HistoryIntegration`] are updated as well.
+///
+/// If there is no router, this only updates the URL using the History API.
 pub fn navigate(url: &str) {
-    let history = window().history().unwrap_throw();
-    history
-        .push_state_with_url(&JsValue::UNDEFINED, "", Some(url))
-        .unwrap_throw();
-    navigate_no_history(url);
+    match current_router() {
+        Some(router) => router.navigate(url),
+        None => window()
+            .history()
+            .unwrap_throw()
+            .push_state_with_url(&history_state(new_history_entry_key(), None), "", Some(url))
+            .unwrap_throw(),
+    }
 }
 
 /// Navigates to the specified `url` without adding a new history entry. Instead, this replaces the
@@ -366,141 +1033,115 @@ pub fn navigate(url: &str) {
 /// This is useful for imperatively navigating to an url when using an anchor tag (`<a>`) is not
 /// possible/suitable (e.g. when submitting a form).
 ///
-/// # Panics
-/// This function will `panic!()` if a [`Router`] has not yet been created.
+/// The router that is navigated is chosen the same way as in [`navigate`].
 pub fn navigate_replace(url: &str) {
-    let history = window().history().unwrap_throw();
-    history
-        .replace_state_with_url(&JsValue::UNDEFINED, "", Some(url))
-        .unwrap_throw();
-    navigate_no_history(url);
+    match current_router() {
+        Some(router) => router.navigate_replace(url),
+        None => window()
+            .history()
+            .unwrap_throw()
+            .replace_state_with_url(&history_state(history_entry_key(), None), "", Some(url))
+            .unwrap_throw(),
+    }
 }
 
 /// Navigates to the specified `url` without touching the history API.
 ///
 /// This means that the url will not be updated and will continue to show the previous value.
 ///
-/// # Panics
-/// This function will `panic!()` if a [`Router`] has not yet been created.
-pub fn navigate_no_history(url: &str) {
-    window().scroll_to_with_x_and_y(0.0, 0.0);
-    update_pathname(url);
-}
-
-/// Internal function for getting the global pathname variable and updating it with the given `url`.
+/// The router that is navigated is chosen the same way as in [`navigate`].
 ///
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
-fn update_pathname(url: &str) {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot navigate outside of a Router",
-        );
-
-        let pathname = pathname.get().unwrap_throw();
-        let path = url.strip_prefix(&base_pathname()).unwrap_or(url);
-        pathname.set(path.to_string());
-    })
+pub fn navigate_no_history(url: &str) {
+    current_router()
+        .expect("cannot navigate outside of a Router")
+        .navigate_no_history(url);
 }
 
 /// Preform a "soft" refresh of the current page.
 ///
 /// Unlike a "hard" refresh which corresponds to clicking on the refresh button, this simply forces
-/// a re-render of the view for the current page.
+/// a re-render of the view for the current page. The scroll position of the page is kept.
+///
+/// The router that is refreshed is chosen the same way as in [`navigate`].
 ///
 /// # Panic
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn refresh() {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot refresh outside of a Router",
-        );
-
-        window().scroll_to_with_x_and_y(0.0, 0.0);
-
-        pathname.get().unwrap_throw().update(|_| {});
-    });
+    current_router()
+        .expect("cannot refresh outside of a Router")
+        .refresh();
 }
 
 /// Creates a ReadSignal that tracks the url query provided.
+///
+/// The query is read from the nearest [`Router`], or from the first router that was created if
+/// this is not called inside a router.
+///
+/// # Panics
+/// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn use_search_query(query: &'static str) -> ReadSignal<Option<String>> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get query outside of a Router",
-        );
+    let router = current_router().expect("cannot get query outside of a Router");
 
-        let pathname = pathname.get().unwrap_throw();
-
-        create_memo(move || {
-            QUERY.with(|query| query.get().unwrap_throw()).track();
-            pathname.track();
-            UrlSearchParams::new_with_str(&window().location().search().unwrap_throw())
-                .unwrap_throw()
-                .get(query)
-        })
+    create_memo(move || {
+        router.query.track();
+        router.pathname.track();
+        UrlSearchParams::new_with_str(&router.integration.current_search())
+            .unwrap_throw()
+            .get(query)
     })
 }
 
 /// Creates a ReadSignal that tracks the url query string.
+///
+/// The query is read from the nearest [`Router`], or from the first router that was created if
+/// this is not called inside a router.
+///
+/// # Panics
+/// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn use_search_queries() -> ReadSignal<HashMap<String, String>> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get query outside of a Router",
-        );
+    let router = current_router().expect("cannot get query outside of a Router");
 
-        let pathname = pathname.get().unwrap_throw();
-
-        create_memo(move || {
-            QUERY.with(|query| query.get().unwrap_throw()).track();
-            pathname.track();
-            UrlSearchParams::new_with_str(&window().location().search().unwrap_throw())
-                .unwrap_throw()
-                .entries()
-                .into_iter()
-                .map(|e| {
-                    let e: Array = e.unwrap_throw().into();
-                    let e = e
-                        .into_iter()
-                        .map(|s| s.as_string().unwrap_throw())
-                        .collect::<Vec<String>>();
-                    (e[0].clone(), e[1].clone())
-                })
-                .collect()
-        })
+    create_memo(move || {
+        router.query.track();
+        router.pathname.track();
+        UrlSearchParams::new_with_str(&router.integration.current_search())
+            .unwrap_throw()
+            .entries()
+            .into_iter()
+            .map(|e| {
+                let e: Array = e.unwrap_throw().into();
+                let e = e
+                    .into_iter()
+                    .map(|s| s.as_string().unwrap_throw())
+                    .collect::<Vec<String>>();
+                (e[0].clone(), e[1].clone())
+            })
+            .collect()
     })
 }
 
 /// Creates a ReadSignal that tracks the url fragment.
+///
+/// The fragment is read from the nearest [`Router`], or from the first router that was created if
+/// this is not called inside a router.
+///
+/// # Panics
+/// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn use_location_hash() -> ReadSignal<String> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get hash outside of a Router"
-        );
+    let router = current_router().expect("cannot get hash outside of a Router");
 
-        let pathname = pathname.get().unwrap_throw();
-
-        let on_hashchange = create_signal(());
-        window()
-            .add_event_listener_with_callback(
-                "hashchange",
-                Closure::wrap(Box::new(move || {
-                    on_hashchange.update(|_| {});
-                }) as Box<dyn FnMut()>)
-                .into_js_value()
-                .unchecked_ref(),
-            )
-            .unwrap_throw();
+    let on_hashchange = create_signal(());
+    sycamore::web::use_window_event(sycamore::web::events::hashchange, move |_| {
+        on_hashchange.update(|_| {})
+    });
 
-        create_memo(move || {
-            on_hashchange.track();
-            pathname.track();
-            window().location().hash().unwrap_throw()
-        })
+    create_memo(move || {
+        on_hashchange.track();
+        router.query.track();
+        router.pathname.track();
+        router.integration.current_hash()
     })
 }
 
@@ -572,4 +1213,227 @@ mod tests {
             "Not Found"
         );
     }
+
+    #[derive(Route, Clone, Copy, Debug, PartialEq, Eq)]
+    enum Routes {
+        #[to("/")]
+        Home,
+        #[to("/about")]
+        About,
+        #[not_found]
+        NotFound,
+    }
+
+    /// Creates a [`Router`] with a [`MemoryIntegration`] that starts at `url`. Returns the handle
+    /// of the router, its route and the scope of its view.
+    fn create_memory_router(url: &str) -> (RouterHandle, ReadSignal<Routes>, NodeHandle) {
+        let router = Rc::new(RefCell::new(None));
+        let view = {
+            let router = Rc::clone(&router);
+            move |route: ReadSignal<Routes>| {
+                *router.borrow_mut() = Some((use_router(), route, use_current_scope()));
+                View::new()
+            }
+        };
+        let _: View = view! {
+            Router(integration=MemoryIntegration::new(url), view=view)
+        };
+        router.take().unwrap()
+    }
+
+    #[test]
+    fn memory_router() {
+        let _ = create_root(|| {
+            let (router, route, scope) = create_memory_router("/about?tab=1");
+            let hash = scope.run_in(use_location_hash);
+            assert_eq!(route.get(), Routes::About);
+            assert_eq!(router.integration.current_search(), "?tab=1");
+
+            router.navigate("/about?tab=2#section");
+            assert_eq!(route.get(), Routes::About);
+            assert_eq!(router.integration.current_search(), "?tab=2");
+            assert_eq!(hash.get_clone(), "#section");
+
+            router.navigate("/");
+            assert_eq!(route.get(), Routes::Home);
+            assert_eq!(router.pathname().get_clone(), "/");
+            assert_eq!(router.integration.current_search(), "");
+            assert_eq!(hash.get_clone(), "");
+        });
+    }
+
+    #[test]
+    fn multiple_routers() {
+        let _ = create_root(|| {
+            let (main, main_route, _) = create_memory_router("/");
+            let (modal, modal_route, modal_scope) = create_memory_router("/");
+
+            modal.navigate("/about");
+            assert_eq!(modal_route.get(), Routes::About);
+            assert_eq!(main_route.get(), Routes::Home);
+
+            // Outside of a router, the first router that was created is used.
+            navigate("/404");
+            assert_eq!(main_route.get(), Routes::NotFound);
+            assert_eq!(modal_route.get(), Routes::About);
+
+            navigate_no_history("/about");
+            assert_eq!(main_route.get(), Routes::About);
+
+            // Inside of a router, the nearest router is used.
+            modal_scope.run_in(|| navigate("/"));
+            assert_eq!(modal_route.get(), Routes::Home);
+            assert_eq!(main_route.get(), Routes::About);
+            assert_eq!(main.pathname().get_clone(), "/about");
+        });
+    }
+
+    #[test]
+    fn nested_routers() {
+        let _ = create_root(|| {
+            let inner = Rc::new(RefCell::new(None));
+            let view = {
+                let inner = Rc::clone(&inner);
+                move |_: ReadSignal<Routes>| {
+                    *inner.borrow_mut() = Some((use_router(), create_memory_router("/about")));
+                    View::new()
+                }
+            };
+            let _: View = view! {
+                Router(integration=MemoryIntegration::new("/"), view=view)
+            };
+            let (outer, (inner, inner_route, inner_scope)) = inner.take().unwrap();
+
+            assert_eq!(inner_route.get(), Routes::About);
+            assert_eq!(outer.pathname().get_clone(), "/");
+            assert!(inner_scope.run_in(use_router).is(&inner));
+
+            inner_scope.run_in(|| navigate("/"));
+            assert_eq!(inner_route.get(), Routes::Home);
+            outer.navigate("/about");
+            assert_eq!(inner_route.get(), Routes::Home);
+            assert_eq!(outer.pathname().get_clone(), "/about");
+        });
+    }
+
+    /// A [`MemoryIntegration`] that records the urls that are scrolled to.
+    struct ScrollRecorder {
+        memory: MemoryIntegration,
+        scrolls: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Integration for ScrollRecorder {
+        fn current_pathname(&self) -> String {
+            self.memory.current_pathname()
+        }
+
+        fn current_search(&self) -> String {
+            self.memory.current_search()
+        }
+
+        fn current_hash(&self) -> String {
+            self.memory.current_hash()
+        }
+
+        fn is_location_shared(&self) -> bool {
+            false
+        }
+
+        fn on_popstate(&self, _f: Box<dyn FnMut()>) {}
+
+        fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)> {
+            Box::new(|_| {})
+        }
+
+        fn push_url(&self, url: &str) {
+            self.memory.push_url(url);
+        }
+
+        fn replace_url(&self, url: &str) {
+            self.memory.replace_url(url);
+        }
+
+        fn scroll_to_target(&self, url: &str) {
+            self.scrolls.borrow_mut().push(url.to_string());
+        }
+    }
+
+    /// Creates a [`Router`] with a [`ScrollRecorder`] that starts at `/`.
+    fn create_scroll_recorder(scrolls: &Rc<RefCell<Vec<String>>>) -> RouterHandle {
+        let integration = ScrollRecorder {
+            memory: MemoryIntegration::new("/"),
+            scrolls: Rc::clone(scrolls),
+        };
+        let router = Rc::new(RefCell::new(None));
+        let view = {
+            let router = Rc::clone(&router);
+            move |_: ReadSignal<Routes>| {
+                *router.borrow_mut() = Some(use_router());
+                View::new()
+            }
+        };
+        let _: View = view! {
+            Router(integration=integration, view=view)
+        };
+        router.take().unwrap()
+    }
+
+    #[test]
+    fn scroll_to_target() {
+        let _ = create_root(|| {
+            let scrolls = Rc::new(RefCell::new(Vec::new()));
+            let router = create_scroll_recorder(&scrolls);
+
+            router.navigate("/about#section");
+            router.navigate_with(
+                "/",
+                NavigateOptions {
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+            router.navigate_no_history("/about");
+            // A refresh keeps the scroll position.
+            router.refresh();
+            assert_eq!(*scrolls.borrow(), ["/about#section", "/about"]);
+        });
+    }
+
+    #[cfg(feature = "suspense")]
+    #[tokio::test]
+    async fn scroll_waits_for_suspense() {
+        use futures::channel::oneshot;
+        use sycamore::futures::{create_suspense_scope, create_suspense_task};
+
+        let (tx, rx) = oneshot::channel();
+        let scrolls = Rc::new(RefCell::new(Vec::new()));
+
+        let local = tokio::task::LocalSet::new();
+        let mut root = create_root(|| {});
+        local
+            .run_until(async {
+                root = create_root(|| {
+                    let main = create_scroll_recorder(&scrolls);
+                    let modal = create_scroll_recorder(&scrolls);
+
+                    // The suspense scope is created after the routers, like the scope of a lazy
+                    // route that is rendered after navigating.
+                    let _ = create_suspense_scope(|| {
+                        create_suspense_task(async move {
+                            rx.await.unwrap();
+                        });
+                    });
+                    main.navigate("/about");
+                    modal.navigate("/about#section");
+                });
+            })
+            .await;
+        assert!(scrolls.borrow().is_empty());
+
+        tx.send(()).unwrap();
+        local.await;
+        // Only the last pending scroll is done.
+        assert_eq!(*scrolls.borrow(), ["/about#section"]);
+        root.dispose();
+    }
 }
@@ -0,0 +1,204 @@
+//! Static site generation.
+
+use std::path::{Component, Path, PathBuf};
+
+use sycamore::prelude::*;
+
+use crate::{Route, StaticRouter};
+
+/// Renders the page at `path` to a string using a [`StaticRouter`]. If the `suspense` feature is
+/// enabled, suspense is awaited before returning.
+///
+/// This function is only available in SSR mode.
+pub async fn render_path_to_string<R, F>(path: &str, view: F) -> String
+where
+    R: Route + 'static,
+    F: Fn(ReadSignal<R>) -> View + 'static,
+{
+    let route = R::default().match_path(path);
+    let view = move || view! { StaticRouter(route=route, view=view) };
+    #[cfg(feature = "suspense")]
+    return sycamore::render_to_string_await_suspense(view).await;
+    #[cfg(not(feature = "suspense"))]
+    return sycamore::render_to_string(view);
+}
+
+/// Pre-renders all the paths of the route `R` (see [`Route::paths`]) and writes them into
+/// `out_dir`.
+///
+/// Every page is written into an `index.html` file in the directory corresponding to its path
+/// (e.g. `/about` is written to `<out_dir>/about/index.html`). The segments of the path are
+/// percent-decoded. A path with a segment that is not a plain file name, such as `..`, is rejected
+/// with an [`InvalidInput`](std::io::ErrorKind::InvalidInput) error. The rendered HTML is prefixed
+/// with `<!DOCTYPE html>`. Returns the list of files that were written.
+///
+/// This function is only available in SSR mode.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::{Route, render_static_site};
+/// #[derive(Route, Clone)]
+/// enum AppRoutes {
+///     #[to("/")]
+///     Home,
+///     #[to("/post/<id>")]
+///     #[params(post_ids)]
+///     Post { id: u32 },
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// fn post_ids() -> Vec<u32> {
+///     vec![1, 2, 3]
+/// }
+///
+/// # async fn build() -> std::io::Result<()> {
+/// render_static_site("dist", |route: ReadSignal<AppRoutes>| match route.get_clone() {
+///     AppRoutes::Home => view! { "Home" },
+///     AppRoutes::Post { id } => view! { "Post " (id) },
+///     AppRoutes::NotFound => view! { "Not Found" },
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn render_static_site<R, F>(
+    out_dir: impl AsRef<Path>,
+    view: F,
+) -> std::io::Result<Vec<PathBuf>>
+where
+    R: Route + 'static,
+    F: Fn(ReadSignal<R>) -> View + Clone + 'static,
+{
+    let out_dir = out_dir.as_ref();
+    let mut files = Vec::new();
+    for path in R::paths() {
+        let html = render_path_to_string::<R, _>(&path, view.clone()).await;
+
+        let dir = out_dir.join(file_path(&path)?);
+        std::fs::create_dir_all(&dir)?;
+        let file = dir.join("index.html");
+        std::fs::write(&file, format!("<!DOCTYPE html>{html}"))?;
+        files.push(file);
+    }
+    Ok(files)
+}
+
+/// Converts a URL path into a relative file path. Every segment is percent-decoded and must be a
+/// plain file name so that the file cannot be written outside of the output directory.
+fn file_path(path: &str) -> std::io::Result<PathBuf> {
+    let mut file_path = PathBuf::new();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        let segment = percent_decode(segment);
+        let mut components = Path::new(&segment).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) if !segment.contains(['/', '\\']) => {
+                file_path.push(name);
+            }
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("cannot write path `{path}` to a file"),
+                ));
+            }
+        }
+    }
+    Ok(file_path)
+}
+
+/// Decodes the `%XX` escapes in a segment of a URL path.
+fn percent_decode(segment: &str) -> String {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%'
+            && let Some(hex) = tail.get(..2)
+            && let Ok(hex) = std::str::from_utf8(hex)
+            && let Ok(decoded) = u8::from_str_radix(hex, 16)
+        {
+            bytes.push(decoded);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Route, Clone)]
+    enum Routes {
+        #[to("/")]
+        Home,
+        #[to("/post/<id>")]
+        #[params(post_ids)]
+        Post { id: u32 },
+        #[not_found]
+        NotFound,
+    }
+
+    fn post_ids() -> [u32; 2] {
+        [1, 2]
+    }
+
+    fn app(route: ReadSignal<Routes>) -> View {
+        match route.get_clone() {
+            Routes::Home => view! { "Home" },
+            Routes::Post { id } => view! { "Post " (id) },
+            Routes::NotFound => view! { "Not Found" },
+        }
+    }
+
+    #[tokio::test]
+    async fn render_path() {
+        assert_eq!(render_path_to_string("/post/1", app).await, "Post 1");
+        assert_eq!(render_path_to_string("/404", app).await, "Not Found");
+    }
+
+    #[tokio::test]
+    async fn render_site() {
+        // Use a new directory for every run so that concurrent runs do not interfere.
+        let out_dir = std::env::temp_dir().join(format!(
+            "sycamore-router-render-site-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let files = render_static_site(&out_dir, app).await.unwrap();
+        assert_eq!(
+            files,
+            [
+                out_dir.join("index.html"),
+                out_dir.join("post/1/index.html"),
+                out_dir.join("post/2/index.html"),
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(out_dir.join("post/2/index.html")).unwrap(),
+            "<!DOCTYPE html>Post 2"
+        );
+
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn file_paths() {
+        assert_eq!(file_path("/").unwrap(), PathBuf::new());
+        assert_eq!(
+            file_path("/post/a%20b").unwrap(),
+            Path::new("post").join("a b")
+        );
+        assert!(file_path("/post/..").is_err());
+        assert!(file_path("/post/%2E%2E").is_err());
+        assert!(file_path("/post/a%2Fb").is_err());
+        assert!(file_path("/post/a%5Cb").is_err());
+    }
+}
@@ -3,19 +3,86 @@
 //! Implementation note: We are not using the `quote::ToTokens` trait because we need to pass
 //! additional information to the codegen such as which mode (Client, Hydrate, SSR), etc...
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Expr, Pat};
-
-use crate::ir::{DynNode, Node, Prop, PropType, Root, TagIdent, TagNode, TextNode};
+use syn::{Expr, Ident, Pat, parse_quote};
+
+use crate::ir::{
+    DynNode, ForNode, IfNode, KEY_MODIFIERS, MatchNode, Node, Prop, PropType, Root, SlotNode,
+    TagIdent, TagNode, TextNode,
+};
+
+pub(crate) mod template;
+
+/// How the generated code creates elements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Create every node one at a time using the element builders.
+    #[default]
+    Builder,
+    /// Extract the static HTML skeleton of every element tree into a `<template>` which is cloned
+    /// for every instance. The dynamic parts are then filled in by walking to them in the cloned
+    /// nodes.
+    ///
+    /// Since templates can only be used when rendering to the DOM, the code for [`Mode::Builder`]
+    /// is also generated and used in SSR mode and while hydrating.
+    Template,
+}
 
+#[derive(Clone, Copy, Default)]
 pub struct Codegen {
-    // TODO: configure mode: Client, Hydrate, SSR
+    pub mode: Mode,
+    /// Whether static element trees are pre-rendered to HTML at compile time. The pre-rendered
+    /// HTML is used in SSR mode instead of building the elements at runtime.
+    pub prerender: bool,
+    /// Whether every element is scoped with the attribute in `__css_scope`. This is set by
+    /// [`Codegen::scoped_root`].
+    pub scoped_css: bool,
 }
 
 impl Codegen {
     pub fn root(&self, root: &Root) -> TokenStream {
-        match &root.0[..] {
+        self.nodes(&root.0.iter().collect::<Vec<_>>())
+    }
+
+    /// Generate a view whose elements are scoped with the `ScopedCss` created by `css`. The
+    /// reactive variables of the CSS are set on the top-level elements.
+    ///
+    /// Templates and pre-rendering are not used since the scope attribute is only known at
+    /// runtime.
+    pub fn scoped_root(&self, css: &Expr, root: &Root) -> TokenStream {
+        let codegen = Codegen {
+            scoped_css: true,
+            ..*self
+        };
+        let nodes =
+            root.0
+                .iter()
+                .map(|node| match node {
+                    Node::Tag(tag) if !is_component(&tag.ident) => codegen
+                        .element_builder_with(tag, quote! { .scoped_css_vars(&__scoped_css) }),
+                    node => codegen.node(node),
+                })
+                .collect::<Vec<_>>();
+        let view = match &nodes[..] {
+            [] => quote! { ::sycamore::rt::View::new() },
+            [node] => node.clone(),
+            nodes => quote! {
+                ::std::convert::Into::<::sycamore::rt::View>::into(::std::vec![#(#nodes),*])
+            },
+        };
+        quote! {{
+            let __scoped_css: ::sycamore::rt::ScopedCss = #css;
+            // The scope is not used if there are no nested elements.
+            #[allow(unused_variables)]
+            let __css_scope = __scoped_css.register();
+            #view
+        }}
+    }
+
+    /// Generate a `View` from a list of `Node`s.
+    fn nodes(&self, nodes: &[&Node]) -> TokenStream {
+        match nodes {
             [] => quote! {
                 ::sycamore::rt::View::new()
             },
@@ -56,17 +123,229 @@ impl Codegen {
                     }
                 }
             }
+            Node::If(if_node) => self.if_node(if_node),
+            Node::Match(match_node) => self.match_node(match_node),
+            Node::For(for_node) => self.for_node(for_node),
+            Node::Slot(SlotNode { name, .. }) => syn::Error::new(
+                name.span(),
+                "slots can only be used directly inside a component",
+            )
+            .to_compile_error(),
+        }
+    }
+
+    /// Generate an `if` node. If any of the conditions are dynamic, the index of the taken branch
+    /// is memoized so that the branch is only re-created when a different branch is taken, or when
+    /// the values bound by an `if let` condition of the branch change.
+    pub fn if_node(
+        &self,
+        IfNode {
+            branches,
+            else_branch,
+        }: &IfNode,
+    ) -> TokenStream {
+        let conds = branches.iter().map(|(cond, _)| cond).collect::<Vec<_>>();
+        let bodies = branches
+            .iter()
+            .map(|(_, body)| self.root(body))
+            .collect::<Vec<_>>();
+        let else_body = match else_branch {
+            Some(body) => self.root(body),
+            None => quote! { ::sycamore::rt::View::new() },
+        };
+
+        if !conds.iter().any(|cond| is_dyn(cond)) {
+            return quote! {
+                #(if #conds { #bodies } else)* { #else_body }
+            };
+        }
+
+        let indices = (0..branches.len()).collect::<Vec<_>>();
+        let binding_indices = conds
+            .iter()
+            .zip(&indices)
+            .filter(|(cond, _)| cond_binds(cond))
+            .map(|(_, index)| index)
+            .collect::<Vec<_>>();
+        let branch_index = quote! {
+            // The bindings are only used when the branch is created.
+            #[allow(unused_variables)]
+            let __index = #(if #conds { #indices } else)* { usize::MAX };
+        };
+        // Track the condition so that the branch is re-created when its bindings change.
+        let track_bindings = (!binding_indices.is_empty()).then(|| {
+            quote! {
+                if matches!(__selected, #(#binding_indices)|*) {
+                    #branch_index
+                }
+            }
+        });
+        quote! {{
+            let __branch = ::sycamore::rt::create_selector(move || {
+                #branch_index
+                __index
+            });
+            ::sycamore::rt::View::from_dynamic(move || {
+                let __selected = __branch.get();
+                #track_bindings
+                ::sycamore::rt::untrack(|| #(if #conds { #bodies } else)* { #else_body })
+            })
+        }}
+    }
+
+    /// Generate a `match` node. If the scrutinee or any of the guards are dynamic, the index of
+    /// the matching arm is memoized so that the arm is only re-created when a different arm
+    /// matches, or when the values bound by the pattern of the arm change.
+    pub fn match_node(&self, MatchNode { expr, arms }: &MatchNode) -> TokenStream {
+        let pats = arms.iter().map(|arm| &arm.pat).collect::<Vec<_>>();
+        let guards = arms
+            .iter()
+            .map(|arm| arm.guard.as_ref().map(|guard| quote! { if #guard }))
+            .collect::<Vec<_>>();
+        let bodies = arms
+            .iter()
+            .map(|arm| self.root(&arm.body))
+            .collect::<Vec<_>>();
+
+        let is_dynamic = is_dyn(expr)
+            || arms
+                .iter()
+                .any(|arm| arm.guard.as_ref().is_some_and(is_dyn));
+        if !is_dynamic {
+            return quote! {
+                match #expr {
+                    #(#pats #guards => #bodies,)*
+                }
+            };
         }
+
+        let indices = (0..arms.len()).collect::<Vec<_>>();
+        let binding_indices = pats
+            .iter()
+            .zip(&indices)
+            .filter(|(pat, _)| pat_binds(pat))
+            .map(|(_, index)| index)
+            .collect::<Vec<_>>();
+        let arm_index = quote! {
+            // The bindings are only used when the arm is created.
+            #[allow(unused_variables)]
+            let __index = match #expr {
+                #(#pats #guards => #indices,)*
+            };
+        };
+        // Track the scrutinee so that the arm is re-created when its bindings change.
+        let track_bindings = (!binding_indices.is_empty()).then(|| {
+            quote! {
+                if matches!(__selected, #(#binding_indices)|*) {
+                    #arm_index
+                }
+            }
+        });
+        quote! {{
+            let __branch = ::sycamore::rt::create_selector(move || {
+                #arm_index
+                __index
+            });
+            ::sycamore::rt::View::from_dynamic(move || {
+                let __selected = __branch.get();
+                #track_bindings
+                ::sycamore::rt::untrack(|| match #expr {
+                    #(#pats #guards => #bodies,)*
+                })
+            })
+        }}
+    }
+
+    /// Generate a `for` node. This uses the `Keyed` component if a key is specified and the
+    /// `Indexed` component otherwise.
+    pub fn for_node(
+        &self,
+        ForNode {
+            pat,
+            iter,
+            key,
+            body,
+        }: &ForNode,
+    ) -> TokenStream {
+        let prop = |name: &str, value: Expr| Prop {
+            ty: PropType::Plain {
+                ident: Ident::new(name, Span::call_site()),
+            },
+            value,
+            span: Span::call_site(),
+        };
+
+        let list = if is_dyn(iter) {
+            parse_quote! { move || #iter }
+        } else {
+            iter.clone()
+        };
+        let body = self.root(body);
+        let mut props = vec![
+            prop("list", list),
+            prop("view", parse_quote! { move |#pat| #body }),
+        ];
+        let component = match key {
+            Some(key) => {
+                props.push(prop(
+                    "key",
+                    parse_quote! {
+                        move |__item: &_| {
+                            // The key can be computed from only some of the bindings.
+                            #[allow(unused_variables)]
+                            let #pat = __item;
+                            #key
+                        }
+                    },
+                ));
+                parse_quote! { ::sycamore::rt::Keyed }
+            }
+            None => parse_quote! { ::sycamore::rt::Indexed },
+        };
+        self.component(&TagNode {
+            ident: TagIdent::Path(component),
+            props,
+            children: Root(Vec::new()),
+        })
     }
 
     pub fn element(&self, element: &TagNode) -> TokenStream {
+        if self.mode == Mode::Template
+            && !self.scoped_css
+            && let Some(template) = self.template(element)
+        {
+            return template;
+        }
+        self.element_builder(element)
+    }
+
+    /// Generate an element using the element builders.
+    pub fn element_builder(&self, element: &TagNode) -> TokenStream {
+        self.element_builder_with(element, TokenStream::new())
+    }
+
+    /// Generate an element using the element builders and call the builder methods in `extra`
+    /// after setting the attributes.
+    fn element_builder_with(&self, element: &TagNode, extra: TokenStream) -> TokenStream {
+        if self.prerender
+            && !self.scoped_css
+            && let Some(prerendered) = self.prerendered(element)
+        {
+            return prerendered;
+        }
+
         let TagNode {
             ident,
             props,
             children,
         } = element;
 
-        let attributes = props.iter().map(|attr| self.attribute(attr));
+        let attributes = ordered_props(props).map(|attr| self.element_attribute(element, attr));
+        let scope = if self.scoped_css {
+            quote! { .bool_attr(__css_scope, true) }
+        } else {
+            quote! {}
+        };
 
         let children = children
             .0
@@ -79,18 +358,44 @@ impl Codegen {
                 assert!(tag.get_ident().is_some(), "elements must be an ident");
                 quote! {
                     ::sycamore::rt::View::from(
-                        ::sycamore::rt::tags::#tag().children(::std::vec![#(#children),*])#(#attributes)*
+                        ::sycamore::rt::tags::#tag().children(::std::vec![#(#children),*])#(#attributes)*#scope #extra
                     )
                 }
             }
             TagIdent::Hyphenated(tag) => quote! {
                 ::sycamore::rt::View::from(
-                    ::sycamore::rt::custom_element(#tag).children(::std::vec![#(#children),*])#(#attributes)*
+                    ::sycamore::rt::custom_element(#tag).children(::std::vec![#(#children),*])#(#attributes)*#scope #extra
                 )
             },
         }
     }
 
+    /// Generate an attribute of `element`. A node ref that is set with `r#ref` gets the type of
+    /// the element if the type of the node ref is not known yet, so that it does not need to be
+    /// annotated.
+    pub fn element_attribute(&self, element: &TagNode, attr: &Prop) -> TokenStream {
+        match &attr.ty {
+            PropType::Ref => {
+                let value = &attr.value;
+                let tag = match &element.ident {
+                    TagIdent::Path(tag) => quote! { ::sycamore::rt::tags::#tag },
+                    TagIdent::Hyphenated(tag) => quote! { || ::sycamore::rt::custom_element(#tag) },
+                };
+                quote! {
+                    .r#ref({
+                        #[allow(unused_imports)]
+                        use ::sycamore::rt::{SelectElementRef as _, SelectNodeRef as _};
+                        // The reference selects `SelectNodeRef` if the type is already known.
+                        #[allow(clippy::needless_borrow)]
+                        let node_ref = (&::sycamore::rt::NodeRefArg::new(#value, #tag)).select();
+                        node_ref
+                    })
+                }
+            }
+            _ => self.attribute(attr),
+        }
+    }
+
     pub fn attribute(&self, attr: &Prop) -> TokenStream {
         let value = &attr.value;
         let is_dynamic = is_dyn(value);
@@ -109,21 +414,56 @@ impl Codegen {
             PropType::PlainQuoted { ident } => {
                 quote! { .attr(#ident, #dyn_value) }
             }
-            PropType::Directive { dir, ident } => match dir.to_string().as_str() {
-                "on" => quote! { .on(::sycamore::rt::events::#ident, #value) },
+            PropType::Directive {
+                dir,
+                ident,
+                modifiers,
+            } => match dir.to_string().as_str() {
+                "on" if modifiers.is_empty() => {
+                    quote! { .on(::sycamore::rt::events::#ident, #value) }
+                }
+                "on" => {
+                    let options = event_options(modifiers);
+                    quote! { .on_with(::sycamore::rt::events::#ident, #options, #value) }
+                }
                 "prop" => {
                     let ident = ident.to_string();
                     quote! { .prop(#ident, #dyn_value) }
                 }
                 "bind" => quote! { .bind(::sycamore::rt::bind::#ident, #value) },
+                "class" | "style" => {
+                    let ident = ident.to_string();
+                    self.directive_with_name(dir, &ident, dyn_value)
+                }
                 _ => syn::Error::new(dir.span(), format!("unknown directive `{dir}`"))
                     .to_compile_error(),
             },
+            PropType::DirectiveHyphenated { dir, ident } => match dir.to_string().as_str() {
+                "prop" | "class" | "style" => self.directive_with_name(dir, ident, dyn_value),
+                "on" | "bind" => syn::Error::new(
+                    dir.span(),
+                    format!("`{dir}` directive does not accept hyphenated names"),
+                )
+                .to_compile_error(),
+                _ => syn::Error::new(dir.span(), format!("unknown directive `{dir}`"))
+                    .to_compile_error(),
+            },
+            PropType::Action { path } => quote! { .use_action(#path, #value) },
             PropType::Ref => quote! { .r#ref(#value) },
             PropType::Spread => quote! { .spread(#value) },
         }
     }
 
+    /// Generate a directive whose name is passed as a string.
+    fn directive_with_name(&self, dir: &Ident, name: &str, value: TokenStream) -> TokenStream {
+        match dir.to_string().as_str() {
+            "prop" => quote! { .prop(#name, #value) },
+            "class" => quote! { .toggle_class(#name, #value) },
+            "style" => quote! { .style_property(#name, #value) },
+            _ => unreachable!("not a directive with a name"),
+        }
+    }
+
     pub fn component(
         &self,
         TagNode {
@@ -153,17 +493,33 @@ impl Codegen {
             .collect::<Vec<_>>();
         let other_attributes = other_props.iter().map(|prop| self.attribute(prop));
 
-        let children_quoted = if children.0.is_empty() {
+        // Slots are passed as separate props. The rest of the nodes are the children.
+        let (slots, children): (Vec<_>, Vec<_>) = children
+            .0
+            .iter()
+            .partition(|child| matches!(child, Node::Slot(_)));
+        let slots = slots.into_iter().map(|slot| {
+            let Node::Slot(SlotNode { name, children }) = slot else {
+                unreachable!()
+            };
+            let children = self.root(children);
+            quote! {
+                .#name(::std::convert::From::from(move || {
+                    #children
+                }))
+            }
+        });
+
+        let children_quoted = if children.is_empty() {
             quote! {}
         } else {
-            let codegen = Codegen {};
-            let children = codegen.root(children);
+            let children = self.nodes(&children);
+            // The conversion allows the children to be passed as either `Children` or
+            // `ChildrenFn`.
             quote! {
-                .children(
-                    ::sycamore::rt::Children::new(move || {
-                        #children
-                    })
-                )
+                .children(::std::convert::From::from(move || {
+                    #children
+                }))
             }
         };
         quote! {{
@@ -173,6 +529,7 @@ impl Codegen {
                 ::sycamore::rt::element_like_component_builder(__component)
                     #(.#plain_names(#plain_values))*
                     #(#other_attributes)*
+                    #(#slots)*
                     #children_quoted
                     .build()
             ))
@@ -180,7 +537,46 @@ impl Codegen {
     }
 }
 
-fn is_component(ident: &TagIdent) -> bool {
+/// Generate the `EventOptions` for the modifiers of an `on:*` directive. The modifiers are
+/// already validated by the parser.
+fn event_options(modifiers: &[Ident]) -> TokenStream {
+    let options = modifiers.iter().map(|modifier| {
+        match KEY_MODIFIERS.iter().find(|(alias, _)| modifier == alias) {
+            Some((_, key)) => quote! { .key(#key) },
+            None => quote! { .#modifier() },
+        }
+    });
+    quote! { ::sycamore::rt::events::EventOptions::new()#(#options)* }
+}
+
+/// Returns whether the prop is a `class:*` or `style:*` directive.
+fn is_class_or_style_directive(prop: &Prop) -> bool {
+    match &prop.ty {
+        PropType::Directive { dir, .. } | PropType::DirectiveHyphenated { dir, .. } => {
+            dir == "class" || dir == "style"
+        }
+        _ => false,
+    }
+}
+
+fn is_bind_directive(prop: &Prop) -> bool {
+    matches!(&prop.ty, PropType::Directive { dir, .. } if dir == "bind")
+}
+
+/// Returns the props of an element in the order in which they are set. `class:*` and `style:*`
+/// directives are set after the other props so that they are merged with the `class` and `style`
+/// attributes instead of being overwritten by them. `bind:*` directives are set last since they can
+/// depend on other attributes, such as the `value` of a radio button. The other props, including
+/// spreads, are set in source order.
+fn ordered_props(props: &[Prop]) -> impl Iterator<Item = &Prop> {
+    let (binds, others): (Vec<_>, Vec<_>) = props.iter().partition(|prop| is_bind_directive(prop));
+    let (directives, others): (Vec<_>, Vec<_>) = others
+        .into_iter()
+        .partition(|prop| is_class_or_style_directive(prop));
+    others.into_iter().chain(directives).chain(binds)
+}
+
+pub(crate) fn is_component(ident: &TagIdent) -> bool {
     match ident {
         TagIdent::Path(path) => {
             path.get_ident().is_none()
@@ -248,6 +644,37 @@ fn is_dyn(ex: &Expr) -> bool {
     }
 }
 
+/// Returns whether the condition of an `if` binds any variables with `let`.
+fn cond_binds(cond: &Expr) -> bool {
+    match cond {
+        Expr::Let(expr) => pat_binds(&expr.pat),
+        Expr::Binary(expr) => cond_binds(&expr.left) || cond_binds(&expr.right),
+        _ => false,
+    }
+}
+
+/// Returns whether `pat` binds any variables. Identifiers that start with an uppercase letter, e.g.
+/// `None`, are assumed to be constants or unit variants.
+fn pat_binds(pat: &Pat) -> bool {
+    match pat {
+        Pat::Ident(id) => {
+            id.by_ref.is_some()
+                || id.mutability.is_some()
+                || id.subpat.is_some()
+                || !id.ident.to_string().starts_with(char::is_uppercase)
+        }
+        Pat::Or(o) => o.cases.iter().any(pat_binds),
+        Pat::Paren(p) => pat_binds(&p.pat),
+        Pat::Reference(r) => pat_binds(&r.pat),
+        Pat::Slice(s) => s.elems.iter().any(pat_binds),
+        Pat::Struct(s) => s.fields.iter().any(|field| pat_binds(&field.pat)),
+        Pat::Tuple(t) => t.elems.iter().any(pat_binds),
+        Pat::TupleStruct(s) => s.elems.iter().any(pat_binds),
+        Pat::Type(t) => pat_binds(&t.pat),
+        _ => false,
+    }
+}
+
 fn is_dyn_pattern(pat: &Pat) -> bool {
     match pat {
         Pat::Wild(_) | Pat::Lit(_) | Pat::Path(_) | Pat::Rest(_) | Pat::Type(_) | Pat::Const(_) => {
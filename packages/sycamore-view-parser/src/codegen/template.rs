@@ -0,0 +1,749 @@
+//! Codegen for [`Mode::Template`].
+//!
+//! The static parts of an element tree (elements, static attributes and text) are written into an
+//! HTML string. Everything else becomes a "hole" which is filled in after the template is cloned:
+//! elements with dynamic attributes are wrapped into their element type so that the same attribute
+//! methods as with the builders can be used, and dynamic children are inserted in place of a
+//! marker comment.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Expr, ExprLit, Ident, Lit, LitStr};
+
+use super::{Codegen, Mode};
+use crate::ir::{Node, Prop, PropType, TagIdent, TagNode, TextNode};
+use crate::validate::CLOSES_P;
+use crate::validate::elements::{HTML_ELEMENTS, VOID_ELEMENTS};
+
+/// HTML elements that can be part of a template.
+///
+/// Elements that the HTML parser treats specially (e.g. tables, `<select>`, raw text elements and
+/// the document metadata) are left out since parsing them might not produce the same tree as the
+/// builders. They are created using the builders instead.
+const TEMPLATE_ELEMENTS: &[&str] = &[
+    "a",
+    "abbr",
+    "address",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "bdi",
+    "bdo",
+    "blockquote",
+    "br",
+    "button",
+    "canvas",
+    "cite",
+    "code",
+    "data",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "i",
+    "img",
+    "input",
+    "ins",
+    "kbd",
+    "label",
+    "legend",
+    "li",
+    "main",
+    "mark",
+    "menu",
+    "meter",
+    "nav",
+    "ol",
+    "output",
+    "p",
+    "picture",
+    "progress",
+    "q",
+    "s",
+    "samp",
+    "section",
+    "small",
+    "source",
+    "span",
+    "strong",
+    "sub",
+    "summary",
+    "sup",
+    "time",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+];
+
+/// Element builder methods that set the attribute with the same name to a string. Static values
+/// of these attributes are written directly into the template.
+const STATIC_ATTRIBUTES: &[&str] = &[
+    "alt",
+    "class",
+    "dir",
+    "for",
+    "href",
+    "id",
+    "lang",
+    "name",
+    "placeholder",
+    "rel",
+    "role",
+    "src",
+    "style",
+    "target",
+    "title",
+    "type",
+];
+
+/// The open elements that change how a child element is parsed.
+#[derive(Clone, Copy, Default)]
+struct Scope<'a> {
+    parent: Option<&'a str>,
+    in_p: bool,
+    in_a: bool,
+    in_button: bool,
+    in_form: bool,
+    in_li: bool,
+    in_dd_dt: bool,
+}
+
+impl<'a> Scope<'a> {
+    /// Returns whether the element `tag` can be put in the template at this position without the
+    /// HTML parser closing any of the open elements.
+    fn allows(&self, tag: &str) -> bool {
+        let is_heading = |tag: &str| matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6");
+        !(self.in_p && CLOSES_P.contains(&tag)
+            || self.in_a && tag == "a"
+            || self.in_button && tag == "button"
+            || self.in_form && tag == "form"
+            || self.in_li && tag == "li"
+            || self.in_dd_dt && matches!(tag, "dd" | "dt")
+            || self.parent.is_some_and(is_heading) && is_heading(tag))
+    }
+
+    /// Returns the scope for the children of the element `tag`.
+    fn enter(&self, tag: &'a str) -> Self {
+        Self {
+            parent: Some(tag),
+            in_p: (self.in_p || tag == "p") && tag != "button",
+            in_a: self.in_a || tag == "a",
+            in_button: self.in_button || tag == "button",
+            in_form: self.in_form || tag == "form",
+            in_li: (self.in_li || tag == "li") && !matches!(tag, "ul" | "ol" | "menu"),
+            in_dd_dt: (self.in_dd_dt || matches!(tag, "dd" | "dt")) && tag != "dl",
+        }
+    }
+}
+
+/// Returns the tag name of `element` if it can be put into a template.
+fn template_tag(element: &TagNode) -> Option<String> {
+    let TagIdent::Path(path) = &element.ident else {
+        return None;
+    };
+    let tag = path.get_ident()?.to_string();
+    let is_void = VOID_ELEMENTS.contains(&tag.as_str());
+    (TEMPLATE_ELEMENTS.contains(&tag.as_str()) && (!is_void || element.children.0.is_empty()))
+        .then_some(tag)
+}
+
+/// Returns the name of the attribute that is set by `prop`, if any.
+fn attribute_name(prop: &Prop) -> Option<String> {
+    match &prop.ty {
+        PropType::Plain { ident } => Some(syn::ext::IdentExt::unraw(ident).to_string()),
+        PropType::PlainHyphenated { ident } | PropType::PlainQuoted { ident } => {
+            Some(ident.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the attribute name and value if `prop` is a static attribute that can be written as
+/// HTML.
+fn static_attribute(prop: &Prop) -> Option<(String, String)> {
+    let name = attribute_name(prop)?;
+    let is_valid = match &prop.ty {
+        PropType::Plain { .. } => STATIC_ATTRIBUTES.contains(&name.as_str()),
+        _ => {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':'))
+        }
+    };
+    if !is_valid {
+        return None;
+    }
+    match &prop.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Some((name, value.value())),
+        _ => None,
+    }
+}
+
+/// Returns the attribute name and value if `prop` of `element` can be written directly into the
+/// template.
+///
+/// If attributes are spread onto the element or an attribute is set multiple times, the attributes
+/// are set in the same order as with the builders instead.
+fn inline_attribute(element: &TagNode, prop: &Prop) -> Option<(String, String)> {
+    if element
+        .props
+        .iter()
+        .any(|prop| matches!(prop.ty, PropType::Spread))
+    {
+        return None;
+    }
+    let (name, value) = static_attribute(prop)?;
+    let count = element
+        .props
+        .iter()
+        .filter_map(attribute_name)
+        .filter(|other| *other == name)
+        .count();
+    (count == 1).then_some((name, value))
+}
+
+fn escape_text(text: &str, html: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+fn escape_attribute(value: &str, html: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+/// A part of a pre-rendered element tree.
+enum Segment<'a> {
+    /// Static HTML, split at the places where the hydration keys of the elements are inserted
+    /// (after the attributes of every element).
+    Static(Vec<String>),
+    /// A child that is created at runtime.
+    Dynamic(&'a Node),
+}
+
+/// Returns the last chunk of static HTML in `segments`, starting a new static segment if the last
+/// segment is dynamic.
+fn static_html<'s>(segments: &'s mut Vec<Segment<'_>>) -> &'s mut String {
+    if !matches!(segments.last(), Some(Segment::Static(_))) {
+        segments.push(Segment::Static(vec![String::new()]));
+    }
+    let Some(Segment::Static(chunks)) = segments.last_mut() else {
+        unreachable!()
+    };
+    chunks.last_mut().unwrap()
+}
+
+/// Returns the tag name of `element` if it can be pre-rendered, i.e. it is an HTML element and all
+/// of its attributes are static. Its children may still be dynamic.
+fn prerender_tag(element: &TagNode) -> Option<String> {
+    let TagIdent::Path(path) = &element.ident else {
+        return None;
+    };
+    let tag = path.get_ident()?.to_string();
+    let is_void = VOID_ELEMENTS.contains(&tag.as_str());
+    // Unlike templates, pre-rendered HTML is never parsed so every HTML element can be
+    // pre-rendered.
+    let is_html = HTML_ELEMENTS.iter().any(|(name, _)| *name == tag);
+    if !is_html || is_void && !element.children.0.is_empty() {
+        return None;
+    }
+    element
+        .props
+        .iter()
+        .all(|prop| inline_attribute(element, prop).is_some())
+        .then_some(tag)
+}
+
+/// Pre-renders `element` to HTML and appends it to `segments`. Children that can not be
+/// pre-rendered are appended as dynamic segments in between the static HTML.
+fn prerender<'a>(element: &'a TagNode, tag: &str, segments: &mut Vec<Segment<'a>>) {
+    let html = static_html(segments);
+    html.push('<');
+    html.push_str(tag);
+    for prop in &element.props {
+        let (name, value) = inline_attribute(element, prop).unwrap();
+        html.push(' ');
+        html.push_str(&name);
+        html.push_str("=\"");
+        escape_attribute(&value, html);
+        html.push('"');
+    }
+    let Some(Segment::Static(chunks)) = segments.last_mut() else {
+        unreachable!()
+    };
+    chunks.push(">".to_string());
+    if VOID_ELEMENTS.contains(&tag) {
+        return;
+    }
+
+    for child in &element.children.0 {
+        match child {
+            Node::Text(TextNode { value }) => escape_text(&value.value(), static_html(segments)),
+            Node::Tag(child_element) => match prerender_tag(child_element) {
+                Some(child_tag) => prerender(child_element, &child_tag, segments),
+                None => segments.push(Segment::Dynamic(child)),
+            },
+            Node::Dyn(_) | Node::If(_) | Node::Match(_) | Node::For(_) | Node::Slot(_) => {
+                segments.push(Segment::Dynamic(child));
+            }
+        }
+    }
+    let html = static_html(segments);
+    html.push_str("</");
+    html.push_str(tag);
+    html.push('>');
+}
+
+/// State for generating a single template.
+struct TemplateBuilder<'a> {
+    codegen: &'a Codegen,
+    html: String,
+    /// Statements that get the nodes in the template that need to be filled in. These run before
+    /// the cloned template is modified.
+    walk: Vec<TokenStream>,
+    /// Statements that fill in the holes of the template.
+    fill: Vec<TokenStream>,
+    /// The number of static nodes in the template other than the root.
+    static_nodes: usize,
+    next_id: usize,
+}
+
+impl TemplateBuilder<'_> {
+    fn new_var(&mut self) -> Ident {
+        let var = Ident::new(&format!("__n{}", self.next_id), Span::mixed_site());
+        self.next_id += 1;
+        var
+    }
+
+    /// Returns whether the element (or any of its descendants) needs to be filled in.
+    fn has_holes(element: &TagNode, tag: &str, scope: Scope<'_>) -> bool {
+        let scope = scope.enter(tag);
+        element
+            .props
+            .iter()
+            .any(|prop| inline_attribute(element, prop).is_none())
+            || element.children.0.iter().any(|child| match child {
+                Node::Text(_) => false,
+                Node::Tag(child) => match template_tag(child) {
+                    Some(child_tag) if scope.allows(&child_tag) => {
+                        Self::has_holes(child, &child_tag, scope)
+                    }
+                    _ => true,
+                },
+                Node::Dyn(_) | Node::If(_) | Node::Match(_) | Node::For(_) | Node::Slot(_) => true,
+            })
+    }
+
+    /// Adds `element`, which is referenced by `var`, to the template. Returns the attributes that
+    /// need to be set after the template is cloned.
+    fn element(
+        &mut self,
+        element: &TagNode,
+        tag: &str,
+        var: &Ident,
+        scope: Scope<'_>,
+    ) -> Vec<TokenStream> {
+        self.html.push('<');
+        self.html.push_str(tag);
+        let mut attributes = Vec::new();
+        for prop in super::ordered_props(&element.props) {
+            if let Some((name, value)) = inline_attribute(element, prop) {
+                self.html.push(' ');
+                self.html.push_str(&name);
+                self.html.push_str("=\"");
+                escape_attribute(&value, &mut self.html);
+                self.html.push('"');
+            } else {
+                attributes.push(self.codegen.element_attribute(element, prop));
+            }
+        }
+        self.html.push('>');
+        if VOID_ELEMENTS.contains(&tag) {
+            return attributes;
+        }
+
+        let scope = scope.enter(tag);
+        // The index of the next child in the DOM, taking into account that adjacent text nodes
+        // are merged when the template is parsed.
+        let mut index = 0;
+        let mut after_text = false;
+        // The last child that was referenced, which is used as the starting point for walking to
+        // the next one.
+        let mut prev: Option<(Ident, usize)> = None;
+        for child in &element.children.0 {
+            match child {
+                Node::Text(TextNode { value }) => {
+                    let value = value.value();
+                    if !value.is_empty() {
+                        escape_text(&value, &mut self.html);
+                        if !after_text {
+                            index += 1;
+                            self.static_nodes += 1;
+                        }
+                        after_text = true;
+                    }
+                    continue;
+                }
+                Node::Tag(child_element) => match template_tag(child_element) {
+                    Some(child_tag) if scope.allows(&child_tag) => {
+                        self.static_nodes += 1;
+                        if Self::has_holes(child_element, &child_tag, scope) {
+                            let child_var = self.walk_to(var, &mut prev, index);
+                            let child_attributes =
+                                self.element(child_element, &child_tag, &child_var, scope);
+                            if !child_attributes.is_empty() {
+                                let tag = Ident::new(&child_tag, Span::call_site());
+                                self.fill.push(quote! {
+                                    ::sycamore::rt::Template::element(
+                                        ::sycamore::rt::tags::#tag, #child_var
+                                    )#(#child_attributes)*;
+                                });
+                            }
+                        } else {
+                            let child_attributes =
+                                self.element(child_element, &child_tag, var, scope);
+                            debug_assert!(child_attributes.is_empty());
+                        }
+                    }
+                    _ => self.hole(child, var, &mut prev, index),
+                },
+                Node::Dyn(_) | Node::If(_) | Node::Match(_) | Node::For(_) | Node::Slot(_) => {
+                    self.hole(child, var, &mut prev, index)
+                }
+            }
+            index += 1;
+            after_text = false;
+        }
+
+        self.html.push_str("</");
+        self.html.push_str(tag);
+        self.html.push('>');
+        attributes
+    }
+
+    /// Adds a marker for a child that is created at runtime.
+    fn hole(
+        &mut self,
+        child: &Node,
+        parent: &Ident,
+        prev: &mut Option<(Ident, usize)>,
+        index: usize,
+    ) {
+        self.html.push_str("<!---->");
+        let marker = self.walk_to(parent, prev, index);
+        let view = self.codegen.node(child);
+        self.fill.push(quote! {
+            ::sycamore::rt::Template::insert(#marker, #view);
+        });
+    }
+
+    /// Generates a statement that gets the child at `index` of `parent`.
+    fn walk_to(
+        &mut self,
+        parent: &Ident,
+        prev: &mut Option<(Ident, usize)>,
+        index: usize,
+    ) -> Ident {
+        let (start, steps) = match prev {
+            Some((prev, prev_index)) => (quote! { #prev }, index - *prev_index),
+            None => (quote! { #parent.first_child().unwrap() }, index),
+        };
+        let steps = (0..steps).map(|_| quote! { .next_sibling().unwrap() });
+        let var = self.new_var();
+        self.walk.push(quote! {
+            let #var = #start #(#steps)*;
+        });
+        *prev = Some((var.clone(), index));
+        var
+    }
+}
+
+impl Codegen {
+    /// Generate an element by cloning a template. Returns `None` if the element can not be put
+    /// into a template or if it would not benefit from it.
+    pub(super) fn template(&self, element: &TagNode) -> Option<TokenStream> {
+        let tag = template_tag(element)?;
+        let mut builder = TemplateBuilder {
+            codegen: self,
+            html: String::new(),
+            walk: Vec::new(),
+            fill: Vec::new(),
+            static_nodes: 0,
+            next_id: 1,
+        };
+        let root = Ident::new("__n0", Span::mixed_site());
+        let attributes = builder.element(element, &tag, &root, Scope::default());
+        if builder.static_nodes == 0 {
+            return None;
+        }
+
+        let TemplateBuilder {
+            html, walk, fill, ..
+        } = builder;
+        let html = LitStr::new(&html, Span::call_site());
+        let tag = Ident::new(&tag, Span::call_site());
+        let fallback = Codegen {
+            mode: Mode::Builder,
+            ..*self
+        }
+        .element_builder(element);
+        Some(quote! {{
+            ::std::thread_local! {
+                static __TEMPLATE: ::sycamore::rt::Template =
+                    const { ::sycamore::rt::Template::new(#html) };
+            }
+            match __TEMPLATE.with(::sycamore::rt::Template::instantiate) {
+                ::std::option::Option::Some(#root) => {
+                    #(#walk)*
+                    #(#fill)*
+                    ::sycamore::rt::View::from(
+                        ::sycamore::rt::Template::element(::sycamore::rt::tags::#tag, #root)
+                            #(#attributes)*
+                    )
+                }
+                ::std::option::Option::None => #fallback,
+            }
+        }})
+    }
+}
+
+impl Codegen {
+    /// Generate an element from HTML that is pre-rendered at compile time, which is used in SSR
+    /// mode. The children that can not be pre-rendered are created at runtime and rendered in
+    /// between the static HTML. Returns `None` if the element itself can not be pre-rendered.
+    pub(super) fn prerendered(&self, element: &TagNode) -> Option<TokenStream> {
+        let tag = prerender_tag(element)?;
+        let mut segments = Vec::new();
+        prerender(element, &tag, &mut segments);
+
+        // The segments are created in order so that the hydration keys are the same as with the
+        // builders.
+        let mut segments = segments.into_iter().map(|segment| match segment {
+            Segment::Static(chunks) => {
+                quote! { ::sycamore::rt::Template::prerendered(&[#(#chunks),*]) }
+            }
+            Segment::Dynamic(node) => self.node(node),
+        });
+        let first = segments.next().unwrap();
+        let view = if segments.len() == 0 {
+            quote! { __view }
+        } else {
+            let rest = segments.map(|segment| {
+                quote! { ::std::convert::Into::<::sycamore::rt::View>::into(#segment) }
+            });
+            quote! {
+                ::std::convert::Into::<::sycamore::rt::View>::into(::std::vec![__view, #(#rest),*])
+            }
+        };
+        let fallback = Codegen {
+            mode: Mode::Builder,
+            prerender: false,
+            ..*self
+        }
+        .element_builder(element);
+        Some(quote! {
+            match #first {
+                ::std::option::Option::Some(__view) => #view,
+                ::std::option::Option::None => #fallback,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::Root;
+
+    fn template_html(input: &str) -> Option<String> {
+        let root: Root = syn::parse_str(input).unwrap();
+        let [Node::Tag(element)] = &root.0[..] else {
+            panic!("expected a single element");
+        };
+        let tag = template_tag(element)?;
+        let codegen = Codegen {
+            mode: Mode::Template,
+            ..Codegen::default()
+        };
+        let mut builder = TemplateBuilder {
+            codegen: &codegen,
+            html: String::new(),
+            walk: Vec::new(),
+            fill: Vec::new(),
+            static_nodes: 0,
+            next_id: 1,
+        };
+        let root = Ident::new("__n0", Span::mixed_site());
+        builder.element(element, &tag, &root, Scope::default());
+        Some(builder.html)
+    }
+
+    /// Returns the pre-rendered segments of `input`, with `None` for the dynamic segments.
+    fn prerendered_segments(input: &str) -> Option<Vec<Option<Vec<String>>>> {
+        let root: Root = syn::parse_str(input).unwrap();
+        let [Node::Tag(element)] = &root.0[..] else {
+            panic!("expected a single element");
+        };
+        let tag = prerender_tag(element)?;
+        let mut segments = Vec::new();
+        prerender(element, &tag, &mut segments);
+        Some(
+            segments
+                .into_iter()
+                .map(|segment| match segment {
+                    Segment::Static(chunks) => Some(chunks),
+                    Segment::Dynamic(_) => None,
+                })
+                .collect(),
+        )
+    }
+
+    fn chunks(chunks: &[&str]) -> Option<Vec<String>> {
+        Some(chunks.iter().map(|chunk| chunk.to_string()).collect())
+    }
+
+    #[test]
+    fn prerender_static_elements() {
+        assert_eq!(
+            prerendered_segments(
+                r#"div(class="a") { p { "Hello " "<World>" } input(r#type="text") table {} }"#
+            ),
+            Some(vec![chunks(&[
+                r#"<div class="a""#,
+                "><p",
+                ">Hello &lt;World&gt;</p><input type=\"text\"",
+                "><table",
+                "></table></div>",
+            ])])
+        );
+    }
+
+    #[test]
+    fn prerender_dynamic_children() {
+        assert_eq!(
+            prerendered_segments(r#"div { p { "a" (b) } span(class=c) {} "d" Comp {} (e) (f) }"#),
+            Some(vec![
+                chunks(&["<div", "><p", ">a"]),
+                None,
+                chunks(&["</p>"]),
+                None,
+                chunks(&["d"]),
+                None,
+                None,
+                None,
+                chunks(&["</div>"]),
+            ])
+        );
+    }
+
+    #[test]
+    fn prerender_dynamic_elements() {
+        assert_eq!(prerendered_segments("div(class=value) {}"), None);
+        assert_eq!(prerendered_segments("div(on:click=f) {}"), None);
+        assert_eq!(prerendered_segments("my-element {}"), None);
+        assert_eq!(prerendered_segments("svg {}"), None);
+        assert_eq!(prerendered_segments(r#"input { "child" }"#), None);
+    }
+
+    #[test]
+    fn static_html() {
+        assert_eq!(
+            template_html(
+                r#"div(class="a", id="b", data-x="c") { p { "Hello " "World!" } br {} }"#
+            )
+            .as_deref(),
+            Some(r#"<div class="a" id="b" data-x="c"><p>Hello World!</p><br></div>"#)
+        );
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            template_html(r#"div(title="\"&") { "<a> & <b>" }"#).as_deref(),
+            Some(r#"<div title="&quot;&amp;">&lt;a&gt; &amp; &lt;b&gt;</div>"#)
+        );
+    }
+
+    #[test]
+    fn holes() {
+        assert_eq!(
+            template_html(r#"div(on:click=f) { "a" (b) Comp {} span(class=c) {} my-element {} }"#)
+                .as_deref(),
+            Some(r#"<div>a<!----><!----><span></span><!----></div>"#)
+        );
+    }
+
+    #[test]
+    fn duplicate_and_spread_attributes_are_not_inlined() {
+        assert_eq!(
+            template_html(r#"div(class="a", class="b") { "x" }"#).as_deref(),
+            Some("<div>x</div>")
+        );
+        assert_eq!(
+            template_html(r#"div(class=a, class="b") { "x" }"#).as_deref(),
+            Some("<div>x</div>")
+        );
+        assert_eq!(
+            template_html(r#"div(class="a", ..attrs) { "x" }"#).as_deref(),
+            Some("<div>x</div>")
+        );
+    }
+
+    #[test]
+    fn special_elements_are_not_templated() {
+        assert_eq!(template_html("table { tr {} }"), None);
+        assert_eq!(template_html(r#"input { "child" }"#), None);
+        assert_eq!(
+            template_html("p { span {} div {} }").as_deref(),
+            Some("<p><span></span><!----></p>")
+        );
+        assert_eq!(
+            template_html("ul { li { div { li {} } } }").as_deref(),
+            Some("<ul><li><div><!----></div></li></ul>")
+        );
+        assert_eq!(
+            template_html("a { span { a {} } }").as_deref(),
+            Some("<a><span><!----></span></a>")
+        );
+    }
+}
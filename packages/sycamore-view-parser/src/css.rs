@@ -0,0 +1,300 @@
+//! Scoping of the CSS of the `css!` macro.
+//!
+//! Every selector is scoped by adding an attribute selector to its last compound selector, e.g.
+//! `.card p` becomes `.card p[data-css-1a2b3c4d]`. The `view!` macro then adds this attribute to
+//! the elements of the views that the CSS is applied to.
+
+/// The result of [`scope_css`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScopedCss {
+    /// The attribute that scopes the CSS, e.g. `data-css-1a2b3c4d`.
+    pub attribute: String,
+    /// The CSS with every selector scoped.
+    pub css: String,
+    /// The variables that are interpolated with `$name`, as `(name, custom property)` pairs in
+    /// order of first appearance.
+    pub vars: Vec<(String, String)>,
+}
+
+/// At-rules whose blocks contain rules with selectors that need to be scoped. The blocks of the
+/// other at-rules, e.g. `@keyframes` and `@font-face`, are left as is.
+const NESTING_AT_RULES: &[&str] = &["media", "supports", "container", "layer", "scope"];
+
+/// Scopes the selectors of `source`.
+///
+/// The attribute is derived from a hash of `source` so that it is the same when the CSS is
+/// compiled for the server and for the client. `$name` is replaced with the custom property
+/// `var(--name)` and selectors wrapped in `:global(...)` are not scoped.
+pub fn scope_css(source: &str) -> Result<ScopedCss, String> {
+    let attribute = format!("data-css-{:08x}", fnv1a(source));
+    let (source, vars) = replace_vars(&strip_comments(source))?;
+    let mut css = String::new();
+    scope_rules(&source, &attribute, &mut css)?;
+    Ok(ScopedCss {
+        attribute,
+        css,
+        vars,
+    })
+}
+
+/// The 32-bit FNV-1a hash of `s`.
+fn fnv1a(s: &str) -> u32 {
+    s.bytes().fold(0x811c9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+    })
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Replaces every `$name` with `var(--name)`. Underscores in `name` are replaced with hyphens in
+/// the custom property.
+fn replace_vars(source: &str) -> Result<(String, Vec<(String, String)>), String> {
+    let mut out = String::with_capacity(source.len());
+    let mut vars = Vec::<(String, String)>::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+        let start = i + 1;
+        let mut end = start;
+        while let Some(&(j, c)) = chars.peek() {
+            let valid = if j == start {
+                c.is_ascii_alphabetic() || c == '_'
+            } else {
+                c.is_ascii_alphanumeric() || c == '_'
+            };
+            if !valid {
+                break;
+            }
+            end = j + c.len_utf8();
+            chars.next();
+        }
+        if start == end {
+            return Err("expected a variable name after `$`".to_string());
+        }
+        let name = &source[start..end];
+        let property = format!("--{}", name.replace('_', "-"));
+        out.push_str(&format!("var({property})"));
+        if !vars.iter().any(|(var, _)| var == name) {
+            vars.push((name.to_string(), property));
+        }
+    }
+    Ok((out, vars))
+}
+
+/// Returns the index of the first occurrence of one of `targets` in `s` that is not nested inside
+/// brackets, parentheses or strings.
+fn find_top_level(s: &str, targets: &[char]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if depth == 0 && targets.contains(&c) => return Some(i),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+/// Returns the index of the `}` that closes the block starting right after `s`.
+fn find_block_end(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut rest = s;
+    let mut offset = 0;
+    while let Some(i) = find_top_level(rest, &['{', '}']) {
+        if &rest[i..i + 1] == "{" {
+            depth += 1;
+        } else if depth == 0 {
+            return Some(offset + i);
+        } else {
+            depth -= 1;
+        }
+        offset += i + 1;
+        rest = &rest[i + 1..];
+    }
+    None
+}
+
+fn scope_rules(source: &str, attribute: &str, out: &mut String) -> Result<(), String> {
+    let mut rest = source.trim();
+    while !rest.is_empty() {
+        let Some(i) = find_top_level(rest, &['{', ';', '}']) else {
+            return Err(format!("expected `{{` after `{rest}`"));
+        };
+        let prelude = rest[..i].trim();
+        match &rest[i..i + 1] {
+            ";" => {
+                // A statement at-rule such as `@import`.
+                out.push_str(prelude);
+                out.push(';');
+                rest = rest[i + 1..].trim_start();
+                continue;
+            }
+            "}" => return Err("unexpected `}`".to_string()),
+            _ => {}
+        }
+
+        let block_start = i + 1;
+        let Some(len) = find_block_end(&rest[block_start..]) else {
+            return Err(format!("unclosed block after `{prelude}`"));
+        };
+        let block = &rest[block_start..block_start + len];
+        if let Some(at_rule) = prelude.strip_prefix('@') {
+            let name = at_rule
+                .split(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+                .next()
+                .unwrap_or_default();
+            out.push_str(prelude);
+            out.push('{');
+            if NESTING_AT_RULES.contains(&name) {
+                scope_rules(block, attribute, out)?;
+            } else {
+                out.push_str(block.trim());
+            }
+        } else {
+            scope_selector_list(prelude, attribute, out);
+            out.push('{');
+            out.push_str(block.trim());
+        }
+        out.push('}');
+        rest = rest[block_start + len + 1..].trim_start();
+    }
+    Ok(())
+}
+
+fn scope_selector_list(selectors: &str, attribute: &str, out: &mut String) {
+    let mut rest = selectors;
+    loop {
+        let end = find_top_level(rest, &[',']).unwrap_or(rest.len());
+        scope_selector(rest[..end].trim(), attribute, out);
+        if end == rest.len() {
+            break;
+        }
+        out.push(',');
+        rest = &rest[end + 1..];
+    }
+}
+
+fn scope_selector(selector: &str, attribute: &str, out: &mut String) {
+    if let Some(global) = selector
+        .strip_prefix(":global(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        out.push_str(global.trim());
+        return;
+    }
+
+    // Find the start of the last compound selector, after the last combinator.
+    let mut compound_start = 0;
+    let mut rest = selector;
+    while let Some(i) = find_top_level(rest, &[' ', '\t', '\n', '\r', '>', '+', '~']) {
+        compound_start += i + 1;
+        rest = &rest[i + 1..];
+    }
+    // The attribute selector must come before a pseudo-element.
+    let insert_at = match rest.find("::") {
+        Some(i) => compound_start + i,
+        None => selector.len(),
+    };
+    out.push_str(&selector[..insert_at]);
+    out.push('[');
+    out.push_str(attribute);
+    out.push(']');
+    out.push_str(&selector[insert_at..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scoped(source: &str) -> String {
+        let mut css = String::new();
+        scope_rules(source, "data-s", &mut css).unwrap();
+        css
+    }
+
+    #[test]
+    fn scope_selectors() {
+        assert_eq!(scoped("p { color: red; }"), "p[data-s]{color: red;}");
+        assert_eq!(
+            scoped(".card  p, a:hover > .icon::before {}"),
+            ".card  p[data-s],a:hover > .icon[data-s]::before{}"
+        );
+        assert_eq!(
+            scoped(r#"input[type="text"] ~ label:not(.a, .b) {}"#),
+            r#"input[type="text"] ~ label:not(.a, .b)[data-s]{}"#
+        );
+        assert_eq!(scoped(":global(body) { margin: 0 }"), "body{margin: 0}");
+    }
+
+    #[test]
+    fn scope_at_rules() {
+        assert_eq!(
+            scoped("@import url(a.css); @media (max-width: 600px) { p { margin: 0; } }"),
+            "@import url(a.css);@media (max-width: 600px){p[data-s]{margin: 0;}}"
+        );
+        assert_eq!(
+            scoped("@keyframes spin { from { rotate: 0deg } to { rotate: 360deg } }"),
+            "@keyframes spin{from { rotate: 0deg } to { rotate: 360deg }}"
+        );
+    }
+
+    #[test]
+    fn comments_and_vars() {
+        let scoped = scope_css("/* {} */ p { color: $text_color; background: $bg; }").unwrap();
+        assert_eq!(
+            scoped.css,
+            format!(
+                "p[{}]{{color: var(--text-color); background: var(--bg);}}",
+                scoped.attribute
+            )
+        );
+        assert_eq!(
+            scoped.vars,
+            vec![
+                ("text_color".to_string(), "--text-color".to_string()),
+                ("bg".to_string(), "--bg".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn attribute_is_stable() {
+        assert_eq!(scope_css("p {}").unwrap().attribute, "data-css-1e7316fd");
+        assert_ne!(
+            scope_css("p {}").unwrap().attribute,
+            scope_css("a {}").unwrap().attribute
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(scope_css("p { color: $; }").is_err());
+        assert!(scope_css("p { color: red;").is_err());
+        assert!(scope_css("p").is_err());
+        assert!(scope_css("}").is_err());
+    }
+}
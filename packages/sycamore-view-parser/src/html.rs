@@ -0,0 +1,318 @@
+//! Parse the HTML-like syntax of the `html!` macro.
+//!
+//! The syntax is parsed into the same intermediate representation as the `view!` macro so that
+//! the same codegen can be used for both. For example, the following is equivalent to
+//! `div(class="container") { p { "Hello, " (name) } br {} }`:
+//!
+//! ```text
+//! <div class="container">
+//!     <p>"Hello, " {name}</p>
+//!     <br />
+//! </div>
+//! ```
+//!
+//! Text can also be written without quotes, as in `<p>Hello, world!</p>`. Since the macro only
+//! sees the tokens of the text, whitespace is taken from the location of the tokens in the source
+//! and collapsed into a single space, like HTML does.
+
+use proc_macro2::{Delimiter, LineColumn, Span, TokenStream, TokenTree};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Brace;
+use syn::{Expr, Lit, LitStr, Result, Token, braced, parse_quote};
+
+use crate::ir::*;
+use crate::validate::elements::VOID_ELEMENTS;
+
+/// A list of nodes written in the HTML-like syntax.
+pub struct HtmlRoot(pub Root);
+
+impl Parse for HtmlRoot {
+    fn parse(input: ParseStream) -> Result<Self> {
+        parse_nodes(input).map(Self)
+    }
+}
+
+fn parse_nodes(input: ParseStream) -> Result<Root> {
+    let mut nodes = Vec::new();
+    let mut prev_end = None;
+    while !input.is_empty() {
+        nodes.push(parse_node(input, &mut prev_end)?);
+    }
+    Ok(Root(nodes))
+}
+
+/// Parses the next node. `prev_end` is the end of the previous node in the same parent, which is
+/// used to find the whitespace in front of unquoted text. It is updated with the end of this node.
+fn parse_node(input: ParseStream, prev_end: &mut Option<LineColumn>) -> Result<Node> {
+    let start = input.cursor();
+    let node = parse_node_inner(input, *prev_end)?;
+    *prev_end = last_token_end(start, input.cursor());
+    Ok(node)
+}
+
+fn parse_node_inner(input: ParseStream, prev_end: Option<LineColumn>) -> Result<Node> {
+    if input.peek(Token![<]) {
+        Ok(Node::Tag(parse_element(input)?))
+    } else if input.peek(LitStr) {
+        Ok(Node::Text(input.parse()?))
+    } else if input.peek(Brace) {
+        let content;
+        braced!(content in input);
+        Ok(Node::Dyn(DynNode {
+            value: content.parse()?,
+        }))
+    } else if input.peek(Token![if]) {
+        Ok(Node::If(IfNode::parse_with(input, parse_nodes)?))
+    } else if input.peek(Token![match]) {
+        Ok(Node::Match(MatchNode::parse_with(input, parse_nodes)?))
+    } else if input.peek(Token![for]) {
+        Ok(Node::For(Box::new(ForNode::parse_with(
+            input,
+            parse_nodes,
+        )?)))
+    } else {
+        Ok(Node::Text(TextNode {
+            value: parse_text(input, prev_end)?,
+        }))
+    }
+}
+
+/// Parses a run of unquoted text up to the next element, string literal or `{`.
+fn parse_text(input: ParseStream, prev_end: Option<LineColumn>) -> Result<LitStr> {
+    input.step(|cursor| {
+        let span = cursor.span();
+        let mut text = String::new();
+        let mut end = None;
+        let mut rest = *cursor;
+        while let Some((token, next)) = rest.token_tree() {
+            let ends_text = match &token {
+                TokenTree::Punct(punct) => punct.as_char() == '<',
+                TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
+                TokenTree::Literal(_) => next_is_str(rest),
+                TokenTree::Ident(_) => false,
+            };
+            if ends_text {
+                break;
+            }
+            write_token(&mut text, &mut end, token)?;
+            rest = next;
+        }
+        if text.is_empty() {
+            return Err(cursor.error("expected an element, text or `{`"));
+        }
+
+        // Whitespace between the text and the nodes next to it is significant, e.g. in
+        // `<b>Hello</b> world`.
+        if prev_end.is_some_and(|prev_end| prev_end != span.start()) {
+            text.insert(0, ' ');
+        }
+        let closes_parent = rest.punct().is_some_and(|(punct, next)| {
+            punct.as_char() == '<'
+                && next
+                    .punct()
+                    .is_some_and(|(punct, _)| punct.as_char() == '/')
+        });
+        if !closes_parent
+            && let Some((next, _)) = rest.token_tree()
+            && end != Some(next.span().start())
+        {
+            text.push(' ');
+        }
+        Ok((LitStr::new(&text, span), rest))
+    })
+}
+
+/// Returns whether the cursor is at a string literal.
+fn next_is_str(cursor: Cursor) -> bool {
+    cursor
+        .literal()
+        .is_some_and(|(lit, _)| matches!(Lit::new(lit), Lit::Str(_)))
+}
+
+/// Appends the text of `token` to `text`, with a space in front of it if there is whitespace
+/// between it and the previous token, which ended at `end`.
+fn write_token(text: &mut String, end: &mut Option<LineColumn>, token: TokenTree) -> Result<()> {
+    match token {
+        TokenTree::Group(group) => {
+            let (open, close) = match group.delimiter() {
+                Delimiter::Parenthesis => ("(", ")"),
+                Delimiter::Bracket => ("[", "]"),
+                Delimiter::Brace | Delimiter::None => {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        "`{` cannot be used inside of parentheses or brackets in unquoted text, \
+                         use a string literal instead",
+                    ));
+                }
+            };
+            push_text(text, end, group.span_open(), open);
+            write_tokens(text, end, group.stream())?;
+            push_text(text, end, group.span_close(), close);
+        }
+        TokenTree::Literal(lit) => match Lit::new(lit.clone()) {
+            Lit::Str(lit) => push_text(text, end, lit.span(), &lit.value()),
+            _ => push_text(text, end, lit.span(), &lit.to_string()),
+        },
+        token => push_text(text, end, token.span(), &token.to_string()),
+    }
+    Ok(())
+}
+
+/// Appends `s`, which is located at `span`, to `text`. See [`write_token`].
+fn push_text(text: &mut String, end: &mut Option<LineColumn>, span: Span, s: &str) {
+    if end.is_some_and(|end| end != span.start()) {
+        text.push(' ');
+    }
+    text.push_str(s);
+    *end = Some(span.end());
+}
+
+fn write_tokens(
+    text: &mut String,
+    end: &mut Option<LineColumn>,
+    tokens: TokenStream,
+) -> Result<()> {
+    for token in tokens {
+        write_token(text, end, token)?;
+    }
+    Ok(())
+}
+
+/// Returns the end of the last token between `start` and `end`.
+fn last_token_end(start: Cursor, end: Cursor) -> Option<LineColumn> {
+    let mut cursor = start;
+    let mut last = None;
+    while cursor < end {
+        let (token, next) = cursor.token_tree()?;
+        last = Some(token.span().end());
+        cursor = next;
+    }
+    last
+}
+
+/// Returns the name of the tag as it is written in the source.
+fn tag_name(ident: &TagIdent) -> String {
+    match ident {
+        TagIdent::Path(path) => {
+            let segments = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+            if path.leading_colon.is_some() {
+                format!("::{segments}")
+            } else {
+                segments
+            }
+        }
+        TagIdent::Hyphenated(tag) => tag.clone(),
+    }
+}
+
+/// Returns whether the input starts with the closing tag `</name>`.
+fn peek_closing_tag(input: ParseStream, name: &str) -> bool {
+    let fork = input.fork();
+    fork.parse::<Token![<]>().is_ok()
+        && fork.parse::<Token![/]>().is_ok()
+        && fork
+            .parse::<TagIdent>()
+            .is_ok_and(|ident| tag_name(&ident) == name)
+}
+
+fn parse_element(input: ParseStream) -> Result<TagNode> {
+    let _lt: Token![<] = input.parse()?;
+    let ident: TagIdent = input.parse()?;
+    let name = tag_name(&ident);
+
+    let mut props = Vec::new();
+    let self_closing = loop {
+        if input.peek(Token![/]) {
+            let _slash: Token![/] = input.parse()?;
+            let _gt: Token![>] = input.parse()?;
+            break true;
+        } else if input.peek(Token![>]) {
+            let _gt: Token![>] = input.parse()?;
+            break false;
+        } else if input.is_empty() {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("expected `>` or `/>` to end the tag `<{name}>`"),
+            ));
+        }
+        props.push(parse_prop(input)?);
+    };
+
+    let mut children = Vec::new();
+    // Void elements do not need a closing tag, but it is allowed.
+    let is_void = matches!(ident, TagIdent::Path(_)) && VOID_ELEMENTS.contains(&name.as_str());
+    if !self_closing && (!is_void || peek_closing_tag(input, &name)) {
+        // Whitespace at the start of an element is not significant.
+        let mut prev_end = None;
+        while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+            if input.is_empty() {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("unclosed element `<{name}>`"),
+                ));
+            }
+            children.push(parse_node(input, &mut prev_end)?);
+        }
+        let _lt: Token![<] = input.parse()?;
+        let _slash: Token![/] = input.parse()?;
+        let closing: TagIdent = input.parse()?;
+        if tag_name(&closing) != name {
+            return Err(syn::Error::new(
+                closing.span(),
+                format!("expected closing tag `</{name}>`"),
+            ));
+        }
+        let _gt: Token![>] = input.parse()?;
+    }
+
+    Ok(TagNode {
+        ident,
+        props,
+        children: Root(children),
+    })
+}
+
+fn parse_prop(input: ParseStream) -> Result<Prop> {
+    let span = input.span();
+    // Syntax: `{..attributes}`.
+    if input.peek(Brace) {
+        let content;
+        braced!(content in input);
+        let _2dot: Token![..] = content.parse()?;
+        return Ok(Prop {
+            ty: PropType::Spread,
+            value: content.parse()?,
+            span,
+        });
+    }
+
+    let ty: PropType = input.parse()?;
+    if matches!(ty, PropType::Spread) {
+        return Err(syn::Error::new(
+            span,
+            "attributes are spread with `{..attributes}`",
+        ));
+    }
+    let value = if input.peek(Token![=]) {
+        let _eqs: Token![=] = input.parse()?;
+        if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+            content.parse()?
+        } else if input.peek(Lit) {
+            Expr::Lit(input.parse()?)
+        } else {
+            return Err(input.error("expected a literal or `{` after `=`"));
+        }
+    } else {
+        // An attribute without a value is a boolean attribute that is set.
+        parse_quote! { true }
+    };
+    Ok(Prop { ty, value, span })
+}
@@ -2,21 +2,37 @@
 
 use proc_macro2::Span;
 use syn::spanned::Spanned;
-use syn::{Expr, Ident, LitStr, Path};
+use syn::{Expr, Ident, LitStr, Pat, Path};
 
 /// A list of nodes. This is the top-level syntax node and entry-point for parsing.
 pub struct Root(pub Vec<Node>);
 
+/// The input of the `view!` macro.
+pub struct ViewInput {
+    /// Syntax: `#![css(<expr>)]` before the nodes. The elements of the view are scoped with this
+    /// `ScopedCss`.
+    pub css: Option<Expr>,
+    pub root: Root,
+}
+
 pub enum Node {
     Tag(TagNode),
     Text(TextNode),
     Dyn(DynNode),
+    If(IfNode),
+    Match(MatchNode),
+    For(Box<ForNode>),
+    Slot(SlotNode),
 }
 
 pub enum NodeType {
     Tag,
     Text,
     Dyn,
+    If,
+    Match,
+    For,
+    Slot,
 }
 
 pub struct TagNode {
@@ -55,14 +71,48 @@ pub enum PropType {
     PlainHyphenated { ident: String },
     /// Syntax: `"<quoted-name>"=<expr>`.
     PlainQuoted { ident: String },
-    /// Syntax: `<dir>:<prop>=<expr>`.
-    Directive { dir: Ident, ident: Ident },
+    /// Syntax: `<dir>:<prop>(.<modifier>)*=<expr>`.
+    Directive {
+        dir: Ident,
+        ident: Ident,
+        modifiers: Vec<Ident>,
+    },
+    /// Syntax: `<dir>:<hyphenated-prop>=<expr>`.
+    DirectiveHyphenated { dir: Ident, ident: String },
+    /// Syntax: `use:<path>=<expr>`.
+    Action { path: Path },
     /// Syntax: `r#ref=<expr>`.
     Ref,
     /// Syntax: `..attributes=<expr>`
     Spread,
 }
 
+/// The modifiers of the `on:*` directive that set an option of the handler, e.g.
+/// `on:submit.prevent_default`.
+pub const EVENT_MODIFIERS: &[&str] = &[
+    "capture",
+    "passive",
+    "once",
+    "prevent_default",
+    "stop_propagation",
+    "self_only",
+];
+
+/// The keys that can be used as modifiers of the `on:*` directive, e.g. `on:keydown.enter`.
+pub const KEY_MODIFIERS: &[(&str, &str)] = &[
+    ("enter", "Enter"),
+    ("escape", "Escape"),
+    ("esc", "Escape"),
+    ("space", " "),
+    ("tab", "Tab"),
+    ("backspace", "Backspace"),
+    ("delete", "Delete"),
+    ("up", "ArrowUp"),
+    ("down", "ArrowDown"),
+    ("left", "ArrowLeft"),
+    ("right", "ArrowRight"),
+];
+
 pub struct TextNode {
     pub value: LitStr,
 }
@@ -70,3 +120,37 @@ pub struct TextNode {
 pub struct DynNode {
     pub value: Expr,
 }
+
+/// Syntax: `if <cond> { ... } else if <cond> { ... } else { ... }`.
+pub struct IfNode {
+    /// The conditions and their branches, in order.
+    pub branches: Vec<(Expr, Root)>,
+    pub else_branch: Option<Root>,
+}
+
+/// Syntax: `match <expr> { <pat> => { ... }, ... }`.
+pub struct MatchNode {
+    pub expr: Expr,
+    pub arms: Vec<MatchArm>,
+}
+
+pub struct MatchArm {
+    pub pat: Pat,
+    pub guard: Option<Expr>,
+    pub body: Root,
+}
+
+/// Syntax: `for <pat> in <expr> key(<expr>) { ... }`. The key is optional.
+pub struct ForNode {
+    pub pat: Pat,
+    pub iter: Expr,
+    pub key: Option<Expr>,
+    pub body: Root,
+}
+
+/// Syntax: `slot:<name> { ... }`. Only allowed directly inside of a component. The children are
+/// passed to the component as the prop `<name>`.
+pub struct SlotNode {
+    pub name: Ident,
+    pub children: Root,
+}
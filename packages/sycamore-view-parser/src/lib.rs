@@ -1,5 +1,8 @@
 //! Proc-macro support crate for Sycamore.
 
 pub mod codegen;
+pub mod css;
+pub mod html;
 pub mod ir;
 pub mod parse;
+pub mod validate;
@@ -3,10 +3,14 @@
 use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::token::{Brace, Paren};
-use syn::{Ident, LitStr, Result, Token, braced, parenthesized, token};
+use syn::{Attribute, Expr, Ident, LitStr, Pat, Path, Result, Token, braced, parenthesized, token};
 
 use crate::ir::*;
 
+mod kw {
+    syn::custom_keyword!(slot);
+}
+
 impl Parse for Root {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut children = Vec::new();
@@ -19,6 +23,25 @@ impl Parse for Root {
     }
 }
 
+impl Parse for ViewInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut css = None;
+        for attr in input.call(Attribute::parse_inner)? {
+            if !attr.path().is_ident("css") || css.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "expected a single `#![css(...)]` attribute",
+                ));
+            }
+            css = Some(attr.parse_args()?);
+        }
+        Ok(Self {
+            css,
+            root: input.parse()?,
+        })
+    }
+}
+
 impl Node {
     fn peek_type(input: ParseStream) -> Option<NodeType> {
         let input = input.fork(); // do not affect original ParseStream
@@ -27,6 +50,14 @@ impl Node {
             Some(NodeType::Text)
         } else if input.peek(Paren) {
             Some(NodeType::Dyn)
+        } else if input.peek(Token![if]) {
+            Some(NodeType::If)
+        } else if input.peek(Token![match]) {
+            Some(NodeType::Match)
+        } else if input.peek(Token![for]) {
+            Some(NodeType::For)
+        } else if input.peek(kw::slot) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            Some(NodeType::Slot)
         } else if input.peek(Token![::]) || input.peek(Ident::peek_any) {
             Some(NodeType::Tag)
         } else {
@@ -46,6 +77,10 @@ impl Parse for Node {
             NodeType::Tag => Self::Tag(input.parse()?),
             NodeType::Text => Self::Text(input.parse()?),
             NodeType::Dyn => Self::Dyn(input.parse()?),
+            NodeType::If => Self::If(input.parse()?),
+            NodeType::Match => Self::Match(input.parse()?),
+            NodeType::For => Self::For(Box::new(input.parse()?)),
+            NodeType::Slot => Self::Slot(input.parse()?),
         })
     }
 }
@@ -143,12 +178,46 @@ impl Parse for PropType {
             } else {
                 let name: Ident = input.call(Ident::parse_any)?;
 
-                if name == "ref" {
+                if name.unraw() == "ref" {
                     Ok(Self::Ref)
+                } else if name == "use" && input.peek(Token![:]) && !input.peek(Token![::]) {
+                    let _colon: Token![:] = input.parse()?;
+                    let path = input.call(Path::parse_mod_style)?;
+                    if input.peek(Token![-]) {
+                        return Err(input.error("`use` directive does not accept hyphenated names"));
+                    }
+                    if input.peek(Token![.]) && !input.peek(Token![..]) {
+                        return Err(input.error("`use` directive does not accept modifiers"));
+                    }
+                    Ok(Self::Action { path })
                 } else if input.peek(Token![:]) {
                     let _colon: Token![:] = input.parse()?;
                     let ident = input.call(Ident::parse_any)?;
-                    Ok(Self::Directive { dir: name, ident })
+                    if input.peek(Token![-]) {
+                        let mut segments = vec![ident];
+                        while input.peek(Token![-]) {
+                            let _: Token![-] = input.parse()?;
+                            segments.push(input.call(Ident::parse_any)?);
+                        }
+                        let ident = segments
+                            .into_iter()
+                            .map(|i| i.to_string())
+                            .collect::<Vec<_>>()
+                            .join("-");
+                        Ok(Self::DirectiveHyphenated { dir: name, ident })
+                    } else {
+                        let mut modifiers = Vec::new();
+                        while input.peek(Token![.]) && !input.peek(Token![..]) {
+                            let _: Token![.] = input.parse()?;
+                            modifiers.push(input.call(Ident::parse_any)?);
+                        }
+                        check_modifiers(&name, &modifiers)?;
+                        Ok(Self::Directive {
+                            dir: name,
+                            ident,
+                            modifiers,
+                        })
+                    }
                 } else {
                     Ok(Self::Plain { ident: name })
                 }
@@ -162,6 +231,47 @@ impl Parse for PropType {
     }
 }
 
+/// Check that the modifiers of a directive are valid. Only the `on:*` directive accepts
+/// modifiers.
+fn check_modifiers(dir: &Ident, modifiers: &[Ident]) -> Result<()> {
+    if dir != "on" {
+        return match modifiers.first() {
+            Some(modifier) => Err(syn::Error::new(
+                modifier.span(),
+                format!("`{dir}` directive does not accept modifiers"),
+            )),
+            None => Ok(()),
+        };
+    }
+    let mut has_key = false;
+    for modifier in modifiers {
+        if EVENT_MODIFIERS.iter().any(|name| modifier == name) {
+            continue;
+        }
+        if !KEY_MODIFIERS.iter().any(|(alias, _)| modifier == alias) {
+            let quote = |name: &str| format!("`{name}`");
+            let options = EVENT_MODIFIERS.iter().map(|name| quote(name));
+            let keys = KEY_MODIFIERS.iter().map(|(alias, _)| quote(alias));
+            return Err(syn::Error::new(
+                modifier.span(),
+                format!(
+                    "unknown event modifier `{modifier}`. Expected one of {} or a key: {}",
+                    options.collect::<Vec<_>>().join(", "),
+                    keys.collect::<Vec<_>>().join(", "),
+                ),
+            ));
+        }
+        if has_key {
+            return Err(syn::Error::new(
+                modifier.span(),
+                "only one key modifier can be used",
+            ));
+        }
+        has_key = true;
+    }
+    Ok(())
+}
+
 impl Parse for TextNode {
     fn parse(input: ParseStream) -> Result<Self> {
         Ok(Self {
@@ -179,3 +289,124 @@ impl Parse for DynNode {
         })
     }
 }
+
+/// A function that parses the nodes inside the body of a control flow node. This allows the
+/// control flow syntax to be shared between the different syntax frontends.
+pub(crate) type ParseBody = fn(ParseStream) -> Result<Root>;
+
+/// Parses a block of nodes surrounded by braces.
+fn parse_block(input: ParseStream, parse_body: ParseBody) -> Result<Root> {
+    let content;
+    braced!(content in input);
+    parse_body(&content)
+}
+
+impl Parse for IfNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, Root::parse)
+    }
+}
+
+impl IfNode {
+    pub(crate) fn parse_with(input: ParseStream, parse_body: ParseBody) -> Result<Self> {
+        let mut branches = Vec::new();
+        let mut else_branch = None;
+        loop {
+            let _if: Token![if] = input.parse()?;
+            let cond = input.call(Expr::parse_without_eager_brace)?;
+            branches.push((cond, parse_block(input, parse_body)?));
+
+            if !input.peek(Token![else]) {
+                break;
+            }
+            let _else: Token![else] = input.parse()?;
+            if !input.peek(Token![if]) {
+                else_branch = Some(parse_block(input, parse_body)?);
+                break;
+            }
+        }
+        Ok(Self {
+            branches,
+            else_branch,
+        })
+    }
+}
+
+impl Parse for MatchNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, Root::parse)
+    }
+}
+
+impl MatchNode {
+    pub(crate) fn parse_with(input: ParseStream, parse_body: ParseBody) -> Result<Self> {
+        let _match: Token![match] = input.parse()?;
+        let expr = input.call(Expr::parse_without_eager_brace)?;
+
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let pat = content.call(Pat::parse_multi_with_leading_vert)?;
+            let guard = if content.peek(Token![if]) {
+                let _if: Token![if] = content.parse()?;
+                Some(content.parse()?)
+            } else {
+                None
+            };
+            let _arrow: Token![=>] = content.parse()?;
+            if !content.peek(Brace) {
+                return Err(content.error("expected `{` after `=>` in `match` arm"));
+            }
+            let body = parse_block(&content, parse_body)?;
+            if !content.is_empty() {
+                let _comma: Option<Token![,]> = content.parse()?;
+            }
+            arms.push(MatchArm { pat, guard, body });
+        }
+        Ok(Self { expr, arms })
+    }
+}
+
+impl Parse for ForNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Self::parse_with(input, Root::parse)
+    }
+}
+
+impl ForNode {
+    pub(crate) fn parse_with(input: ParseStream, parse_body: ParseBody) -> Result<Self> {
+        let _for: Token![for] = input.parse()?;
+        let pat = input.call(Pat::parse_multi_with_leading_vert)?;
+        let _in: Token![in] = input.parse()?;
+        let iter = input.call(Expr::parse_without_eager_brace)?;
+        let key = if input.peek(Ident) && input.peek2(Paren) {
+            let ident: Ident = input.parse()?;
+            if ident != "key" {
+                return Err(syn::Error::new(ident.span(), "expected `key` or `{`"));
+            }
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        let body = parse_block(input, parse_body)?;
+        Ok(Self {
+            pat,
+            iter,
+            key,
+            body,
+        })
+    }
+}
+
+impl Parse for SlotNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: kw::slot = input.parse()?;
+        let _: Token![:] = input.parse()?;
+        let name = input.call(Ident::parse_any)?;
+        let children = parse_block(input, Root::parse)?;
+        Ok(Self { name, children })
+    }
+}
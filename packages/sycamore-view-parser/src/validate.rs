@@ -0,0 +1,576 @@
+//! Validation of the elements in a view.
+//!
+//! The attributes of elements are checked against the element definitions in `sycamore-web` so
+//! that a typo results in a clear error with a suggestion instead of a trait method error. Element
+//! nesting that the browser fixes up when parsing the HTML can also be reported as an error since
+//! this breaks hydration.
+
+use proc_macro2::Span;
+use syn::ext::IdentExt;
+
+use crate::codegen::is_component;
+use crate::ir::*;
+
+#[rustfmt::skip]
+pub(crate) mod elements;
+
+use self::elements::*;
+
+/// Elements that close an open `<p>` element when they are parsed.
+pub(crate) const CLOSES_P: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Props that are available on all elements in addition to their attributes.
+const GLOBAL_PROPS: &[&str] = &["children", "dangerously_set_inner_html", "ref", "spread"];
+
+/// Validate the elements in `root`. If `check_nesting` is `true`, elements that are nested in a way
+/// that is not allowed by HTML are reported as well.
+pub fn validate(root: &Root, check_nesting: bool) -> syn::Result<()> {
+    let mut validator = Validator {
+        check_nesting,
+        errors: None,
+    };
+    validator.root(root, &mut Vec::new());
+    match validator.errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+struct Validator {
+    check_nesting: bool,
+    errors: Option<syn::Error>,
+}
+
+impl Validator {
+    fn error(&mut self, span: Span, message: String) {
+        let error = syn::Error::new(span, message);
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    fn root(&mut self, root: &Root, ancestors: &mut Vec<String>) {
+        for node in &root.0 {
+            match node {
+                Node::Tag(tag) if is_component(&tag.ident) => {
+                    // The children of a component can be rendered anywhere.
+                    self.root(&tag.children, &mut Vec::new());
+                }
+                Node::Tag(tag) => self.element(tag, ancestors),
+                Node::Text(_) | Node::Dyn(_) => {}
+                Node::If(IfNode {
+                    branches,
+                    else_branch,
+                }) => {
+                    for (_, body) in branches {
+                        self.root(body, ancestors);
+                    }
+                    if let Some(body) = else_branch {
+                        self.root(body, ancestors);
+                    }
+                }
+                Node::Match(MatchNode { arms, .. }) => {
+                    for arm in arms {
+                        self.root(&arm.body, ancestors);
+                    }
+                }
+                Node::For(for_node) => self.root(&for_node.body, ancestors),
+                Node::Slot(slot) => self.root(&slot.children, ancestors),
+            }
+        }
+    }
+
+    fn element(&mut self, element: &TagNode, ancestors: &mut Vec<String>) {
+        let span = element.ident.span();
+        let tag = match &element.ident {
+            TagIdent::Path(path) => match path.get_ident() {
+                Some(ident) => ident.unraw().to_string(),
+                None => return,
+            },
+            TagIdent::Hyphenated(tag) => tag.clone(),
+        };
+
+        let attributes = match &element.ident {
+            TagIdent::Hyphenated(_) => Some((&[][..], HTML_GLOBAL_ATTRIBUTES)),
+            TagIdent::Path(_) => HTML_ELEMENTS
+                .iter()
+                .find(|(name, _)| *name == tag)
+                .map(|(_, attributes)| (*attributes, HTML_GLOBAL_ATTRIBUTES))
+                .or_else(|| {
+                    SVG_ELEMENTS
+                        .iter()
+                        .find(|(name, _)| *name == tag)
+                        .map(|(_, attributes)| (*attributes, SVG_GLOBAL_ATTRIBUTES))
+                }),
+        };
+        match attributes {
+            Some((attributes, global_attributes)) => {
+                self.props(element, &tag, &[attributes, global_attributes]);
+            }
+            None => {
+                let tags = HTML_ELEMENTS
+                    .iter()
+                    .chain(SVG_ELEMENTS)
+                    .map(|(name, _)| *name);
+                let message = match suggest(&tag, tags) {
+                    Some(suggestion) => {
+                        format!("unknown element `{tag}`, did you mean `{suggestion}`?")
+                    }
+                    None => {
+                        format!("unknown element `{tag}`, custom elements must contain a hyphen")
+                    }
+                };
+                self.error(span, message);
+            }
+        }
+
+        if self.check_nesting
+            && let Some(message) = nesting_error(ancestors, &tag)
+        {
+            self.error(span, message);
+        }
+
+        ancestors.push(tag);
+        self.root(&element.children, ancestors);
+        ancestors.pop();
+    }
+
+    fn props(&mut self, element: &TagNode, tag: &str, attributes: &[&[&str]]) {
+        for prop in &element.props {
+            let PropType::Plain { ident } = &prop.ty else {
+                continue;
+            };
+            let name = ident.unraw().to_string();
+            let candidates = || {
+                attributes
+                    .iter()
+                    .flat_map(|attributes| attributes.iter())
+                    .chain(GLOBAL_PROPS)
+                    .copied()
+            };
+            if candidates().any(|candidate| candidate == name) {
+                continue;
+            }
+
+            let message = match suggest(&name, candidates()) {
+                Some(suggestion) => {
+                    format!("unknown attribute `{name}` on `<{tag}>`, did you mean `{suggestion}`?")
+                }
+                None => format!(
+                    "unknown attribute `{name}` on `<{tag}>`, use a quoted name such as \
+                     `\"{name}\"=...` to set a custom attribute"
+                ),
+            };
+            self.error(ident.span(), message);
+        }
+    }
+}
+
+/// Returns why `child` cannot be nested inside the `ancestors`, or `None` if it can be.
+fn nesting_error(ancestors: &[String], child: &str) -> Option<String> {
+    let parent = ancestors.last()?.as_str();
+    let reason = match (parent, child) {
+        ("p", _) if CLOSES_P.contains(&child) => format!("`<{child}>` cannot be a child of `<p>`"),
+        (_, "a" | "button" | "form") if ancestors.iter().any(|tag| tag == child) => {
+            format!("`<{child}>` cannot be nested inside another `<{child}>`")
+        }
+        ("tbody" | "thead" | "tfoot", "tr") => return None,
+        (_, "tr") => "`<tr>` must be a child of `<tbody>`, `<thead>` or `<tfoot>`".to_string(),
+        ("tr", "td" | "th") => return None,
+        (_, "td" | "th") => format!("`<{child}>` must be a child of `<tr>`"),
+        ("table", "caption" | "colgroup" | "thead" | "tbody" | "tfoot" | "script" | "template") => {
+            return None;
+        }
+        (_, "caption" | "colgroup" | "thead" | "tbody" | "tfoot") => {
+            format!("`<{child}>` must be a child of `<table>`")
+        }
+        ("table", _) => format!("`<{child}>` cannot be a child of `<table>`"),
+        ("li", "li") | ("option", "option") => {
+            format!("`<{child}>` cannot be a child of `<{parent}>`")
+        }
+        ("dd" | "dt", "dd" | "dt") => format!("`<{child}>` cannot be a child of `<{parent}>`"),
+        _ => return None,
+    };
+    Some(format!(
+        "{reason}. The browser changes the nesting when parsing the HTML, which breaks hydration."
+    ))
+}
+
+/// Returns the candidate that is closest to `name` if it is close enough to be a likely typo.
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.len() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The edit distance between `a` and `b`, ignoring case. Swapping two adjacent characters counts
+/// as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.to_lowercase().chars().collect::<Vec<_>>();
+    let b = b.to_lowercase().chars().collect::<Vec<_>>();
+    // d[i][j] is the distance between the first i characters of a and the first j characters of b.
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+    use std::path::Path;
+
+    use quote::ToTokens;
+    use syn::parse::{ParseStream, Parser};
+    use syn::token::Paren;
+    use syn::{Attribute, Ident, LitStr, Token, Type, braced, parenthesized};
+
+    use super::*;
+
+    fn check(view: &str) -> Result<(), Vec<String>> {
+        let root = syn::parse_str::<Root>(view).unwrap();
+        validate(&root, true)
+            .map_err(|errors| errors.into_iter().map(|err| err.to_string()).collect())
+    }
+
+    #[test]
+    fn valid_attributes() {
+        assert_eq!(
+            check(r#"a(href="/", class="link", r#type="text/html") { "Link" }"#),
+            Ok(())
+        );
+        assert_eq!(check(r#"svg(viewBox="0 0 10 10") { rect(x="1") }"#), Ok(()));
+        assert_eq!(
+            check(r#"my-element(id="custom", "unknown"=1, data-value=2, on:click=f)"#),
+            Ok(())
+        );
+        assert_eq!(
+            check(r#"div(r#ref=node, dangerously_set_inner_html="<p></p>")"#),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn unknown_attribute() {
+        assert_eq!(
+            check(r#"a(hreff="/")"#),
+            Err(vec![
+                "unknown attribute `hreff` on `<a>`, did you mean `href`?".to_string()
+            ])
+        );
+        assert_eq!(
+            check(r#"div(foo="bar")"#),
+            Err(vec![
+                "unknown attribute `foo` on `<div>`, use a quoted name such as `\"foo\"=...` to \
+                 set a custom attribute"
+                    .to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn unknown_element() {
+        assert_eq!(
+            check("dvi {}"),
+            Err(vec![
+                "unknown element `dvi`, did you mean `div`?".to_string()
+            ])
+        );
+        // Components are not validated.
+        assert_eq!(check(r#"Component(hreff="/")"#), Ok(()));
+    }
+
+    #[test]
+    fn invalid_nesting() {
+        let view = "p { div {} } table { tr {} } a { span { a {} } }";
+        let errors = check(view).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("`<div>` cannot be a child of `<p>`."));
+        assert!(errors[1].starts_with("`<tr>` must be a child of `<tbody>`"));
+        assert!(errors[2].starts_with("`<a>` cannot be nested inside another `<a>`."));
+        // Nesting is only checked when it is enabled.
+        assert!(validate(&syn::parse_str(view).unwrap(), false).is_ok());
+
+        assert_eq!(
+            check("table { tbody { tr { td {} } } } p { span {} } ul { li {} }"),
+            Ok(())
+        );
+        // The children of components can be rendered anywhere.
+        assert_eq!(check("p { Component { div {} } }"), Ok(()));
+        // Control flow does not change the parent.
+        assert_eq!(check("p { if cond { div {} } }").unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn suggestions() {
+        let candidates = || ["href", "hreflang", "target"].into_iter();
+        assert_eq!(suggest("hre", candidates()), Some("href"));
+        assert_eq!(suggest("trget", candidates()), Some("target"));
+        assert_eq!(suggest("HREF", candidates()), Some("href"));
+        assert_eq!(suggest("foo", candidates()), None);
+    }
+
+    /// Parses the elements in an `impl_elements!` or `impl_svg_elements!` macro.
+    fn parse_elements(input: ParseStream) -> syn::Result<Vec<(String, Vec<String>)>> {
+        let mut elements = Vec::new();
+        while !input.is_empty() {
+            input.call(Attribute::parse_outer)?;
+            let name = input.call(Ident::parse_any)?.unraw().to_string();
+            if input.peek(Paren) {
+                let tag;
+                parenthesized!(tag in input);
+                tag.parse::<LitStr>()?;
+            }
+            if input.peek(Token![as]) {
+                input.parse::<Token![as]>()?;
+                input.parse::<Type>()?;
+            }
+            let attributes;
+            braced!(attributes in input);
+            elements.push((name, parse_attributes(&attributes)?));
+            input.parse::<Token![,]>()?;
+        }
+        Ok(elements)
+    }
+
+    /// Parses the attributes in an `impl_attributes!` macro.
+    fn parse_attributes(input: ParseStream) -> syn::Result<Vec<String>> {
+        let mut attributes = Vec::new();
+        while !input.is_empty() {
+            input.call(Attribute::parse_outer)?;
+            attributes.push(input.call(Ident::parse_any)?.unraw().to_string());
+            if input.peek(Paren) {
+                let name;
+                parenthesized!(name in input);
+                name.parse::<LitStr>()?;
+            }
+            input.parse::<Token![:]>()?;
+            input.parse::<Type>()?;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(attributes)
+    }
+
+    /// Writes `items` as a slice of string literals that is wrapped to fit into 100 columns.
+    fn write_list(out: &mut String, indent: usize, items: &[String]) {
+        if items.is_empty() {
+            out.push_str("&[]");
+            return;
+        }
+        out.push_str("&[\n");
+        let mut line = String::new();
+        for item in items {
+            let item = format!("{item:?},");
+            if !line.is_empty() && indent + 4 + line.len() + 1 + item.len() > 100 {
+                writeln!(out, "{:width$}{line}", "", width = indent + 4).unwrap();
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&item);
+        }
+        writeln!(out, "{:width$}{line}", "", width = indent + 4).unwrap();
+        write!(out, "{:indent$}]", "").unwrap();
+    }
+
+    /// Returns the string literals in `tokens`.
+    fn string_literals(tokens: proc_macro2::TokenStream) -> Vec<String> {
+        tokens
+            .into_iter()
+            .flat_map(|token| match token {
+                proc_macro2::TokenTree::Group(group) => string_literals(group.stream()),
+                proc_macro2::TokenTree::Literal(lit) => {
+                    syn::parse2::<LitStr>(lit.into_token_stream())
+                        .map(|lit| vec![lit.value()])
+                        .unwrap_or_default()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Generates the source of the `elements` module from the sources of
+    /// `sycamore-web/src/elements.rs` and `sycamore-web/src/node/ssr_node.rs`.
+    fn generate_elements(source: &str, ssr_source: &str) -> String {
+        let file = syn::parse_file(source).unwrap();
+        let void_elements = syn::parse_file(ssr_source)
+            .unwrap()
+            .items
+            .into_iter()
+            .find_map(|item| match item {
+                syn::Item::Static(item) if item.ident == "VOID_ELEMENTS" => {
+                    Some(string_literals(item.expr.into_token_stream()))
+                }
+                _ => None,
+            })
+            .unwrap();
+
+        let mut html_elements = None;
+        let mut svg_elements = None;
+        let mut html_global_attributes = None;
+        let mut svg_global_attributes = None;
+        for item in &file.items {
+            match item {
+                syn::Item::Mod(module) if module.ident == "tags" => {
+                    for item in &module.content.as_ref().unwrap().1 {
+                        if let syn::Item::Macro(item) = item {
+                            let elements = parse_elements.parse2(item.mac.tokens.clone()).unwrap();
+                            if item.mac.path.is_ident("impl_elements") {
+                                html_elements = Some(elements);
+                            } else if item.mac.path.is_ident("impl_svg_elements") {
+                                svg_elements = Some(elements);
+                            }
+                        }
+                    }
+                }
+                syn::Item::Trait(item) => {
+                    let Some(syn::TraitItem::Macro(mac)) = item.items.first() else {
+                        continue;
+                    };
+                    let attributes = parse_attributes.parse2(mac.mac.tokens.clone()).unwrap();
+                    if item.ident == "HtmlGlobalAttributes" {
+                        html_global_attributes = Some(attributes);
+                    } else if item.ident == "SvgGlobalAttributes" {
+                        svg_global_attributes = Some(attributes);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut out = String::from(
+            "//! The elements and attributes that are defined in `sycamore-web/src/elements.rs` and the void\n\
+             //! elements that are defined in `sycamore-web/src/node/ssr_node.rs`.\n\
+             //!\n\
+             //! This file is generated by the `elements_are_up_to_date` test. Do not edit it by hand \
+             but run\n\
+             //! `UPDATE_ELEMENTS=1 cargo test -p sycamore-view-parser` instead.\n",
+        );
+        let elements = [
+            ("HTML_ELEMENTS", "HTML", html_elements.unwrap()),
+            ("SVG_ELEMENTS", "SVG", svg_elements.unwrap()),
+        ];
+        let global_attributes = [
+            (
+                "HTML_GLOBAL_ATTRIBUTES",
+                "HTML",
+                html_global_attributes.unwrap(),
+            ),
+            (
+                "SVG_GLOBAL_ATTRIBUTES",
+                "SVG",
+                svg_global_attributes.unwrap(),
+            ),
+        ];
+        for ((name, kind, elements), (global_name, _, global_attributes)) in
+            elements.iter().zip(&global_attributes)
+        {
+            writeln!(out, "\n/// The {kind} elements and their attributes.").unwrap();
+            writeln!(out, "pub(crate) const {name}: &[(&str, &[&str])] = &[").unwrap();
+            for (element, attributes) in elements {
+                write!(out, "    ({element:?}, ").unwrap();
+                write_list(&mut out, 4, attributes);
+                out.push_str("),\n");
+            }
+            out.push_str("];\n");
+
+            writeln!(
+                out,
+                "\n/// The attributes that are available on all {kind} elements."
+            )
+            .unwrap();
+            write!(out, "pub(crate) const {global_name}: &[&str] = ").unwrap();
+            write_list(&mut out, 0, global_attributes);
+            out.push_str(";\n");
+        }
+
+        out.push_str(
+            "\n/// The HTML elements that cannot have any children and are rendered without a closing \
+             tag.\n",
+        );
+        out.push_str("pub(crate) const VOID_ELEMENTS: &[&str] = ");
+        write_list(&mut out, 0, &void_elements);
+        out.push_str(";\n");
+        out
+    }
+
+    /// Checks that the element tables match the element definitions in `sycamore-web`. Run with
+    /// `UPDATE_ELEMENTS=1` to regenerate the tables.
+    #[test]
+    fn elements_are_up_to_date() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let source_path = manifest_dir.join("../sycamore-web/src/elements.rs");
+        let source = std::fs::read_to_string(&source_path)
+            .unwrap_or_else(|err| panic!("cannot read `{}`: {err}", source_path.display()));
+        let ssr_source_path = manifest_dir.join("../sycamore-web/src/node/ssr_node.rs");
+        let ssr_source = std::fs::read_to_string(&ssr_source_path)
+            .unwrap_or_else(|err| panic!("cannot read `{}`: {err}", ssr_source_path.display()));
+        let generated = generate_elements(&source, &ssr_source);
+
+        let path = manifest_dir.join("src/validate/elements.rs");
+        if std::env::var_os("UPDATE_ELEMENTS").is_some() {
+            std::fs::write(&path, generated).unwrap();
+        } else {
+            assert!(
+                std::fs::read_to_string(&path).unwrap() == generated,
+                "`src/validate/elements.rs` is out of date, run `UPDATE_ELEMENTS=1 cargo test -p \
+                 sycamore-view-parser` to regenerate it"
+            );
+        }
+    }
+}
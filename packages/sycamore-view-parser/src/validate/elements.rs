@@ -0,0 +1,349 @@
+//! The elements and attributes that are defined in `sycamore-web/src/elements.rs` and the void
+//! elements that are defined in `sycamore-web/src/node/ssr_node.rs`.
+//!
+//! This file is generated by the `elements_are_up_to_date` test. Do not edit it by hand but run
+//! `UPDATE_ELEMENTS=1 cargo test -p sycamore-view-parser` instead.
+
+/// The HTML elements and their attributes.
+pub(crate) const HTML_ELEMENTS: &[(&str, &[&str])] = &[
+    ("a", &[
+        "download", "href", "hreflang", "target", "type", "ping", "rel",
+    ]),
+    ("abbr", &[]),
+    ("address", &[]),
+    ("area", &[
+        "alt", "coords", "download", "href", "hreflang", "media", "referrerpolicy", "ping", "rel",
+        "shape", "target", "type",
+    ]),
+    ("article", &[]),
+    ("aside", &[]),
+    ("audio", &[
+        "autoplay", "controls", "crossorigin", "muted", "preload", "src", "loop",
+    ]),
+    ("b", &[]),
+    ("base", &[
+        "href", "target",
+    ]),
+    ("bdi", &[]),
+    ("bdo", &[]),
+    ("blockquote", &[
+        "cite",
+    ]),
+    ("body", &[]),
+    ("br", &[]),
+    ("button", &[
+        "autofocus", "disabled", "form", "formaction", "formenctype", "formmethod",
+        "formnovalidate", "formtarget", "name", "type", "value",
+    ]),
+    ("canvas", &[
+        "height", "width",
+    ]),
+    ("caption", &[]),
+    ("cite", &[]),
+    ("code", &[
+        "language",
+    ]),
+    ("col", &[
+        "span",
+    ]),
+    ("colgroup", &[
+        "span",
+    ]),
+    ("data", &[
+        "value",
+    ]),
+    ("datalist", &[]),
+    ("dd", &[]),
+    ("del", &[
+        "cite", "datetime",
+    ]),
+    ("details", &[
+        "open",
+    ]),
+    ("dfn", &[]),
+    ("dialog", &[]),
+    ("div", &[]),
+    ("dl", &[]),
+    ("dt", &[]),
+    ("em", &[]),
+    ("embed", &[
+        "height", "src", "type", "width",
+    ]),
+    ("fieldset", &[]),
+    ("figcaption", &[]),
+    ("figure", &[]),
+    ("footer", &[]),
+    ("form", &[
+        "acceptcharset", "action", "autocomplete", "enctype", "method", "name", "novalidate",
+        "target",
+    ]),
+    ("head", &[]),
+    ("header", &[]),
+    ("hgroup", &[]),
+    ("h1", &[]),
+    ("h2", &[]),
+    ("h3", &[]),
+    ("h4", &[]),
+    ("h5", &[]),
+    ("h6", &[]),
+    ("hr", &[]),
+    ("html", &[]),
+    ("i", &[]),
+    ("iframe", &[
+        "allow", "allowfullscreen", "allowpaymentrequest", "height", "loading", "name",
+        "referrerpolicy", "sandbox", "src", "srcdoc", "width",
+    ]),
+    ("img", &[
+        "alt", "crossorigin", "decoding", "height", "ismap", "loading", "referrerpolicy", "sizes",
+        "src", "srcset", "usemap", "width",
+    ]),
+    ("input", &[
+        "accept", "alt", "autocomplete", "autofocus", "capture", "checked", "directory", "disabled",
+        "form", "formaction", "formenctype", "formmethod", "formnovalidate", "formtarget", "height",
+        "initial_checked", "initial_value", "list", "max", "maxlength", "min", "minlength",
+        "multiple", "name", "pattern", "placeholder", "readonly", "required", "size", "spellcheck",
+        "src", "step", "tabindex", "type", "value", "width",
+    ]),
+    ("ins", &[
+        "cite", "datetime",
+    ]),
+    ("kbd", &[]),
+    ("keygen", &[]),
+    ("label", &[
+        "form", "for",
+    ]),
+    ("legend", &[]),
+    ("li", &[
+        "value",
+    ]),
+    ("link", &[
+        "as", "crossorigin", "href", "hreflang", "media", "rel", "sizes", "title", "type",
+        "integrity",
+    ]),
+    ("main", &[]),
+    ("map", &[
+        "name",
+    ]),
+    ("mark", &[]),
+    ("menu", &[]),
+    ("menuitem", &[]),
+    ("meta", &[
+        "charset", "content", "http_equiv", "name",
+    ]),
+    ("meter", &[
+        "value", "min", "max", "low", "high", "optimum", "form",
+    ]),
+    ("nav", &[]),
+    ("noscript", &[]),
+    ("object", &[
+        "data", "form", "height", "name", "type", "typemustmatch", "usemap", "width",
+    ]),
+    ("ol", &[
+        "reversed", "start", "type",
+    ]),
+    ("optgroup", &[
+        "disabled", "label",
+    ]),
+    ("option", &[
+        "disabled", "initial_selected", "label", "selected", "value",
+    ]),
+    ("output", &[
+        "for", "form", "name",
+    ]),
+    ("p", &[]),
+    ("param", &[
+        "name", "value",
+    ]),
+    ("picture", &[]),
+    ("pre", &[]),
+    ("progress", &[
+        "value", "max",
+    ]),
+    ("q", &[
+        "cite",
+    ]),
+    ("rp", &[]),
+    ("rt", &[]),
+    ("ruby", &[]),
+    ("s", &[]),
+    ("samp", &[]),
+    ("script", &[
+        "async", "crossorigin", "defer", "integrity", "nomodule", "nonce", "src", "script", "text",
+        "type",
+    ]),
+    ("section", &[]),
+    ("select", &[
+        "autocomplete", "autofocus", "disabled", "form", "multiple", "name", "required", "size",
+        "value",
+    ]),
+    ("small", &[]),
+    ("source", &[
+        "src", "type",
+    ]),
+    ("span", &[]),
+    ("strong", &[]),
+    ("style", &[
+        "media", "nonce", "title", "type",
+    ]),
+    ("sub", &[]),
+    ("summary", &[]),
+    ("sup", &[]),
+    ("table", &[]),
+    ("tbody", &[]),
+    ("td", &[
+        "colspan", "headers", "rowspan",
+    ]),
+    ("template", &[]),
+    ("textarea", &[
+        "autocomplete", "autofocus", "cols", "disabled", "form", "initial_value", "maxlength",
+        "minlength", "name", "placeholder", "readonly", "required", "rows", "spellcheck", "type",
+        "value", "wrap",
+    ]),
+    ("tfoot", &[]),
+    ("th", &[
+        "abbr", "colspan", "headers", "rowspan", "scope",
+    ]),
+    ("thead", &[]),
+    ("time", &[
+        "datetime",
+    ]),
+    ("title", &[]),
+    ("tr", &[]),
+    ("track", &[
+        "default", "kind", "label", "src", "srclang",
+    ]),
+    ("u", &[]),
+    ("ul", &[]),
+    ("var", &[]),
+    ("video", &[
+        "autoplay", "controls", "crossorigin", "height", "loop", "muted", "playsinline", "poster",
+        "preload", "src", "width",
+    ]),
+    ("wbr", &[]),
+];
+
+/// The attributes that are available on all HTML elements.
+pub(crate) const HTML_GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey", "autocapitalize", "autofocus", "class", "contenteditable", "dir", "draggable",
+    "enterkeyhint", "exportparts", "hidden", "id", "inert", "inputmode", "is", "itemid", "itemprop",
+    "itemref", "itemscope", "itemtype", "lang", "nonce", "part", "popover", "role", "slot",
+    "spellcheck", "style", "tabindex", "title", "translate", "virtualkeyboardpolicy",
+];
+
+/// The SVG elements and their attributes.
+pub(crate) const SVG_ELEMENTS: &[(&str, &[&str])] = &[
+    ("svg", &[
+        "xmlns",
+    ]),
+    ("svg_a", &[]),
+    ("animate", &[]),
+    ("animateMotion", &[]),
+    ("animateTransform", &[]),
+    ("circle", &[]),
+    ("clipPath", &[]),
+    ("defs", &[]),
+    ("desc", &[]),
+    ("discard", &[]),
+    ("ellipse", &[]),
+    ("feBlend", &[]),
+    ("feColorMatrix", &[]),
+    ("feComponentTransfer", &[]),
+    ("feComposite", &[]),
+    ("feConvolveMatrix", &[]),
+    ("feDiffuseLighting", &[]),
+    ("feDisplacementMap", &[]),
+    ("feDistantLight", &[]),
+    ("feDropShadow", &[]),
+    ("feFlood", &[]),
+    ("feFuncA", &[]),
+    ("feFuncB", &[]),
+    ("feFuncG", &[]),
+    ("feFuncR", &[]),
+    ("feGaussianBlur", &[]),
+    ("feImage", &[]),
+    ("feMerge", &[]),
+    ("feMergeNode", &[]),
+    ("feMorphology", &[]),
+    ("feOffset", &[]),
+    ("fePointLight", &[]),
+    ("feSpecularLighting", &[]),
+    ("feSpotLight", &[]),
+    ("feTile", &[]),
+    ("feTurbulence", &[]),
+    ("filter", &[]),
+    ("foreignObject", &[]),
+    ("g", &[]),
+    ("hatch", &[]),
+    ("hatchpath", &[]),
+    ("image", &[]),
+    ("line", &[]),
+    ("linearGradient", &[]),
+    ("marker", &[]),
+    ("mask", &[]),
+    ("metadata", &[]),
+    ("mpath", &[]),
+    ("path", &[]),
+    ("pattern", &[]),
+    ("polygon", &[]),
+    ("polyline", &[]),
+    ("radialGradient", &[]),
+    ("rect", &[]),
+    ("svg_script", &[]),
+    ("set", &[]),
+    ("stop", &[]),
+    ("svg_style", &[]),
+    ("switch", &[]),
+    ("symbol", &[]),
+    ("text", &[]),
+    ("textPath", &[]),
+    ("svg_title", &[]),
+    ("tspan", &[]),
+    ("use", &[]),
+    ("view", &[]),
+];
+
+/// The attributes that are available on all SVG elements.
+pub(crate) const SVG_GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accentHeight", "accumulate", "additive", "alignmentBaseline", "alphabetic", "amplitude",
+    "arabicForm", "ascent", "attributeName", "attributeType", "azimuth", "baseFrequency",
+    "baselineShift", "baseProfile", "bbox", "begin", "bias", "by", "calcMode", "capHeight", "class",
+    "clipPathUnits", "clipPath", "clipRule", "color", "colorInterpolation",
+    "colorInterpolationFilters", "colorProfile", "colorRendering", "crossorigin", "cursor", "cx",
+    "cy", "d", "decelerate", "descent", "diffuseConstant", "direction", "display", "divisor",
+    "dominantBaseline", "dur", "dx", "dy", "edgeMode", "elevation", "enableBackground", "end",
+    "exponent", "fill", "fillOpacity", "fillRule", "filter", "filterUnits", "floodColor",
+    "floodOpacity", "fontFamily", "fontSize", "fontSizeAdjust", "fontStretch", "fontStyle",
+    "fontVariant", "fontWeight", "format", "from", "fr", "fx", "fy", "g1", "g2", "glyphName",
+    "glyphOrientationHorizontal", "glyphOrientationVertical", "glyphRef", "gradientTransform",
+    "gradientUnits", "hanging", "height", "href", "hreflang", "horizAdvX", "horizOriginX", "id",
+    "ideographic", "imageRendering", "in_", "in2", "intercept", "k", "k1", "k2", "k3", "k4",
+    "kernelMatrix", "kernelUnitLength", "kerning", "keyPoints", "keySplines", "keyTimes", "lang",
+    "lengthAdjust", "letterSpacing", "lightingColor", "limitingConeAngle", "local", "markerEnd",
+    "markerMid", "markerStart", "markerHeight", "markerUnits", "markerWidth", "mask",
+    "maskContentUnits", "maskUnits", "mathematical", "max", "media", "method", "min", "mode",
+    "name", "numOctaves", "offset", "opacity", "operator", "order", "orient", "orientation",
+    "origin", "overflow", "overlinePosition", "overlineThickness", "panose1", "paintOrder", "path",
+    "pathLength", "patternContentUnits", "patternTransform", "patternUnits", "ping",
+    "pointerEvents", "points", "pointsAtX", "pointsAtY", "pointsAtZ", "preserveAlpha",
+    "preserveAspectRatio", "primitiveUnits", "r", "radius", "referrerPolicy", "refX", "refY", "rel",
+    "renderingIntent", "repeatCount", "repeatDur", "requiredExtensions", "requiredFeatures",
+    "restart", "result", "rotate", "rx", "ry", "scale", "seed", "shapeRendering", "slope",
+    "spacing", "specularConstant", "specularExponent", "speed", "spreadMethod", "startOffset",
+    "stdDeviation", "stemh", "stemv", "stitchTiles", "stopColor", "stopOpacity",
+    "strikethroughPosition", "strikethroughThickness", "string", "stroke", "strokeDasharray",
+    "strokeDashoffset", "strokeLinecap", "strokeLinejoin", "strokeMiterlimit", "strokeOpacity",
+    "strokeWidth", "style", "surfaceScale", "systemLanguage", "tabindex", "tableValues", "target",
+    "targetX", "targetY", "textAnchor", "textDecoration", "textRendering", "textLength", "to",
+    "transform", "transformOrigin", "type_", "u1", "u2", "underlinePosition", "underlineThickness",
+    "unicode", "unicodeBidi", "unicodeRange", "unitsPerEm", "vAlphabetic", "vHanging",
+    "vIdeographic", "vMathematical", "values", "vectorEffect", "version", "vertAdvY", "vertOriginX",
+    "vertOriginY", "viewBox", "visibility", "width", "widths", "wordSpacing", "writingMode", "x",
+    "xHeight", "x1", "x2", "xChannelSelector", "xmlBase", "xmlLang", "xmlSpace", "y", "y1", "y2",
+    "yChannelSelector", "zoomAndPan",
+];
+
+/// The HTML elements that cannot have any children and are rendered without a closing tag.
+pub(crate) const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr", "command", "keygen", "menuitem",
+];
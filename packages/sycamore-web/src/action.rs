@@ -0,0 +1,60 @@
+//! Actions are reusable behaviors that can be attached to elements with the `use:*` directive.
+
+use crate::*;
+
+/// A reusable behavior that can be attached to an element with the `use:*` directive.
+///
+/// This is implemented for all functions that take the element and an argument. The function
+/// is called once the element is mounted. It can either register its own cleanup with
+/// [`on_cleanup`] or return a closure which is called when the element is disposed.
+///
+/// Actions are not run in SSR mode.
+///
+/// # Example
+///
+/// ```
+/// # use sycamore::prelude::*;
+/// fn tooltip(el: web_sys::Element, text: &'static str) {
+///     el.set_attribute("title", text).unwrap();
+/// }
+///
+/// fn log_lifetime(_el: web_sys::Element, name: &'static str) -> impl FnOnce() {
+///     console_log!("mounted {name}");
+///     move || console_log!("disposed {name}")
+/// }
+///
+/// # fn App() -> View {
+/// view! {
+///     button(use:tooltip="Save the file", use:log_lifetime="button") { "Save" }
+/// }
+/// # }
+/// ```
+pub trait Action<A, R = ()>: 'static {
+    /// Run the action on `el`.
+    fn apply(self, el: web_sys::Element, arg: A);
+}
+
+impl<F, A> Action<A, ()> for F
+where
+    F: FnOnce(web_sys::Element, A) + 'static,
+{
+    fn apply(self, el: web_sys::Element, arg: A) {
+        self(el, arg);
+    }
+}
+
+/// Marker type for actions that return a cleanup function.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct WithCleanup;
+
+impl<F, A, C> Action<A, WithCleanup> for F
+where
+    F: FnOnce(web_sys::Element, A) -> C + 'static,
+    C: FnOnce() + 'static,
+{
+    fn apply(self, el: web_sys::Element, arg: A) {
+        let cleanup = self(el, arg);
+        on_cleanup(cleanup);
+    }
+}
@@ -5,6 +5,15 @@ use crate::*;
 /// element.
 pub trait AttributeValue: AttributeValueBoxed + 'static {
     fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>);
+
+    /// Converts the value into the value that is set when it is spread onto an element with
+    /// [`Attributes`]. By default, this is the value itself.
+    fn into_spread(self) -> Box<dyn AttributeValue>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
 }
 
 /// Type alias representing a possibly dynamic string value.
@@ -29,10 +38,50 @@ impl AttributeValue for MaybeDyn<JsValue> {
     }
 }
 
+/// A class that is added to or removed from an element depending on a possibly dynamic boolean
+/// value. This is set with the `class:*` directive.
+pub struct ClassToggle(pub MaybeDyn<bool>);
+impl AttributeValue for ClassToggle {
+    fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
+        el.set_class(name, self.0);
+    }
+}
+
+/// A single CSS property in the inline style of an element. This is set with the `style:*`
+/// directive.
+pub struct StyleProperty(pub StringAttribute);
+impl AttributeValue for StyleProperty {
+    fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
+        el.set_style_property(name, self.0);
+    }
+}
+
+/// The value of the `class` attribute. When it is spread onto an element with [`Attributes`], the
+/// classes are merged with the classes of the element instead of replacing them.
+pub struct ClassAttribute(pub StringAttribute);
+impl AttributeValue for ClassAttribute {
+    fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
+        el.set_attribute(name, self.0);
+    }
+
+    fn into_spread(self) -> Box<dyn AttributeValue> {
+        Box::new(MergeClasses(self.0))
+    }
+}
+
+/// The `class` attribute of [`Attributes`], which is merged with the classes of the element.
+struct MergeClasses(StringAttribute);
+impl AttributeValue for MergeClasses {
+    fn set_self(self, el: &mut HtmlNode, _name: Cow<'static, str>) {
+        el.add_classes(self.0);
+    }
+}
+
 /// Trait used to implement `AttributeValue` for `Box<dyn AttributeValue>`.
 #[doc(hidden)]
 pub trait AttributeValueBoxed: 'static {
     fn set_self_boxed(self: Box<Self>, el: &mut HtmlNode, name: Cow<'static, str>);
+    fn into_spread_boxed(self: Box<Self>) -> Box<dyn AttributeValue>;
 }
 
 impl<T> AttributeValueBoxed for T
@@ -42,12 +91,20 @@ where
     fn set_self_boxed(self: Box<Self>, el: &mut HtmlNode, name: Cow<'static, str>) {
         self.set_self(el, name);
     }
+
+    fn into_spread_boxed(self: Box<Self>) -> Box<dyn AttributeValue> {
+        self.into_spread()
+    }
 }
 
 impl AttributeValue for Box<dyn AttributeValue> {
     fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
         self.set_self_boxed(el, name);
     }
+
+    fn into_spread(self) -> Box<dyn AttributeValue> {
+        self.into_spread_boxed()
+    }
 }
 
 /// Implemented for all types that can accept attributes ([`AttributeValue`]).
@@ -58,6 +115,12 @@ pub trait SetAttribute {
         name: &'static str,
         value: impl FnMut(web_sys::Event) + 'static,
     );
+    fn set_event_handler_with_options(
+        &mut self,
+        name: &'static str,
+        options: events::EventOptions,
+        value: impl FnMut(web_sys::Event) + 'static,
+    );
 }
 
 impl<T> SetAttribute for T
@@ -75,19 +138,39 @@ where
     ) {
         self.as_html_node().set_event_handler(name.into(), value);
     }
+
+    fn set_event_handler_with_options(
+        &mut self,
+        name: &'static str,
+        options: events::EventOptions,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.as_html_node()
+            .set_event_handler_with_options(name.into(), options, value);
+    }
 }
 
 /// A special prop type that can be used to spread attributes onto an element.
+///
+/// When spread onto an element, the attributes are applied in the same order as the other
+/// attributes of the element, so they override the attributes before the spread and are overridden
+/// by the attributes after it. The `class` attribute is merged with the classes of the element
+/// instead of replacing them, and event handlers are added in addition to the event handlers of the
+/// element.
 #[derive(Default)]
 pub struct Attributes {
     values: Vec<(Cow<'static, str>, Box<dyn AttributeValue>)>,
     #[allow(clippy::type_complexity)]
-    event_handlers: Vec<(Cow<'static, str>, Box<dyn FnMut(web_sys::Event)>)>,
+    event_handlers: Vec<(
+        Cow<'static, str>,
+        events::EventOptions,
+        Box<dyn FnMut(web_sys::Event)>,
+    )>,
 }
 
 impl SetAttribute for Attributes {
     fn set_attribute(&mut self, name: &'static str, value: impl AttributeValue) {
-        self.values.push((name.into(), Box::new(value)));
+        self.values.push((name.into(), value.into_spread()));
     }
 
     fn set_event_handler(
@@ -95,7 +178,17 @@ impl SetAttribute for Attributes {
         name: &'static str,
         value: impl FnMut(web_sys::Event) + 'static,
     ) {
-        self.event_handlers.push((name.into(), Box::new(value)));
+        self.set_event_handler_with_options(name, events::EventOptions::new(), value);
+    }
+
+    fn set_event_handler_with_options(
+        &mut self,
+        name: &'static str,
+        options: events::EventOptions,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.event_handlers
+            .push((name.into(), options, Box::new(value)));
     }
 }
 
@@ -105,12 +198,42 @@ impl Attributes {
         Self::default()
     }
 
+    /// Removes all the attributes and event handlers with any of the given names.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// #[component(inline_props)]
+    /// fn Input(#[prop(attributes(html, input))] attributes: Attributes) -> View {
+    ///     // The `id` is always generated by the component.
+    ///     view! {
+    ///         input(id="generated-id", ..attributes.without(["id"]))
+    ///     }
+    /// }
+    /// ```
+    pub fn without<'a>(mut self, names: impl IntoIterator<Item = &'a str>) -> Self {
+        for name in names {
+            self.values.retain(|(value_name, _)| value_name != name);
+            self.event_handlers
+                .retain(|(handler_name, ..)| handler_name != name);
+        }
+        self
+    }
+
+    /// Adds all the attributes and event handlers of `other` to these attributes. The attributes
+    /// of `other` take precedence, except for `class` which is merged and event handlers which are
+    /// all added.
+    pub fn merge(&mut self, other: Attributes) {
+        self.values.extend(other.values);
+        self.event_handlers.extend(other.event_handlers);
+    }
+
     pub fn apply_self(self, el: &mut HtmlNode) {
         for (name, value) in self.values {
             value.set_self(el, name);
         }
-        for (name, handler) in self.event_handlers {
-            el.set_event_handler(name, handler);
+        for (name, options, handler) in self.event_handlers {
+            el.set_event_handler_with_options(name, options, handler);
         }
     }
 }
@@ -120,6 +243,7 @@ mod tests {
     use expect_test::{Expect, expect};
 
     use super::*;
+    use crate::tags::*;
 
     fn check<T: Into<View>>(view: impl FnOnce() -> T, expect: Expect) {
         let actual = render_to_string(move || view().into());
@@ -149,4 +273,59 @@ mod tests {
             expect![[r#"<div class="test-class" id="test-id" data-hk="0.0"></div>"#]],
         );
     }
+
+    #[test]
+    fn spread_merges_class() {
+        let mut attributes = Attributes::new();
+        attributes.set_attribute("class", ClassAttribute("b c".into()));
+        attributes.set_attribute("class", ClassAttribute("a d".into()));
+
+        check(
+            move || view! { div(class="a b", ..attributes) },
+            expect![[r#"<div class="a b c d" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn spread_in_source_order() {
+        let mut attributes = Attributes::new();
+        attributes.set_attribute("type", StringAttribute::from("submit"));
+        attributes.set_attribute("id", StringAttribute::from("spread"));
+
+        check(
+            move || view! { button(r#type="button", ..attributes, id="button") },
+            expect![[r#"<button type="submit" id="button" data-hk="0.0"></button>"#]],
+        );
+    }
+
+    #[test]
+    fn attributes_without() {
+        let mut attributes = Attributes::new();
+        attributes.set_attribute("id", StringAttribute::from("removed"));
+        attributes.set_attribute("class", ClassAttribute("removed".into()));
+        attributes.set_attribute("title", StringAttribute::from("kept"));
+
+        check(
+            move || view! { div(id="id", ..attributes.without(["id", "class"])) },
+            expect![[r#"<div id="id" title="kept" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn forward_attributes_through_components() {
+        #[component(inline_props)]
+        fn Inner(#[prop(attributes(html, button))] attributes: Attributes) -> View {
+            view! { button(class="inner", r#type="button", ..attributes) }
+        }
+
+        #[component(inline_props)]
+        fn Outer(#[prop(attributes(html, button))] attributes: Attributes) -> View {
+            view! { Inner(class="outer", ..attributes) }
+        }
+
+        check(
+            move || view! { Outer(class="user", r#type="submit", on:click=|_| {}) },
+            expect![[r#"<button class="inner outer user" type="submit" data-hk="0.0"></button>"#]],
+        );
+    }
 }
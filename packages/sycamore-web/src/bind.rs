@@ -1,41 +1,260 @@
 //! Definition for bind-able attributes/properties.
 
-use crate::events::EventDescriptor;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
 use crate::*;
 
-/// Description for a bind-able attribute/property.
-pub trait BindDescriptor {
-    /// The event which we listen to to update the value.
-    type Event: EventDescriptor;
-    /// The value of the signal that drives the attribute/property.
-    type ValueTy: Into<JsValue> + Clone;
+/// When the signal of a bind is updated from the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindTrigger {
+    /// After the event with this name is fired on the element.
+    Event(&'static str),
+    /// When the element is resized, using a `ResizeObserver`.
+    Resize,
+}
+
+/// Description for a bind-able attribute/property with a signal of type `T`.
+///
+/// A descriptor can be implemented for several signal types. For instance, [`value`] binds to a
+/// `String` for most elements and to a `Vec<String>` for a `<select multiple>`.
+pub trait BindDescriptor<T: 'static>: 'static {
     /// The name of the property to which we are binding.
-    const TARGET_PROPERTY: &'static str;
-    /// Function for converting from JS to Rust type.
-    const CONVERT_FROM_JS: for<'a> fn(&'a JsValue) -> Option<Self::ValueTy>;
-}
-
-macro_rules! impl_bind {
-    ($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr) => {
-        #[allow(non_camel_case_types)]
-        pub struct $name;
-        impl BindDescriptor for $name {
-            type Event = $event;
-            type ValueTy = $value;
-            const TARGET_PROPERTY: &'static str = $target;
-            const CONVERT_FROM_JS: for<'a> fn(&'a JsValue) -> Option<Self::ValueTy> = $fn;
+    const NAME: &'static str;
+    /// When the signal is updated from the element.
+    const TRIGGER: BindTrigger;
+    /// Read-only binds only update the signal from the element. The signal is first updated once
+    /// the element is mounted.
+    const READ_ONLY: bool = false;
+
+    /// Updates the signal from the element.
+    fn read(el: &web_sys::Element, signal: Signal<T>);
+    /// Updates the element from the value of the signal. This is not called for read-only binds.
+    fn write(_el: &web_sys::Element, _current: &T) {}
+}
+
+/// The value of a `bind:*` directive. This is created by
+/// [`GlobalAttributes::bind`](crate::GlobalAttributes::bind).
+pub(crate) struct Bind<T: 'static, E>(Signal<T>, PhantomData<E>);
+
+impl<T: 'static, E: BindDescriptor<T>> Bind<T, E> {
+    pub(crate) fn new(signal: Signal<T>) -> Self {
+        Self(signal, PhantomData)
+    }
+}
+
+impl<T: 'static, E: BindDescriptor<T>> AttributeValue for Bind<T, E> {
+    fn set_self(self, node: &mut HtmlNode, _name: Cow<'static, str>) {
+        // Binds are never rendered on the server.
+        if is_ssr!() {
+            return;
+        }
+        let signal = self.0;
+        let el = node
+            .as_web_sys()
+            .clone()
+            .unchecked_into::<web_sys::Element>();
+        let scope = use_current_scope(); // Read the element inside the current scope.
+        let read = {
+            let el = el.clone();
+            move || scope.run_in(|| E::read(&el, signal))
+        };
+
+        match E::TRIGGER {
+            BindTrigger::Event(name) => {
+                let read = read.clone();
+                node.set_event_handler(name.into(), move |_| read());
+            }
+            BindTrigger::Resize => {
+                let read = read.clone();
+                crate::observers::observe_resize(&el, move |_| read());
+            }
+        }
+
+        if E::READ_ONLY {
+            on_mount(read);
+        } else {
+            create_effect(move || signal.with(|current| E::write(&el, current)));
         }
+    }
+}
+
+macro_rules! impl_bind_structs {
+    ($($name:ident,)*) => {
+        $(
+            #[allow(non_camel_case_types)]
+            pub struct $name;
+        )*
+    };
+}
+
+impl_bind_structs! {
+    value,
+    valueAsNumber,
+    valueAsDate,
+    checked,
+    group,
+    files,
+    textContent,
+    innerHTML,
+    clientWidth,
+    clientHeight,
+    scrollTop,
+    scrollLeft,
+}
+
+/// Implements [`BindDescriptor`] for binds that read and write the JS property with the same name
+/// as the bind.
+macro_rules! impl_property_binds {
+    ($($name:ident: $trigger:expr, $value:ty, $fn:expr $(, $read_only:ident)?;)*) => {
+        $(impl_property_bind!($name: $trigger, $value, $fn $(, $read_only)?);)*
     };
 }
 
-macro_rules! impl_binds {
-    ($($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr,)*) => {
-        $(impl_bind!($name: $event, $value, $target, $fn);)*
+macro_rules! impl_property_bind {
+    ($name:ident: $trigger:expr, $value:ty, $fn:expr) => {
+        impl BindDescriptor<$value> for $name {
+            const NAME: &'static str = stringify!($name);
+            const TRIGGER: BindTrigger = $trigger;
+
+            fn read(el: &web_sys::Element, signal: Signal<$value>) {
+                signal.set(read_property(el, stringify!($name), $fn));
+            }
+
+            fn write(el: &web_sys::Element, current: &$value) {
+                write_property(el, stringify!($name), current.clone().into());
+            }
+        }
+    };
+    ($name:ident: $trigger:expr, $value:ty, $fn:expr, read_only) => {
+        impl BindDescriptor<$value> for $name {
+            const NAME: &'static str = stringify!($name);
+            const TRIGGER: BindTrigger = $trigger;
+            const READ_ONLY: bool = true;
+
+            fn read(el: &web_sys::Element, signal: Signal<$value>) {
+                signal.set(read_property(el, stringify!($name), $fn));
+            }
+        }
     };
 }
 
-impl_binds! {
-    value: events::input, String, "value", JsValue::as_string,
-    valueAsNumber: events::input, f64, "valueAsNumber", JsValue::as_f64,
-    checked: events::change, bool, "checked", JsValue::as_bool,
+fn read_property<T>(el: &web_sys::Element, name: &str, f: fn(&JsValue) -> Option<T>) -> T {
+    let js = js_sys::Reflect::get(el, &name.into()).unwrap_throw();
+    f(&js).expect("failed to convert value from js")
+}
+
+/// Sets the property unless it already has the value. This happens when the signal was just
+/// updated from the element, in which case setting e.g. the `textContent` of a contenteditable
+/// would replace its children and reset the caret.
+fn write_property(el: &web_sys::Element, name: &str, js: JsValue) {
+    let name = JsValue::from(name);
+    if js_sys::Reflect::get(el, &name).unwrap_throw() != js {
+        js_sys::Reflect::set(el, &name, &js).unwrap_throw();
+    }
+}
+
+fn as_date(js: &JsValue) -> Option<Option<js_sys::Date>> {
+    Some(js.dyn_ref().cloned())
+}
+
+impl_property_binds! {
+    value: BindTrigger::Event("input"), String, JsValue::as_string;
+    valueAsNumber: BindTrigger::Event("input"), f64, JsValue::as_f64;
+    valueAsDate: BindTrigger::Event("input"), Option<js_sys::Date>, as_date;
+    checked: BindTrigger::Event("change"), bool, JsValue::as_bool;
+    textContent: BindTrigger::Event("input"), String, JsValue::as_string;
+    innerHTML: BindTrigger::Event("input"), String, JsValue::as_string;
+    clientWidth: BindTrigger::Resize, f64, JsValue::as_f64, read_only;
+    clientHeight: BindTrigger::Resize, f64, JsValue::as_f64, read_only;
+    scrollTop: BindTrigger::Event("scroll"), f64, JsValue::as_f64, read_only;
+    scrollLeft: BindTrigger::Event("scroll"), f64, JsValue::as_f64, read_only;
+}
+
+/// Returns the `<option>` elements of a `<select>` element.
+fn select_options(el: &web_sys::Element) -> impl Iterator<Item = web_sys::HtmlOptionElement> {
+    let select = el.unchecked_ref::<web_sys::HtmlSelectElement>().clone();
+    (0..select.length())
+        .filter_map(move |i| select.item(i))
+        .map(JsCast::unchecked_into)
+}
+
+/// The values of the selected options of a `<select multiple>`.
+impl BindDescriptor<Vec<String>> for value {
+    const NAME: &'static str = "value";
+    const TRIGGER: BindTrigger = BindTrigger::Event("change");
+
+    fn read(el: &web_sys::Element, signal: Signal<Vec<String>>) {
+        signal.set(
+            select_options(el)
+                .filter(|option| option.selected())
+                .map(|option| option.value())
+                .collect(),
+        );
+    }
+
+    fn write(el: &web_sys::Element, current: &Vec<String>) {
+        for option in select_options(el) {
+            option.set_selected(current.contains(&option.value()));
+        }
+    }
+}
+
+/// The value of the checked radio button of a group of radio buttons.
+impl BindDescriptor<String> for group {
+    const NAME: &'static str = "group";
+    const TRIGGER: BindTrigger = BindTrigger::Event("change");
+
+    fn read(el: &web_sys::Element, signal: Signal<String>) {
+        let input = el.unchecked_ref::<web_sys::HtmlInputElement>();
+        if input.checked() {
+            signal.set(input.value());
+        }
+    }
+
+    fn write(el: &web_sys::Element, current: &String) {
+        let input = el.unchecked_ref::<web_sys::HtmlInputElement>();
+        input.set_checked(input.value() == *current);
+    }
+}
+
+/// The values of the checked checkboxes of a group of checkboxes. The `value` attribute of each
+/// checkbox is parsed into a `T`.
+impl<T: FromStr + ToString + 'static> BindDescriptor<Vec<T>> for group {
+    const NAME: &'static str = "group";
+    const TRIGGER: BindTrigger = BindTrigger::Event("change");
+
+    fn read(el: &web_sys::Element, signal: Signal<Vec<T>>) {
+        let input = el.unchecked_ref::<web_sys::HtmlInputElement>();
+        let own = input.value();
+        signal.update(|values| {
+            values.retain(|v| v.to_string() != own);
+            if input.checked()
+                && let Ok(v) = own.parse()
+            {
+                values.push(v);
+            }
+        });
+    }
+
+    fn write(el: &web_sys::Element, current: &Vec<T>) {
+        let input = el.unchecked_ref::<web_sys::HtmlInputElement>();
+        let own = input.value();
+        input.set_checked(current.iter().any(|v| v.to_string() == own));
+    }
+}
+
+/// The files selected in an `<input type="file">`.
+impl BindDescriptor<Vec<web_sys::File>> for files {
+    const NAME: &'static str = "files";
+    const TRIGGER: BindTrigger = BindTrigger::Event("change");
+    const READ_ONLY: bool = true;
+
+    fn read(el: &web_sys::Element, signal: Signal<Vec<web_sys::File>>) {
+        let list = el.unchecked_ref::<web_sys::HtmlInputElement>().files();
+        signal.set(
+            list.map(|list| (0..list.length()).filter_map(|i| list.get(i)).collect())
+                .unwrap_or_default(),
+        );
+    }
 }
@@ -13,9 +13,13 @@ pub struct ShowProps {
 }
 
 /// An utility component that only renders its children when a condition is satisfied.
+///
+/// Inside of a component that provides a [`PresenceHandle`], the children are only removed once
+/// the leave hook is done with them.
 #[component]
 pub fn Show(props: ShowProps) -> View {
-    let mut children = props.children.call();
+    let presence = use_presence();
+    let mut children = without_presence(&presence, || props.children.call());
     let when = create_selector(move || props.when.get());
 
     if is_ssr!() {
@@ -27,22 +31,98 @@ pub fn Show(props: ShowProps) -> View {
             }
         })
     } else {
-        View::from_dynamic(move || {
-            let cloned = utils::clone_nodes_via_web_sys(&children);
+        let start = HtmlNode::create_marker_node();
+        let start_node = start.as_web_sys().clone();
+        let end = HtmlNode::create_marker_node();
+        let end_node = end.as_web_sys().clone();
+        // Hidden children are kept inside a document fragment so that they can still be
+        // dynamically updated even though they are not mounted.
+        let fragment = document().create_document_fragment();
+        // Incremented on every update so that a pending leave hook does not hide the children
+        // after they have been shown again.
+        let generation = Rc::new(Cell::new(0usize));
 
-            if when.get() {
-                // Do not set `children` to the document fragment since the document fragment will
-                // be emptied when it is appended.
-                children = utils::unwrap_from_document_fragment(cloned);
+        if let Some(presence) = presence.clone()
+            && when.get_untracked()
+        {
+            let start_node = start_node.clone();
+            let end_node = end_node.clone();
+            on_mount(move || {
+                for node in utils::get_nodes_between(&start_node, &end_node) {
+                    presence.appear(&node);
+                }
+            });
+        }
 
-                utils::clone_nodes_via_web_sys(&children)
+        let view = create_effect_initial(move || {
+            let view = if when.get() {
+                children
             } else {
-                // Wrap children inside a document fragment so that it can still be dynamically
-                // updated even though it is not mounted.
-                children = utils::wrap_in_document_fragment(cloned);
-                view! {}
-            }
-        })
+                for node in children.as_web_sys() {
+                    fragment.append_child(&node).unwrap();
+                }
+                View::new()
+            };
+            (
+                Box::new(move || {
+                    let Some(parent) = start_node.parent_node() else {
+                        if cfg!(debug_assertions) {
+                            console_warn!("cannot update a dynamic view if it is not mounted");
+                        }
+                        return;
+                    };
+                    generation.set(generation.get() + 1);
+                    if when.get() {
+                        // The children might still be mounted if they were leaving.
+                        parent.insert_before(&fragment, Some(&end_node)).unwrap();
+                        if let Some(presence) = &presence {
+                            for node in utils::get_nodes_between(&start_node, &end_node) {
+                                presence.enter(&node);
+                            }
+                        }
+                    } else {
+                        let nodes = utils::get_nodes_between(&start_node, &end_node);
+                        let hide = {
+                            let fragment = fragment.clone();
+                            let start_node = start_node.clone();
+                            let end_node = end_node.clone();
+                            let generation = generation.clone();
+                            let current = generation.get();
+                            move || {
+                                if generation.get() == current {
+                                    for node in utils::get_nodes_between(&start_node, &end_node) {
+                                        fragment.append_child(&node).unwrap();
+                                    }
+                                }
+                            }
+                        };
+                        match &presence {
+                            Some(presence) if !nodes.is_empty() => {
+                                // Hide the children once the last of them is done leaving.
+                                let remaining = Rc::new(Cell::new(nodes.len()));
+                                let hide = Rc::new(Cell::new(Some(hide)));
+                                for node in &nodes {
+                                    let remaining = remaining.clone();
+                                    let hide = hide.clone();
+                                    presence.leave(node, move || {
+                                        remaining.set(remaining.get() - 1);
+                                        if remaining.get() == 0
+                                            && let Some(hide) = hide.take()
+                                        {
+                                            hide();
+                                        }
+                                    });
+                                }
+                            }
+                            _ => hide(),
+                        }
+                    }
+                }) as Box<dyn FnMut()>,
+                view,
+            )
+        });
+
+        View::from((start, view, end))
     }
 }
 
@@ -0,0 +1,324 @@
+//! Defining custom elements which are rendered with Sycamore.
+//!
+//! This is the reverse of [`custom_element`](crate::custom_element), which creates an element that
+//! is defined elsewhere.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::*;
+
+/// A type that can be registered as a custom element with [`define_custom_element`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::WebComponent;
+/// #[derive(Props, CustomElementProps)]
+/// struct GreetingProps {
+///     name: ReadSignal<Option<String>>,
+/// }
+///
+/// #[component]
+/// fn Greeting(props: GreetingProps) -> View {
+///     view! {
+///         p { "Hello, " (props.name.get_clone().unwrap_or_else(|| "World".to_string())) "!" }
+///     }
+/// }
+///
+/// struct GreetingElement;
+///
+/// impl WebComponent for GreetingElement {
+///     type Props = GreetingProps;
+///     const STYLES: &'static str = "p { color: green; }";
+///
+///     fn render(props: GreetingProps) -> View {
+///         view! { Greeting(name=props.name) }
+///     }
+/// }
+/// ```
+pub trait WebComponent: 'static {
+    /// The props that are created from the attributes of the element. The attributes are observed
+    /// so that the props are updated whenever they change.
+    type Props: CustomElementProps;
+    /// Whether to render into an open shadow root attached to the element instead of into the
+    /// element itself.
+    const SHADOW_ROOT: bool = true;
+    /// CSS that is added in a `<style>` element before the rendered view. The styles are only
+    /// scoped to the element if [`SHADOW_ROOT`](Self::SHADOW_ROOT) is `true`.
+    const STYLES: &'static str = "";
+
+    /// Renders the contents of the element. This is called every time the element is connected
+    /// to the document.
+    fn render(props: Self::Props) -> View;
+}
+
+/// Props that are created from the attributes of a custom element. This is usually implemented
+/// with `#[derive(CustomElementProps)]`, which maps every field to the attribute with the same
+/// name, with `_` replaced by `-`. The type of every field must implement [`FromAttribute`].
+pub trait CustomElementProps: Sized + 'static {
+    /// The names of the attributes that the props are created from.
+    const OBSERVED_ATTRIBUTES: &'static [&'static str];
+
+    /// Creates the props from the values of the attributes, which are in the same order as
+    /// [`OBSERVED_ATTRIBUTES`](Self::OBSERVED_ATTRIBUTES).
+    fn from_attributes(attributes: &[ReadSignal<Option<String>>]) -> Self;
+}
+
+impl CustomElementProps for () {
+    const OBSERVED_ATTRIBUTES: &'static [&'static str] = &[];
+
+    fn from_attributes(_: &[ReadSignal<Option<String>>]) {}
+}
+
+/// A prop that can be created from the value of an attribute of a custom element.
+pub trait FromAttribute: 'static {
+    /// Creates the prop from the value of the attribute, or `None` if the attribute is not set.
+    fn from_attribute(value: ReadSignal<Option<String>>) -> Self;
+}
+
+/// The parsed value of the attribute, or `None` if the attribute is not set or cannot be parsed.
+impl<T: FromStr + 'static> FromAttribute for ReadSignal<Option<T>> {
+    fn from_attribute(value: ReadSignal<Option<String>>) -> Self {
+        create_memo(move || value.with(|value| value.as_deref()?.parse().ok()))
+    }
+}
+
+/// Whether the attribute is set, like a boolean HTML attribute.
+impl FromAttribute for ReadSignal<bool> {
+    fn from_attribute(value: ReadSignal<Option<String>>) -> Self {
+        create_selector(move || value.with(Option::is_some))
+    }
+}
+
+impl<T: FromStr + Clone + 'static> FromAttribute for MaybeDyn<Option<T>> {
+    fn from_attribute(value: ReadSignal<Option<String>>) -> Self {
+        ReadSignal::<Option<T>>::from_attribute(value).into()
+    }
+}
+
+impl FromAttribute for MaybeDyn<bool> {
+    fn from_attribute(value: ReadSignal<Option<String>>) -> Self {
+        ReadSignal::<bool>::from_attribute(value).into()
+    }
+}
+
+/// A custom element that is currently connected to the document.
+struct Instance {
+    scope: NodeHandle,
+    /// The values of the observed attributes.
+    attributes: Vec<Signal<Option<String>>>,
+    /// The node that the view was rendered into.
+    parent: web_sys::Node,
+    /// The markers around the view if it was rendered into the element itself, since the element
+    /// can have other children.
+    markers: Option<(web_sys::Node, web_sys::Node)>,
+}
+
+thread_local! {
+    static INSTANCES: RefCell<HashMap<usize, Instance>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+#[wasm_bindgen(inline_js = r#"
+export function defineCustomElement(name, observedAttributes, connected, disconnected, attributeChanged) {
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return observedAttributes;
+        }
+        connectedCallback() {
+            connected(this);
+        }
+        disconnectedCallback() {
+            disconnected(this);
+        }
+        attributeChangedCallback(name, _oldValue, newValue) {
+            attributeChanged(this, observedAttributes.indexOf(name), newValue);
+        }
+    });
+}
+"#)]
+extern "C" {
+    #[wasm_bindgen(catch, js_name = "defineCustomElement")]
+    fn define_custom_element_js(
+        name: &str,
+        observed_attributes: js_sys::Array,
+        connected: &js_sys::Function,
+        disconnected: &js_sys::Function,
+        attribute_changed: &js_sys::Function,
+    ) -> Result<(), JsValue>;
+
+    /// Extend [`web_sys::HtmlElement`] with the id of its [`Instance`].
+    #[wasm_bindgen(extends = web_sys::HtmlElement)]
+    type CustomElementWithId;
+    #[wasm_bindgen(method, getter, js_name = "$sycamoreElement")]
+    fn instance_id(this: &CustomElementWithId) -> Option<usize>;
+    #[wasm_bindgen(method, setter, js_name = "$sycamoreElement")]
+    fn set_instance_id(this: &CustomElementWithId, id: Option<usize>);
+}
+
+/// Registers `C` as a custom element with the tag `name`, so that it can be used in any HTML page,
+/// including pages that are not rendered with Sycamore.
+///
+/// Every time an element is connected to the document, [`WebComponent::render`] is called in a
+/// new reactive scope with the props created from the attributes of the element, and the view is
+/// rendered into the element. The scope is disposed and the view is removed when the element is
+/// disconnected.
+///
+/// This does nothing in SSR mode.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{WebComponent, define_custom_element};
+/// struct Counter;
+///
+/// impl WebComponent for Counter {
+///     type Props = ();
+///
+///     fn render(_: ()) -> View {
+///         let count = create_signal(0);
+///         view! {
+///             button(on:click=move |_| count.set(count.get() + 1)) { (count.get()) }
+///         }
+///     }
+/// }
+///
+/// define_custom_element::<Counter>("my-counter");
+/// // `<my-counter></my-counter>` can now be used in HTML.
+/// ```
+///
+/// # Panics
+/// Panics if `name` is not a valid custom element name or if an element with this name is already
+/// defined.
+pub fn define_custom_element<C: WebComponent>(name: &str) {
+    if is_ssr!() {
+        return;
+    }
+    // The definition is never removed, so neither is its root.
+    let root = create_root(|| {});
+
+    let connected = Closure::<dyn Fn(CustomElementWithId)>::new(move |el: CustomElementWithId| {
+        root.run_in(|| connect::<C>(el));
+    });
+    let disconnected = Closure::<dyn Fn(CustomElementWithId)>::new(disconnect);
+    let attribute_changed = Closure::<dyn Fn(CustomElementWithId, usize, Option<String>)>::new(
+        |el: CustomElementWithId, index: usize, value: Option<String>| {
+            let Some(id) = el.instance_id() else {
+                // Attributes set before the element is connected are read when it is connected.
+                return;
+            };
+            // Do not hold the borrow while updating the signal, since effects can connect other
+            // custom elements.
+            let signal = INSTANCES
+                .with(|instances| Some(*instances.borrow().get(&id)?.attributes.get(index)?));
+            if let Some(signal) = signal {
+                signal.set(value);
+            }
+        },
+    );
+
+    let observed_attributes = C::Props::OBSERVED_ATTRIBUTES
+        .iter()
+        .map(|name| JsValue::from_str(name))
+        .collect();
+    define_custom_element_js(
+        name,
+        observed_attributes,
+        connected.as_ref().unchecked_ref(),
+        disconnected.as_ref().unchecked_ref(),
+        attribute_changed.as_ref().unchecked_ref(),
+    )
+    .unwrap_or_else(|err| panic!("failed to define custom element `{name}`: {err:?}"));
+
+    connected.forget();
+    disconnected.forget();
+    attribute_changed.forget();
+}
+
+fn connect<C: WebComponent>(el: CustomElementWithId) {
+    let host = el.unchecked_ref::<web_sys::HtmlElement>().clone();
+    let parent: web_sys::Node = if C::SHADOW_ROOT {
+        host.shadow_root()
+            .unwrap_or_else(|| {
+                let init = web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open);
+                host.attach_shadow(&init).unwrap_throw()
+            })
+            .into()
+    } else {
+        host.clone().into()
+    };
+
+    let mut attributes = Vec::new();
+    let mut nodes = Vec::new();
+    let scope = create_child_scope(|| {
+        attributes = C::Props::OBSERVED_ATTRIBUTES
+            .iter()
+            .map(|&name| create_signal(host.get_attribute(name)))
+            .collect::<Vec<_>>();
+
+        if !C::STYLES.is_empty() {
+            let style = document().create_element("style").unwrap_throw();
+            style.set_text_content(Some(C::STYLES));
+            nodes.push(style.into());
+        }
+        // The element can be connected while a Sycamore app is being hydrated, but its contents
+        // are never hydrated.
+        let is_hydrating = IS_HYDRATING.replace(false);
+        let values = attributes.iter().map(|&value| *value).collect::<Vec<_>>();
+        let view = C::render(C::Props::from_attributes(&values));
+        IS_HYDRATING.set(is_hydrating);
+        nodes.extend(view.as_web_sys());
+    });
+
+    let markers = (!C::SHADOW_ROOT).then(|| {
+        let start: web_sys::Node = document().create_comment("").into();
+        let end: web_sys::Node = document().create_comment("").into();
+        nodes.insert(0, start.clone());
+        nodes.push(end.clone());
+        (start, end)
+    });
+    for node in &nodes {
+        parent.append_child(node).unwrap_throw();
+    }
+
+    let id = NEXT_ID.replace(NEXT_ID.get() + 1);
+    el.set_instance_id(Some(id));
+    INSTANCES.with(|instances| {
+        instances.borrow_mut().insert(
+            id,
+            Instance {
+                scope,
+                attributes,
+                parent,
+                markers,
+            },
+        )
+    });
+}
+
+fn disconnect(el: CustomElementWithId) {
+    let Some(id) = el.instance_id() else {
+        return;
+    };
+    el.set_instance_id(None);
+    let instance = INSTANCES.with(|instances| instances.borrow_mut().remove(&id));
+    if let Some(instance) = instance {
+        instance.scope.dispose();
+        // The view can have changed since it was rendered, so everything that is in its place now
+        // is removed.
+        match instance.markers {
+            Some((start, end)) => {
+                while let Some(node) = start.next_sibling() {
+                    instance.parent.remove_child(&node).unwrap_throw();
+                    if node == end {
+                        break;
+                    }
+                }
+                instance.parent.remove_child(&start).unwrap_throw();
+            }
+            None => instance.parent.set_text_content(None),
+        }
+    }
+}
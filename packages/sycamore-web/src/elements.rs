@@ -29,13 +29,21 @@ impl From<CustomElement> for View {
     }
 }
 
+impl FromHtmlNode for CustomElement {
+    fn from_html_node(node: HtmlNode) -> Self {
+        Self(node)
+    }
+}
+
 impl AsHtmlNode for CustomElement {
     fn as_html_node(&mut self) -> &mut HtmlNode {
         &mut self.0
     }
 }
 
-impl GlobalProps for CustomElement {}
+impl GlobalProps for CustomElement {
+    type Element = web_sys::HtmlElement;
+}
 impl HtmlGlobalAttributes for CustomElement {}
 
 macro_rules! impl_attribute {
@@ -45,12 +53,23 @@ macro_rules! impl_attribute {
     ($(#[$attr:meta])* $v:vis $ident:ident ($name:expr): $ty:ty) => {
         $(#[$attr])*
         $v fn $ident(mut self, value: $ty) -> Self {
-            self.set_attribute($name, value.into());
+            self.set_attribute($name, attribute_value!($ident, value));
             self
         }
     }
 }
 
+/// Converts the value of an attribute into the type that is set. The `class` attribute is carried
+/// as a [`ClassAttribute`] so that it can be merged when it is spread.
+macro_rules! attribute_value {
+    (class, $value:expr) => {
+        ClassAttribute($value.into())
+    };
+    ($ident:ident, $value:expr) => {
+        $value.into()
+    };
+}
+
 macro_rules! impl_attributes {
     ($(
         $(#[$attr:meta])*
@@ -62,17 +81,27 @@ macro_rules! impl_attributes {
     };
 }
 
+/// The type of the DOM node of an element, which defaults to [`web_sys::HtmlElement`].
+macro_rules! element_type {
+    () => {
+        web_sys::HtmlElement
+    };
+    ($el:ty) => {
+        $el
+    };
+}
+
 macro_rules! impl_element {
     (
         $(#[$attr:meta])*
-        $name:ident {
+        $name:ident $(as $el:ty)? {
             $(
                 $(#[$prop_attr:meta])*
                 $prop:ident $(($prop_name:literal))?: $ty:ty,
             )*
         }
     ) => {
-        impl_element!($(#[$attr])* $name (stringify!($name)) {
+        impl_element!($(#[$attr])* $name (stringify!($name)) $(as $el)? {
             $(
                 $(#[$prop_attr])*
                 $prop $(($prop_name))*: $ty,
@@ -81,7 +110,7 @@ macro_rules! impl_element {
     };
     (
         $(#[$attr:meta])*
-        $name:ident ($tag:expr) {
+        $name:ident ($tag:expr) $(as $el:ty)? {
             $(
                 $(#[$prop_attr:meta])*
                 $prop:ident $(($prop_name:literal))?: $ty:ty,
@@ -105,13 +134,21 @@ macro_rules! impl_element {
                 }
             }
 
+            impl FromHtmlNode for [<Html $name:camel>] {
+                fn from_html_node(node: HtmlNode) -> Self {
+                    Self(node)
+                }
+            }
+
             impl AsHtmlNode for [<Html $name:camel>] {
                 fn as_html_node(&mut self) -> &mut HtmlNode {
                     &mut self.0
                 }
             }
 
-            impl GlobalProps for [<Html $name:camel>] {}
+            impl GlobalProps for [<Html $name:camel>] {
+                type Element = element_type!($($el)?);
+            }
             impl HtmlGlobalAttributes for [<Html $name:camel>] {}
 
             #[doc = "Trait that provides attributes for the `<" $name ">` HTML element."]
@@ -132,7 +169,7 @@ macro_rules! impl_element {
 macro_rules! impl_elements {
     ($(
         $(#[$attr:meta])*
-        $name:ident $(($tag:expr))? {
+        $name:ident $(($tag:expr))? $(as $el:ty)? {
             $(
                 $(#[$prop_attr:meta])*
                 $prop:ident $(($prop_name:literal))?: $ty:ty,
@@ -140,7 +177,7 @@ macro_rules! impl_elements {
         },
     )*) => {
         $(
-            impl_element!($(#[$attr])* $name $(($tag))* {
+            impl_element!($(#[$attr])* $name $(($tag))* $(as $el)? {
                 $(
                     $(#[$prop_attr])*
                     $prop $(($prop_name))*: $ty,
@@ -199,13 +236,21 @@ macro_rules! impl_svg_element {
                 }
             }
 
+            impl FromHtmlNode for [<Svg $name:camel>] {
+                fn from_html_node(node: HtmlNode) -> Self {
+                    Self(node)
+                }
+            }
+
             impl AsHtmlNode for [<Svg $name:camel>] {
                 fn as_html_node(&mut self) -> &mut HtmlNode {
                     &mut self.0
                 }
             }
 
-            impl GlobalProps for [<Svg $name:camel>] {}
+            impl GlobalProps for [<Svg $name:camel>] {
+                type Element = web_sys::SvgElement;
+            }
             impl SvgGlobalAttributes for [<Svg $name:camel>] {}
 
             #[doc = "Trait that provides attributes for the `<" $name ">` SVG element."]
@@ -258,7 +303,7 @@ pub mod tags {
         /// The `<a>` HTML element (or anchor element), with its `href` attribute, creates a hyperlink to web pages, files, email addresses, locations in the same page, or anything else a URL can address.
         ///
         /// Content within each `<a>` should indicate the link's destination. If the `href` attribute is present, pressing the enter key while focused on the `<a>` element will activate it.
-        a {
+        a as web_sys::HtmlAnchorElement {
             download: impl Into<StringAttribute>,
             href: impl Into<StringAttribute>,
             hreflang: impl Into<StringAttribute>,
@@ -285,7 +330,7 @@ pub mod tags {
         },
         article {},
         aside {},
-        audio {
+        audio as web_sys::HtmlAudioElement {
             autoplay: impl Into<MaybeDyn<bool>>,
             controls: impl Into<MaybeDyn<bool>>,
             crossorigin: impl Into<StringAttribute>,
@@ -309,7 +354,7 @@ pub mod tags {
         /// The `<button>` HTML element represents a clickable button, used to submit forms or anywhere in a document for accessible, standard button functionality.
         ///
         /// By default, HTML buttons are presented in a style resembling the platform the user agent runs on, but you can change buttons’ appearance with CSS.
-        button {
+        button as web_sys::HtmlButtonElement {
             autofocus: impl Into<MaybeDyn<bool>>,
             disabled: impl Into<MaybeDyn<bool>>,
             form: impl Into<StringAttribute>,
@@ -322,7 +367,7 @@ pub mod tags {
             r#type("type"): impl Into<StringAttribute>,
             value: impl Into<StringAttribute>,
         },
-        canvas {
+        canvas as web_sys::HtmlCanvasElement {
             height: impl Into<StringAttribute>, // TODO: int value
             width: impl Into<StringAttribute>, // TODO: int value
         },
@@ -346,11 +391,11 @@ pub mod tags {
             cite: impl Into<StringAttribute>,
             datetime: impl Into<StringAttribute>,
         },
-        details {
+        details as web_sys::HtmlDetailsElement {
             open: impl Into<MaybeDyn<bool>>,
         },
         dfn {},
-        dialog {},
+        dialog as web_sys::HtmlDialogElement {},
         /// The `<div>` HTML element is the generic container for flow content. It has no effect on the content or layout until styled in some way using CSS (e.g. styling is directly applied to it, or some kind of layout model like Flexbox is applied to its parent element).
         ///
         /// As a "pure" container, the `<div>` element does not inherently represent anything. Instead, it's used to group content so it can be easily styled using the class or id attributes, marking a section of a document as being written in a different language (using the lang attribute), and so on.
@@ -371,7 +416,7 @@ pub mod tags {
         figcaption {},
         figure {},
         footer {},
-        form {
+        form as web_sys::HtmlFormElement {
             acceptcharset: impl Into<StringAttribute>,
             action: impl Into<StringAttribute>,
             autocomplete: impl Into<StringAttribute>,
@@ -393,7 +438,7 @@ pub mod tags {
         hr {},
         html {},
         i {},
-        iframe {
+        iframe as web_sys::HtmlIFrameElement {
             allow: impl Into<StringAttribute>,
             allowfullscreen: impl Into<MaybeDyn<bool>>,
             allowpaymentrequest: impl Into<MaybeDyn<bool>>,
@@ -406,7 +451,7 @@ pub mod tags {
             srcdoc: impl Into<StringAttribute>,
             width: impl Into<StringAttribute>,
         },
-        img {
+        img as web_sys::HtmlImageElement {
             alt: impl Into<StringAttribute>,
             crossorigin: impl Into<StringAttribute>,
             decoding: impl Into<StringAttribute>,
@@ -421,7 +466,7 @@ pub mod tags {
             width: impl Into<StringAttribute>,
         },
         /// The `<input>` HTML element is used to create interactive controls for web-based forms in order to accept data from the user; a wide variety of types of input data and control widgets are available, depending on the device and user agent. The `<input>` element is one of the most powerful and complex in all of HTML due to the sheer number of combinations of input types and attributes.
-        input {
+        input as web_sys::HtmlInputElement {
             accept: impl Into<StringAttribute>,
             alt: impl Into<StringAttribute>,
             autocomplete: impl Into<StringAttribute>,
@@ -488,7 +533,7 @@ pub mod tags {
         /// <label for="username">Forgot your username?</label>
         /// ```
         /// Elements that can be associated with a `<label>` element include `<button>`, `<input>` (except for `type="hidden"`), `<meter>`, `<output>`, `<progress>`, `<select>` and `<textarea>`.
-        label {
+        label as web_sys::HtmlLabelElement {
             form: impl Into<StringAttribute>,
             r#for("for"): impl Into<StringAttribute>,
         },
@@ -553,7 +598,7 @@ pub mod tags {
             disabled: impl Into<MaybeDyn<bool>>,
             label: impl Into<StringAttribute>,
         },
-        option {
+        option as web_sys::HtmlOptionElement {
             disabled: impl Into<MaybeDyn<bool>>,
             initial_selected: impl Into<MaybeDyn<bool>>,
             label: impl Into<StringAttribute>,
@@ -600,7 +645,7 @@ pub mod tags {
             r#type("type"): impl Into<StringAttribute>,
         },
         section {},
-        select {
+        select as web_sys::HtmlSelectElement {
             autocomplete: impl Into<StringAttribute>,
             autofocus: impl Into<MaybeDyn<bool>>,
             disabled: impl Into<MaybeDyn<bool>>,
@@ -635,8 +680,8 @@ pub mod tags {
             headers: impl Into<StringAttribute>,
             rowspan: impl Into<StringAttribute>, // TODO: int value
         },
-        template {},
-        textarea {
+        template as web_sys::HtmlTemplateElement {},
+        textarea as web_sys::HtmlTextAreaElement {
             autocomplete: impl Into<StringAttribute>,
             autofocus: impl Into<MaybeDyn<bool>>,
             cols: impl Into<StringAttribute>, // TODO: int value
@@ -680,7 +725,7 @@ pub mod tags {
         /// The `<ul>` HTML element represents an unordered list of items, typically rendered as a bulleted list.
         ul {},
         var {},
-        video {
+        video as web_sys::HtmlVideoElement {
             autoplay: impl Into<MaybeDyn<bool>>,
             controls: impl Into<MaybeDyn<bool>>,
             crossorigin: impl Into<StringAttribute>,
@@ -1124,6 +1169,29 @@ pub trait GlobalAttributes: SetAttribute + Sized {
         self
     }
 
+    /// Add the class `name` if `value` is `true` and remove it otherwise. This is merged with the
+    /// `class` attribute.
+    fn toggle_class(mut self, name: &'static str, value: impl Into<MaybeDyn<bool>>) -> Self {
+        self.set_attribute(name, ClassToggle(value.into()));
+        self
+    }
+
+    /// Set the CSS property `name` in the inline style with `value`. This is merged with the
+    /// `style` attribute.
+    fn style_property(mut self, name: &'static str, value: impl Into<StringAttribute>) -> Self {
+        self.set_attribute(name, StyleProperty(value.into()));
+        self
+    }
+
+    /// Set the custom properties of the variables of `css` in the inline style. This is called by
+    /// the `view!` macro for the top-level elements of a view with a `#![css(...)]` attribute.
+    fn scoped_css_vars(mut self, css: &ScopedCss) -> Self {
+        for (name, value) in css.vars() {
+            self.set_attribute(name, StyleProperty(value.clone()));
+        }
+        self
+    }
+
     /// Set JS property `name` with `value`.
     fn prop(mut self, name: &'static str, value: impl Into<MaybeDyn<JsValue>>) -> Self {
         self.set_attribute(name, value.into());
@@ -1142,20 +1210,37 @@ pub trait GlobalAttributes: SetAttribute + Sized {
         self
     }
 
-    /// Set a two way binding with `name`.
-    fn bind<E: bind::BindDescriptor>(mut self, _: E, signal: Signal<E::ValueTy>) -> Self {
+    /// Set an event handler with `name` and the given options and modifiers.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use sycamore::web::events::{self, EventOptions};
+    /// # use sycamore::web::tags::input;
+    /// # fn Component() -> View {
+    /// input().on_with(events::keydown, EventOptions::new().key("Enter"), |_| {
+    ///     // submit the input
+    /// })
+    /// # .into()
+    /// # }
+    /// ```
+    fn on_with<E: events::EventDescriptor, R>(
+        mut self,
+        _: E,
+        options: events::EventOptions,
+        mut handler: impl EventHandler<E, R>,
+    ) -> Self {
         let scope = use_current_scope(); // Run handler inside the current scope.
-        let handler = move |ev: web_sys::Event| {
-            scope.run_in(|| {
-                let value =
-                    js_sys::Reflect::get(&ev.current_target().unwrap(), &E::TARGET_PROPERTY.into())
-                        .unwrap();
-                signal.set(E::CONVERT_FROM_JS(&value).expect("failed to convert value from js"));
-            })
-        };
-        self.set_event_handler(<E::Event as events::EventDescriptor>::NAME, handler);
+        let handler = options
+            .wrap(move |ev: web_sys::Event| scope.run_in(|| handler.call(ev.unchecked_into())));
+        self.set_event_handler_with_options(E::NAME, options, handler);
+        self
+    }
 
-        self.prop(E::TARGET_PROPERTY, move || signal.get_clone().into())
+    /// Set a two way binding with `name`. Binds are set up in the browser only.
+    fn bind<T: 'static, E: bind::BindDescriptor<T>>(mut self, _: E, signal: Signal<T>) -> Self {
+        self.set_attribute(E::NAME, bind::Bind::<T, E>::new(signal));
+        self
     }
 }
 
@@ -1163,6 +1248,11 @@ impl<T: GlobalProps> GlobalAttributes for T {}
 
 /// Props that are available on all elements.
 pub trait GlobalProps: GlobalAttributes + AsHtmlNode + Sized {
+    /// The type of the DOM node of this element, e.g. [`web_sys::HtmlInputElement`] for
+    /// `<input>`. A [`NodeRef<T>`] can be attached with [`r#ref`](#method.ref) if this type is a
+    /// `T`.
+    type Element: JsCast;
+
     /// Set the inner html of an element.
     fn dangerously_set_inner_html(mut self, inner_html: impl Into<Cow<'static, str>>) -> Self {
         self.as_html_node().set_inner_html(inner_html.into());
@@ -1175,14 +1265,32 @@ pub trait GlobalProps: GlobalAttributes + AsHtmlNode + Sized {
         self
     }
 
-    /// Set a [`NodeRef`] on this element.
-    fn r#ref(mut self, noderef: NodeRef) -> Self {
+    /// Set a [`NodeRef`] on this element. The node ref can be untyped or typed with the type of
+    /// this element or any of its parent types, e.g. [`web_sys::HtmlElement`].
+    fn r#ref<T: JsCast>(mut self, noderef: NodeRef<T>) -> Self
+    where
+        Self::Element: Into<T>,
+    {
         if is_not_ssr!() {
             noderef.set(Some(self.as_html_node().as_web_sys().clone()));
         }
         self
     }
 
+    /// Attach an [`Action`] to this element. The action is run with `arg` once the element is
+    /// mounted.
+    fn use_action<A: 'static, R>(mut self, action: impl Action<A, R>, arg: A) -> Self {
+        if is_not_ssr!() {
+            let el = self
+                .as_html_node()
+                .as_web_sys()
+                .clone()
+                .unchecked_into::<web_sys::Element>();
+            on_mount(move || action.apply(el, arg));
+        }
+        self
+    }
+
     fn spread(mut self, attributes: Attributes) -> Self {
         attributes.apply_self(self.as_html_node());
         self
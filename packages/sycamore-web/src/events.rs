@@ -181,6 +181,124 @@ impl_events! {
     visibilitychange: Event,
 }
 
+/// Options and modifiers for an event handler.
+///
+/// In the `view!` macro, these are set with modifiers on the `on:*` directive, e.g.
+/// `on:submit.prevent_default` or `on:keydown.enter`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Component() -> View {
+/// view! {
+///     form(on:submit.prevent_default=|_| { /* submit the form */ })
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventOptions {
+    pub(crate) capture: bool,
+    pub(crate) passive: bool,
+    pub(crate) once: bool,
+    prevent_default: bool,
+    stop_propagation: bool,
+    self_only: bool,
+    key: Option<&'static str>,
+}
+
+impl EventOptions {
+    /// Create new options for a plain bubbling event handler.
+    pub const fn new() -> Self {
+        Self {
+            capture: false,
+            passive: false,
+            once: false,
+            prevent_default: false,
+            stop_propagation: false,
+            self_only: false,
+            key: None,
+        }
+    }
+
+    /// Call the handler in the capture phase instead of the bubbling phase.
+    pub const fn capture(mut self) -> Self {
+        self.capture = true;
+        self
+    }
+
+    /// Promise the browser that the handler never calls `prevent_default`. This allows the
+    /// browser to scroll without waiting for the handler.
+    pub const fn passive(mut self) -> Self {
+        self.passive = true;
+        self
+    }
+
+    /// Remove the handler after it is called for the first time. Note that events that are
+    /// skipped because of another modifier, e.g. [`self_only`](Self::self_only), also count.
+    pub const fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    /// Call `prevent_default` on the event before calling the handler.
+    pub const fn prevent_default(mut self) -> Self {
+        self.prevent_default = true;
+        self
+    }
+
+    /// Call `stop_propagation` on the event before calling the handler.
+    pub const fn stop_propagation(mut self) -> Self {
+        self.stop_propagation = true;
+        self
+    }
+
+    /// Only call the handler if the event was fired on the element itself and not on one of its
+    /// descendants.
+    pub const fn self_only(mut self) -> Self {
+        self.self_only = true;
+        self
+    }
+
+    /// Only call the handler for keyboard events with the given
+    /// [`key`](KeyboardEvent::key), e.g. `"Enter"` or `"Escape"`.
+    pub const fn key(mut self, key: &'static str) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Returns whether the handler needs to be added with native listener options.
+    #[sycamore_macro::cfg_not_ssr]
+    pub(crate) fn has_listener_options(&self) -> bool {
+        self.capture || self.passive || self.once
+    }
+
+    /// Wrap `handler` so that the modifiers are applied before it is called.
+    pub(crate) fn wrap(
+        self,
+        mut handler: impl FnMut(Event) + 'static,
+    ) -> impl FnMut(Event) + 'static {
+        move |ev: Event| {
+            if let Some(key) = self.key
+                && ev
+                    .dyn_ref::<KeyboardEvent>()
+                    .is_none_or(|ev| ev.key() != key)
+            {
+                return;
+            }
+            if self.self_only && ev.target() != ev.current_target() {
+                return;
+            }
+            if self.prevent_default {
+                ev.prevent_default();
+            }
+            if self.stop_propagation {
+                ev.stop_propagation();
+            }
+            handler(ev);
+        }
+    }
+}
+
 pub trait EventHandler<E: EventDescriptor, R = ()>: 'static {
     fn call(&mut self, event: E::EventTy);
 }
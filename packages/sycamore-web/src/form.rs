@@ -0,0 +1,776 @@
+//! Forms with per-field state and validation.
+//!
+//! A form is a struct which implements [`Form`], usually with `#[derive(Form)]`. Calling
+//! [`create_form`] creates a [`Field`] for every field of the struct, which tracks the value, the
+//! touched and dirty state and the validation errors of the field as signals.
+
+use std::borrow::Borrow;
+#[cfg(feature = "suspense")]
+use std::cell::RefCell;
+#[cfg(feature = "suspense")]
+use std::future::Future;
+#[cfg(feature = "suspense")]
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[cfg(feature = "suspense")]
+use sycamore_futures::spawn_local_scoped;
+
+use crate::*;
+
+#[cfg(feature = "suspense")]
+type LocalFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// A trait that is implemented automatically by the `Form` derive macro.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// fn not_empty(value: &str) -> Result<(), String> {
+///     if value.is_empty() {
+///         Err("This field is required".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Form, Default)]
+/// struct Signup {
+///     #[form(validate = not_empty)]
+///     email: String,
+///     age: f64,
+///     newsletter: bool,
+/// }
+/// ```
+///
+/// This generates a `SignupFields` struct with a public [`Field`] for every field of `Signup`.
+pub trait Form: Sized + 'static {
+    /// The struct with a [`Field`] for every field of the form.
+    type Fields: Copy + 'static;
+
+    /// Creates the fields of the form with the initial values from `initial`.
+    fn create_fields(initial: Self) -> Self::Fields;
+    /// Collects the current values of the fields.
+    fn values(fields: &Self::Fields) -> Self;
+    /// Returns every field of the form.
+    fn fields(fields: &Self::Fields) -> Vec<Box<dyn FormField>>;
+}
+
+/// A type which can be the value of a [`Field`].
+pub trait FieldValue: Clone + PartialEq + 'static {
+    /// Sets the attributes that display the value on an `<input>` element and that update the
+    /// signal when the value of the element changes.
+    ///
+    /// The initial value is also set as an attribute so that the form can be posted before it is
+    /// hydrated.
+    fn set_attributes(attributes: &mut Attributes, signal: Signal<Self>);
+}
+
+impl FieldValue for String {
+    fn set_attributes(attributes: &mut Attributes, signal: Signal<Self>) {
+        let initial = signal.get_clone_untracked();
+        attributes.set_attribute("value", StringAttribute::from(initial));
+        attributes.set_attribute("value", bind::Bind::<_, bind::value>::new(signal));
+    }
+}
+
+impl FieldValue for f64 {
+    fn set_attributes(attributes: &mut Attributes, signal: Signal<Self>) {
+        let initial = signal.get_untracked().to_string();
+        attributes.set_attribute("value", StringAttribute::from(initial));
+        attributes.set_attribute(
+            "valueAsNumber",
+            bind::Bind::<_, bind::valueAsNumber>::new(signal),
+        );
+    }
+}
+
+impl FieldValue for bool {
+    fn set_attributes(attributes: &mut Attributes, signal: Signal<Self>) {
+        let initial = signal.get_untracked();
+        attributes.set_attribute("checked", BoolAttribute::from(initial));
+        attributes.set_attribute("checked", bind::Bind::<_, bind::checked>::new(signal));
+    }
+}
+
+/// A validator that checks the value of a field synchronously.
+type Validator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+
+/// A validator that checks the value of a field asynchronously.
+#[cfg(feature = "suspense")]
+type AsyncValidator<T> = Rc<dyn Fn(T) -> LocalFuture<Result<(), String>>>;
+
+/// A field of a form. This is created by [`create_form`] for every field of a [`Form`].
+pub struct Field<T: 'static> {
+    name: &'static str,
+    value: Signal<T>,
+    initial: Signal<T>,
+    touched: Signal<bool>,
+    errors: Signal<Vec<String>>,
+    validators: Signal<Vec<Validator<T>>>,
+    #[cfg(feature = "suspense")]
+    async_validators: Signal<Vec<AsyncValidator<T>>>,
+    /// The number of async validations that are in progress.
+    #[cfg(feature = "suspense")]
+    pending: Signal<u32>,
+    /// Incremented whenever the field is validated so that the results of outdated async
+    /// validations are discarded.
+    version: Signal<u32>,
+}
+
+impl<T: 'static> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for Field<T> {}
+
+impl<T: FieldValue> Field<T> {
+    /// Creates a new field. This is called by the `Form` derive macro.
+    ///
+    /// Once the field is touched, it is validated whenever its value changes.
+    pub fn new(name: &'static str, initial: T) -> Self {
+        let field = Self {
+            name,
+            value: create_signal(initial.clone()),
+            initial: create_signal(initial),
+            touched: create_signal(false),
+            errors: create_signal(Vec::new()),
+            validators: create_signal(Vec::new()),
+            #[cfg(feature = "suspense")]
+            async_validators: create_signal(Vec::new()),
+            #[cfg(feature = "suspense")]
+            pending: create_signal(0),
+            version: create_signal(0),
+        };
+        create_effect(move || {
+            field.value.track();
+            if field.touched.get_untracked() {
+                field.validate();
+            }
+        });
+        field
+    }
+
+    /// The name of the field, which is also the `name` attribute of its element.
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// The signal with the value of the field.
+    pub fn value(self) -> Signal<T> {
+        self.value
+    }
+
+    /// Gets the value of the field. This method is tracked.
+    pub fn get(self) -> T {
+        self.value.get_clone()
+    }
+
+    /// Sets the value of the field.
+    pub fn set(self, value: T) {
+        self.value.set(value);
+    }
+
+    /// Returns `true` if the field has lost focus at least once or if the form was submitted.
+    pub fn is_touched(self) -> bool {
+        self.touched.get()
+    }
+
+    /// Marks the field as touched.
+    pub fn touch(self) {
+        self.touched.set(true);
+    }
+
+    /// Returns `true` if the value of the field is different from its initial value.
+    pub fn is_dirty(self) -> bool {
+        self.value
+            .with(|value| self.initial.with(|initial| value != initial))
+    }
+
+    /// The validation errors of the field from the last time it was validated.
+    pub fn errors(self) -> ReadSignal<Vec<String>> {
+        *self.errors
+    }
+
+    /// The first validation error of the field, if any.
+    pub fn error(self) -> Option<String> {
+        self.errors.with(|errors| errors.first().cloned())
+    }
+
+    /// Returns `true` if the field had no validation errors the last time it was validated.
+    pub fn is_valid(self) -> bool {
+        self.errors.with(Vec::is_empty)
+    }
+
+    /// Adds a validator which returns an error message if the value is not valid. The validator can
+    /// also take a borrowed form of the value, e.g. a `&str` for a `String` field.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # #[derive(Form, Default)]
+    /// # struct Login { username: String }
+    /// # let _ = create_root(|| {
+    /// let form = sycamore::web::create_form::<Login>();
+    /// form.fields().username.add_validator(|username: &str| {
+    ///     if username.len() < 3 {
+    ///         Err("Too short".to_string())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn add_validator<V: ?Sized>(
+        self,
+        validator: impl Fn(&V) -> Result<(), String> + 'static,
+    ) -> Self
+    where
+        T: Borrow<V>,
+    {
+        let validator: Validator<T> = Rc::new(move |value| validator(value.borrow()));
+        self.validators
+            .update(|validators| validators.push(validator));
+        self
+    }
+
+    /// Runs the validators of the field and updates the errors. Returns `true` if the value is
+    /// valid.
+    ///
+    /// Async validators are only run if all the other validators succeed, and are not waited for.
+    /// Use [`Field::validate_async`] to wait for them.
+    pub fn validate(self) -> bool {
+        let is_valid = self.run_validators();
+        #[cfg(feature = "suspense")]
+        if is_valid && self.async_validators.with_untracked(|v| !v.is_empty()) {
+            spawn_local_scoped(async move {
+                self.validate_async().await;
+            });
+        }
+        is_valid
+    }
+
+    /// Runs the synchronous validators of the field and updates the errors.
+    fn run_validators(self) -> bool {
+        self.version.update(|version| *version += 1);
+        let errors = self.value.with_untracked(|value| {
+            self.validators.with_untracked(|validators| {
+                validators
+                    .iter()
+                    .filter_map(|validator| validator(value).err())
+                    .collect::<Vec<_>>()
+            })
+        });
+        let is_valid = errors.is_empty();
+        self.errors.set(errors);
+        is_valid
+    }
+
+    /// Resets the field to its initial value and clears its touched state and errors.
+    pub fn reset(self) {
+        self.touched.set(false);
+        self.version.update(|version| *version += 1);
+        self.value.set(self.initial.get_clone_untracked());
+        self.errors.set(Vec::new());
+    }
+
+    /// Returns the attributes to spread onto the `<input>` element of the field. This sets the
+    /// `name`, the value and the `aria-invalid` attributes of the element, and touches and
+    /// validates the field when the element loses focus.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # #[derive(Form, Default)]
+    /// # struct Login { username: String }
+    /// # fn Component() -> View {
+    /// let form = sycamore::web::create_form::<Login>();
+    /// let username = form.fields().username;
+    /// view! {
+    ///     input(..username.attributes())
+    ///     (username.error().unwrap_or_default())
+    /// }
+    /// # }
+    /// ```
+    pub fn attributes(self) -> Attributes {
+        let mut attributes = Attributes::new();
+        attributes.set_attribute("name", StringAttribute::from(self.name));
+        T::set_attributes(&mut attributes, self.value);
+        attributes.set_attribute(
+            "aria-invalid",
+            StringAttribute::from(move || {
+                (self.is_touched() && !self.is_valid()).then_some("true")
+            }),
+        );
+        attributes.set_event_handler("blur", move |_| {
+            self.touch();
+            self.validate();
+        });
+        attributes
+    }
+}
+
+#[cfg(feature = "suspense")]
+impl<T: FieldValue> Field<T> {
+    /// Adds an async validator which returns an error message if the value is not valid. Async
+    /// validators are only run if all the synchronous validators succeed.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # #[derive(Form, Default)]
+    /// # struct Login { username: String }
+    /// # async fn is_taken(_: &str) -> bool { false }
+    /// # let _ = create_root(|| {
+    /// let form = sycamore::web::create_form::<Login>();
+    /// form.fields()
+    ///     .username
+    ///     .add_async_validator(|username: String| async move {
+    ///         if is_taken(&username).await {
+    ///             Err("This username is taken".to_string())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn add_async_validator<F>(self, validator: impl Fn(T) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        let validator: AsyncValidator<T> = Rc::new(move |value| Box::pin(validator(value)));
+        self.async_validators
+            .update(|validators| validators.push(validator));
+        self
+    }
+
+    /// Returns `true` while async validators are running.
+    pub fn is_validating(self) -> bool {
+        self.pending.get() > 0
+    }
+
+    /// Runs all the validators of the field, including the async ones, and updates the errors.
+    /// Returns `true` if the value is valid.
+    ///
+    /// The errors are not updated if the field is validated again before the async validators
+    /// finish.
+    pub async fn validate_async(self) -> bool {
+        if !self.run_validators() {
+            return false;
+        }
+        let version = self.version.get_untracked();
+        let value = self.value.get_clone_untracked();
+        let validators = self.async_validators.get_clone_untracked();
+        if validators.is_empty() {
+            return true;
+        }
+
+        self.pending.update(|pending| *pending += 1);
+        let mut errors = Vec::new();
+        for validator in validators {
+            if let Err(err) = validator(value.clone()).await {
+                errors.push(err);
+            }
+        }
+        self.pending.update(|pending| *pending -= 1);
+
+        if self.version.get_untracked() != version {
+            return self.is_valid();
+        }
+        let is_valid = errors.is_empty();
+        self.errors.set(errors);
+        is_valid
+    }
+}
+
+/// A [`Field`] with its value type erased, for operations on all the fields of a form.
+pub trait FormField {
+    /// See [`Field::name`].
+    fn name(&self) -> &'static str;
+    /// See [`Field::is_touched`].
+    fn is_touched(&self) -> bool;
+    /// See [`Field::touch`].
+    fn touch(&self);
+    /// See [`Field::is_dirty`].
+    fn is_dirty(&self) -> bool;
+    /// See [`Field::is_valid`].
+    fn is_valid(&self) -> bool;
+    /// See [`Field::validate`].
+    fn validate(&self) -> bool;
+    /// See [`Field::reset`].
+    fn reset(&self);
+    /// See [`Field::is_validating`].
+    #[cfg(feature = "suspense")]
+    fn is_validating(&self) -> bool;
+    /// See [`Field::validate_async`].
+    #[cfg(feature = "suspense")]
+    fn validate_async(&self) -> LocalFuture<bool>;
+}
+
+impl<T: FieldValue> FormField for Field<T> {
+    fn name(&self) -> &'static str {
+        Field::name(*self)
+    }
+
+    fn is_touched(&self) -> bool {
+        Field::is_touched(*self)
+    }
+
+    fn touch(&self) {
+        Field::touch(*self);
+    }
+
+    fn is_dirty(&self) -> bool {
+        Field::is_dirty(*self)
+    }
+
+    fn is_valid(&self) -> bool {
+        Field::is_valid(*self)
+    }
+
+    fn validate(&self) -> bool {
+        Field::validate(*self)
+    }
+
+    fn reset(&self) {
+        Field::reset(*self);
+    }
+
+    #[cfg(feature = "suspense")]
+    fn is_validating(&self) -> bool {
+        Field::is_validating(*self)
+    }
+
+    #[cfg(feature = "suspense")]
+    fn validate_async(&self) -> LocalFuture<bool> {
+        Box::pin(Field::validate_async(*self))
+    }
+}
+
+/// A handler which is called with the values of a form when it is submitted. This is implemented
+/// for closures which take the values of the form, and for async closures if the `suspense`
+/// feature is enabled.
+pub trait SubmitHandler<T, R = ()>: 'static {
+    /// Calls the handler, returning the future of an async handler.
+    #[cfg(feature = "suspense")]
+    fn call(&mut self, values: T) -> Option<LocalFuture<()>>;
+    /// Calls the handler.
+    #[cfg(not(feature = "suspense"))]
+    fn call(&mut self, values: T);
+}
+
+impl<T, F> SubmitHandler<T, ()> for F
+where
+    F: FnMut(T) + 'static,
+{
+    #[cfg(feature = "suspense")]
+    fn call(&mut self, values: T) -> Option<LocalFuture<()>> {
+        self(values);
+        None
+    }
+
+    #[cfg(not(feature = "suspense"))]
+    fn call(&mut self, values: T) {
+        self(values);
+    }
+}
+
+/// Support submitting with async functions as well.
+#[cfg(feature = "suspense")]
+impl<T, F, R> SubmitHandler<T, events::AsyncHandler> for F
+where
+    F: FnMut(T) -> R + 'static,
+    R: Future<Output = ()> + 'static,
+{
+    fn call(&mut self, values: T) -> Option<LocalFuture<()>> {
+        Some(Box::pin(self(values)))
+    }
+}
+
+/// The state of a form. This is created with [`create_form`].
+pub struct FormState<T: Form> {
+    fields: T::Fields,
+    submitting: Signal<bool>,
+}
+
+impl<T: Form> Clone for FormState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Form> Copy for FormState<T> {}
+
+impl<T: Form> FormState<T> {
+    /// The fields of the form.
+    pub fn fields(self) -> T::Fields {
+        self.fields
+    }
+
+    /// Collects the current values of the fields. This method is tracked.
+    pub fn values(self) -> T {
+        T::values(&self.fields)
+    }
+
+    /// Returns `true` if every field had no validation errors the last time it was validated.
+    pub fn is_valid(self) -> bool {
+        T::fields(&self.fields).iter().all(|field| field.is_valid())
+    }
+
+    /// Returns `true` if any field is different from its initial value.
+    pub fn is_dirty(self) -> bool {
+        T::fields(&self.fields).iter().any(|field| field.is_dirty())
+    }
+
+    /// Returns `true` if any field has been touched.
+    pub fn is_touched(self) -> bool {
+        T::fields(&self.fields)
+            .iter()
+            .any(|field| field.is_touched())
+    }
+
+    /// Returns `true` while the form is being submitted, i.e. while async validators or an async
+    /// submit handler are running.
+    pub fn is_submitting(self) -> bool {
+        self.submitting.get()
+    }
+
+    /// Returns `true` while async validators of any field are running.
+    #[cfg(feature = "suspense")]
+    pub fn is_validating(self) -> bool {
+        T::fields(&self.fields)
+            .iter()
+            .any(|field| field.is_validating())
+    }
+
+    /// Touches and validates every field. Returns `true` if every field is valid.
+    ///
+    /// Async validators are not waited for. Use [`FormState::validate_async`] to wait for them.
+    pub fn validate(self) -> bool {
+        let fields = T::fields(&self.fields);
+        fields.iter().for_each(|field| field.touch());
+        // Validate every field, even after the first invalid one, so that all errors are shown.
+        let mut is_valid = true;
+        for field in fields {
+            is_valid &= field.validate();
+        }
+        is_valid
+    }
+
+    /// Touches and validates every field, including with async validators. Returns `true` if
+    /// every field is valid.
+    #[cfg(feature = "suspense")]
+    pub async fn validate_async(self) -> bool {
+        let fields = T::fields(&self.fields);
+        fields.iter().for_each(|field| field.touch());
+        let mut is_valid = true;
+        for field in fields {
+            is_valid &= field.validate_async().await;
+        }
+        is_valid
+    }
+
+    /// Resets every field to its initial value.
+    pub fn reset(self) {
+        T::fields(&self.fields)
+            .iter()
+            .for_each(|field| field.reset());
+    }
+
+    /// Returns the attributes to spread onto the `<form>` element. When the form is submitted, all
+    /// fields are validated and `on_submit` is called with the values if they are all valid.
+    ///
+    /// The event handler is only added once the form is hydrated. Before that, submitting the
+    /// form posts it to its `action` like any other HTML form. This is why the `method` and
+    /// `action` attributes should still be set for server-rendered forms.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # #[derive(Form, Default)]
+    /// # struct Login { username: String }
+    /// # fn Component() -> View {
+    /// let form = sycamore::web::create_form::<Login>();
+    /// view! {
+    ///     form(method="post", action="/login", ..form.attributes(|login: Login| {
+    ///         console_log!("logging in as {}", login.username);
+    ///     })) {
+    ///         input(..form.fields().username.attributes())
+    ///         button(r#type="submit") { "Log in" }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn attributes<R>(self, on_submit: impl SubmitHandler<T, R>) -> Attributes {
+        let mut attributes = Attributes::new();
+        self.set_submit_handler(&mut attributes, on_submit);
+        attributes
+    }
+
+    #[cfg(not(feature = "suspense"))]
+    fn set_submit_handler<R>(
+        self,
+        attributes: &mut Attributes,
+        mut on_submit: impl SubmitHandler<T, R>,
+    ) {
+        attributes.set_event_handler("submit", move |ev: web_sys::Event| {
+            ev.prevent_default();
+            if self.submitting.get_untracked() || !self.validate() {
+                return;
+            }
+            self.submitting.set(true);
+            on_submit.call(self.values());
+            self.submitting.set(false);
+        });
+    }
+
+    #[cfg(feature = "suspense")]
+    fn set_submit_handler<R>(
+        self,
+        attributes: &mut Attributes,
+        on_submit: impl SubmitHandler<T, R>,
+    ) {
+        let on_submit = Rc::new(RefCell::new(on_submit));
+        attributes.set_event_handler("submit", move |ev: web_sys::Event| {
+            ev.prevent_default();
+            if self.submitting.get_untracked() {
+                return;
+            }
+            let on_submit = Rc::clone(&on_submit);
+            self.submitting.set(true);
+            spawn_local_scoped(async move {
+                if self.validate_async().await {
+                    let future = on_submit.borrow_mut().call(self.values());
+                    if let Some(future) = future {
+                        future.await;
+                    }
+                }
+                self.submitting.set(false);
+            });
+        });
+    }
+}
+
+/// Creates the state of a form, with the default values of `T` as the initial values.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// #[derive(Form, Default)]
+/// struct Login {
+///     username: String,
+///     remember_me: bool,
+/// }
+///
+/// # fn Component() -> View {
+/// let form = sycamore::web::create_form::<Login>();
+/// let fields = form.fields();
+/// view! {
+///     form(..form.attributes(|login: Login| { /* ... */ })) {
+///         input(..fields.username.attributes())
+///         input(r#type="checkbox", ..fields.remember_me.attributes())
+///         button(r#type="submit", disabled=!form.is_valid()) { "Log in" }
+///     }
+/// }
+/// # }
+/// ```
+pub fn create_form<T: Form + Default>() -> FormState<T> {
+    create_form_with(T::default())
+}
+
+/// Creates the state of a form, with the values of `initial` as the initial values.
+pub fn create_form_with<T: Form>(initial: T) -> FormState<T> {
+    FormState {
+        fields: T::create_fields(initial),
+        submitting: create_signal(false),
+    }
+}
+
+#[cfg(test)]
+#[cfg_ssr]
+mod tests {
+    use expect_test::expect;
+    use sycamore_macro::Form;
+
+    use super::*;
+    use crate::tags::*;
+
+    fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Form, Default, Debug, PartialEq)]
+    struct Login {
+        #[form(validate = not_empty)]
+        username: String,
+        age: f64,
+        remember_me: bool,
+    }
+
+    #[test]
+    fn field_state() {
+        let _ = create_root(|| {
+            let form = create_form::<Login>();
+            let username = form.fields().username;
+            username.add_validator(|value: &str| {
+                if value.len() < 3 {
+                    Err("too short".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+            assert!(!username.is_touched());
+            assert!(!username.is_dirty());
+            assert!(username.is_valid());
+
+            assert!(!form.validate());
+            assert!(username.is_touched());
+            assert_eq!(username.errors().get_clone(), ["required", "too short"]);
+
+            // Touched fields are validated again whenever the value changes.
+            username.set("ab".to_string());
+            assert!(username.is_dirty());
+            assert_eq!(username.error().as_deref(), Some("too short"));
+            username.set("abc".to_string());
+            assert!(form.is_valid());
+            assert_eq!(
+                form.values(),
+                Login {
+                    username: "abc".to_string(),
+                    ..Default::default()
+                }
+            );
+
+            form.reset();
+            assert!(!form.is_touched());
+            assert!(!form.is_dirty());
+            assert_eq!(username.get(), "");
+        });
+    }
+
+    #[test]
+    fn render_form() {
+        let html = render_to_string(|| {
+            let form = create_form_with(Login {
+                username: "admin".to_string(),
+                age: 42.0,
+                remember_me: true,
+            });
+            let fields = form.fields();
+            view! {
+                form(method="post", action="/login", ..form.attributes(|_: Login| {})) {
+                    input(..fields.username.attributes())
+                    input(r#type="number", ..fields.age.attributes())
+                    input(r#type="checkbox", ..fields.remember_me.attributes())
+                }
+            }
+        });
+        expect![[r#"<form method="post" action="/login" data-hk="0.0"><input name="username" value="admin" data-hk="0.1"><input type="number" name="age" value="42" data-hk="0.2"><input type="checkbox" name="remember_me" checked data-hk="0.3"></form>"#]].assert_eq(&html);
+    }
+}
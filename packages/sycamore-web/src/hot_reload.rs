@@ -0,0 +1,237 @@
+//! Hot reloading of components in development builds.
+//!
+//! When the `hot-reload` feature is enabled, every non-generic `#[component]` registers its body
+//! in a registry, keyed by the path of the component (e.g. `my_app::pages::Counter`). The
+//! implementation of a component can then be swapped with [`replace_component`], or the component
+//! can be re-run with [`rerun_component`] if the code behind the function was patched in place.
+//!
+//! [`replace_component`] takes a function pointer, so the new implementation must already be
+//! compiled into the same binary. Newly compiled code can't be loaded into a running wasm module,
+//! so a dev server can't use it to swap in a component that was changed on disk. This requires a
+//! tool that patches the code of the binary in place and then calls [`rerun_component`].
+//!
+//! Only the scope of the component is re-created. Signals that are created outside of the
+//! component, e.g. in a parent component or in a context, keep their values. Signals that are
+//! created inside of the component are created again.
+//!
+//! A component can only be re-run if its props implement [`Clone`], since the props are needed
+//! again to re-create the component. Other components still use the latest implementation the
+//! next time they are created, e.g. when a parent component is re-run.
+//!
+//! Hot reloading wraps every component in a dynamic view which changes the rendered HTML, so the
+//! feature should be enabled for both the server and the client when using SSR. It has no effect
+//! in release builds.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use crate::*;
+
+/// A component in the registry.
+struct Entry {
+    /// The current implementation of the component. This is a `fn(P) -> View` where `P` is the
+    /// type of the props.
+    f: Box<dyn Any>,
+    /// A trigger for each instance of the component that can be re-run.
+    instances: Vec<Signal<()>>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<HashMap<&'static str, Entry>> = RefCell::new(HashMap::new());
+}
+
+/// Returns the current implementation of the component with the given key, registering `f` if the
+/// component is not registered yet.
+fn current<P: 'static>(key: &'static str, f: fn(P) -> View) -> fn(P) -> View {
+    REGISTRY.with_borrow_mut(|registry| {
+        let entry = registry.entry(key).or_insert_with(|| Entry {
+            f: Box::new(f),
+            instances: Vec::new(),
+        });
+        match entry.f.downcast_ref::<fn(P) -> View>() {
+            Some(current) => *current,
+            // The type of the props has changed, so the old implementation can no longer be used.
+            None => {
+                entry.f = Box::new(f);
+                f
+            }
+        }
+    })
+}
+
+/// Re-runs all the instances of a component. The registry must not be borrowed while the
+/// instances are re-run since the components access it.
+fn trigger(key: &str) {
+    let instances = REGISTRY.with_borrow(|registry| {
+        registry
+            .get(key)
+            .map(|entry| entry.instances.clone())
+            .unwrap_or_default()
+    });
+    for instance in instances {
+        if instance.is_alive() {
+            instance.set(());
+        }
+    }
+}
+
+/// Replaces the implementation of the component registered under `key` and re-runs all of its
+/// instances that can be re-run.
+///
+/// The key is the path of the component, e.g. `my_app::pages::Counter`. Nothing happens if the
+/// type of the props does not match.
+pub fn replace_component<P: 'static>(key: &str, f: fn(P) -> View) {
+    let replaced = REGISTRY.with_borrow_mut(|registry| match registry.get_mut(key) {
+        Some(entry) if entry.f.is::<fn(P) -> View>() => {
+            entry.f = Box::new(f);
+            true
+        }
+        _ => false,
+    });
+    if replaced {
+        trigger(key);
+    }
+}
+
+/// Re-runs all the instances of the component registered under `key` that can be re-run. This is
+/// useful if the code of the component was patched in place.
+pub fn rerun_component(key: &str) {
+    trigger(key);
+}
+
+/// Returns the keys of all the components that have been registered so far.
+pub fn registered_components() -> Vec<&'static str> {
+    REGISTRY.with_borrow(|registry| registry.keys().copied().collect())
+}
+
+/// A component that is being created. This is used by the `#[component]` macro.
+///
+/// The component is rendered with [`RerunComponent::render`] if the props implement [`Clone`] and
+/// with [`RenderComponent::render`] otherwise.
+#[doc(hidden)]
+pub struct HotComponent<P> {
+    key: &'static str,
+    f: fn(P) -> View,
+    props: Cell<Option<P>>,
+}
+
+impl<P> HotComponent<P> {
+    pub fn new(key: &'static str, f: fn(P) -> View, props: P) -> Self {
+        Self {
+            key,
+            f,
+            props: Cell::new(Some(props)),
+        }
+    }
+}
+
+/// Renders a component which can be re-run.
+#[doc(hidden)]
+pub trait RerunComponent {
+    fn render(&self) -> View;
+}
+
+impl<P: Clone + 'static> RerunComponent for HotComponent<P> {
+    fn render(&self) -> View {
+        let (key, f) = (self.key, self.f);
+        let props = self.props.take().unwrap();
+
+        let trigger = create_signal(());
+        current(key, f);
+        REGISTRY.with_borrow_mut(|registry| {
+            registry.get_mut(key).unwrap().instances.push(trigger);
+        });
+        on_cleanup(move || {
+            REGISTRY.with_borrow_mut(|registry| {
+                if let Some(entry) = registry.get_mut(key) {
+                    entry.instances.retain(|instance| *instance != trigger);
+                }
+            });
+        });
+
+        View::from_dynamic(move || {
+            trigger.track();
+            let f = current(key, f);
+            let props = props.clone();
+            untrack(move || f(props))
+        })
+    }
+}
+
+/// Renders a component which cannot be re-run because its props do not implement [`Clone`].
+#[doc(hidden)]
+pub trait RenderComponent {
+    fn render(&self) -> View;
+}
+
+impl<P: 'static> RenderComponent for &HotComponent<P> {
+    fn render(&self) -> View {
+        let f = current(self.key, self.f);
+        f(self.props.take().unwrap())
+    }
+}
+
+#[cfg(test)]
+#[cfg_ssr]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct CounterProps {
+        count: Signal<i32>,
+    }
+
+    fn counter_v1(props: CounterProps) -> View {
+        format!("v1: {}", props.count.get()).into()
+    }
+
+    fn counter_v2(props: CounterProps) -> View {
+        format!("v2: {}", props.count.get()).into()
+    }
+
+    struct NotCloneProps(String);
+
+    fn not_clone_v1(props: NotCloneProps) -> View {
+        format!("v1: {}", props.0).into()
+    }
+
+    fn not_clone_v2(props: NotCloneProps) -> View {
+        format!("v2: {}", props.0).into()
+    }
+
+    #[test]
+    fn replace_component_keeps_outer_signals() {
+        let html = render_to_string(|| {
+            let count = create_signal(0);
+            let view =
+                HotComponent::new("tests::Counter", counter_v1, CounterProps { count }).render();
+            count.set(1);
+            replace_component("tests::Counter", counter_v2);
+            view
+        });
+        assert_eq!(html, "<!--/-->v2: 1<!--/-->");
+        assert_eq!(registered_components(), ["tests::Counter"]);
+    }
+
+    #[test]
+    fn components_with_props_that_are_not_clone_use_latest_implementation() {
+        let html = render_to_string(|| {
+            let first = (&HotComponent::new(
+                "tests::NotClone",
+                not_clone_v1,
+                NotCloneProps("first".to_string()),
+            ))
+                .render();
+            replace_component("tests::NotClone", not_clone_v2);
+            let second = (&HotComponent::new(
+                "tests::NotClone",
+                not_clone_v1,
+                NotCloneProps("second".to_string()),
+            ))
+                .render();
+            View::from((first, second))
+        });
+        assert_eq!(html, "v1: firstv2: second");
+    }
+}
@@ -5,7 +5,8 @@
 
 #![allow(non_snake_case)]
 
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::ops::Deref;
 
@@ -102,6 +103,19 @@ where
         let end = HtmlNode::create_marker_node();
         let end_node = end.as_web_sys().clone();
 
+        let presence = use_presence();
+        if let Some(presence) = presence.clone() {
+            let start_node = start_node.clone();
+            let end_node = end_node.clone();
+            on_mount(move || {
+                for node in utils::get_nodes_between(&start_node, &end_node) {
+                    presence.appear(&node);
+                }
+            });
+        }
+        // The ids of the nodes that are kept in the DOM until the leave hook is done with them.
+        let leaving = Rc::new(RefCell::new(HashSet::new()));
+
         // Run the initial function in the outer scope, not the effect scope.
         // This is because we might want to create signals and other things managed by the reactive
         // tree that will be used in future triggers of this effect. These things must therefore
@@ -109,7 +123,12 @@ where
         let scope = use_current_scope();
         create_effect_initial(move || {
             scope.run_in(move || {
-                let nodes = map_keyed(list, move |x| view(x).into().as_web_sys(), key);
+                let item_presence = presence.clone();
+                let nodes = map_keyed(
+                    list,
+                    move |x| without_presence(&item_presence, || view(x).into().as_web_sys()),
+                    key,
+                );
                 // Flatten nodes.
                 let flattened = nodes.map(|x| x.iter().flatten().cloned().collect::<Vec<_>>());
                 let view = flattened.with(|x| {
@@ -124,13 +143,38 @@ where
                         // Get all nodes between start and end and reconcile with new nodes.
                         let mut new = flattened.get_clone();
                         let mut old = utils::get_nodes_between(&start_node, &end_node);
-                        // We must include the end node in case `old` is empty (precondition for
-                        // reconcile_fragments).
+                        let Some(parent) = start_node.parent_node() else {
+                            return;
+                        };
+                        let Some(presence) = &presence else {
+                            // We must include the end node in case `old` is empty (precondition
+                            // for reconcile_fragments).
+                            new.push(end_node.clone());
+                            old.push(end_node.clone());
+                            reconcile_fragments(&parent, &mut old, &new);
+                            return;
+                        };
+
+                        let (mut new, removed, added) =
+                            keep_leaving_nodes(&old, new, &leaving.borrow());
                         new.push(end_node.clone());
                         old.push(end_node.clone());
+                        reconcile_fragments(&parent, &mut old, &new);
 
-                        if let Some(parent) = start_node.parent_node() {
-                            reconcile_fragments(&parent, &mut old, &new);
+                        for node in added {
+                            presence.enter(&node);
+                        }
+                        for node in removed {
+                            let id = HashableNode::new(&node).1;
+                            leaving.borrow_mut().insert(id);
+                            let leaving = leaving.clone();
+                            let leaving_node = node.clone();
+                            presence.leave(&leaving_node, move || {
+                                leaving.borrow_mut().remove(&id);
+                                if let Some(parent) = node.parent_node() {
+                                    parent.remove_child(&node).unwrap();
+                                }
+                            });
                         }
                     }) as Box<dyn FnMut()>,
                     (start, view, end).into(),
@@ -140,6 +184,55 @@ where
     }
 }
 
+/// Inserts the nodes in `old` that are not in `new` back into `new` so that they stay where they
+/// are while they are leaving. Each of them is kept after the node that was in front of it.
+///
+/// Returns the new nodes, the nodes that were removed and the nodes that were added.
+fn keep_leaving_nodes(
+    old: &[web_sys::Node],
+    new: Vec<web_sys::Node>,
+    leaving: &HashSet<usize>,
+) -> (Vec<web_sys::Node>, Vec<web_sys::Node>, Vec<web_sys::Node>) {
+    let id = |node: &web_sys::Node| HashableNode::new(node).1;
+    let in_new = new.iter().map(id).collect::<HashSet<_>>();
+    let in_old = old.iter().map(id).collect::<HashSet<_>>();
+
+    let mut removed = Vec::new();
+    // The nodes that stay in the DOM, grouped by the kept node in front of them.
+    let mut kept_before_first = Vec::new();
+    let mut kept_after = HashMap::<usize, Vec<web_sys::Node>>::new();
+    let mut prev = None;
+    for node in old {
+        let node_id = id(node);
+        if in_new.contains(&node_id) {
+            prev = Some(node_id);
+            continue;
+        }
+        if !leaving.contains(&node_id) {
+            removed.push(node.clone());
+        }
+        match prev {
+            Some(prev) => kept_after.entry(prev).or_default().push(node.clone()),
+            None => kept_before_first.push(node.clone()),
+        }
+    }
+
+    let added = new
+        .iter()
+        .filter(|node| !in_old.contains(&id(node)))
+        .cloned()
+        .collect();
+    let mut nodes = kept_before_first;
+    for node in new {
+        let kept = kept_after.remove(&id(&node));
+        nodes.push(node);
+        if let Some(kept) = kept {
+            nodes.extend(kept);
+        }
+    }
+    (nodes, removed, added)
+}
+
 /// Props for [`Keyed`].
 #[derive(Props)]
 pub struct IndexedProps<T, U, List, F>
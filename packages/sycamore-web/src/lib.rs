@@ -38,34 +38,55 @@ pub mod events;
 #[doc(hidden)]
 pub mod utils;
 
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+
+mod action;
 mod attributes;
 mod components;
+mod custom_elements;
 mod elements;
+mod form;
 mod iter;
+mod listeners;
 mod macros;
 mod node;
 mod noderef;
+mod observers;
 mod portal;
+mod presence;
 #[cfg(feature = "suspense")]
 mod resource;
+mod scoped_css;
+mod shadow_root;
 mod stable_counter;
 #[cfg(feature = "suspense")]
 mod suspense;
+mod template;
 
 pub(crate) mod view;
 
+pub use self::action::*;
 pub use self::attributes::*;
 pub use self::components::*;
+pub use self::custom_elements::*;
 pub use self::elements::*;
+pub use self::form::*;
 pub use self::iter::*;
+pub use self::listeners::*;
 pub use self::node::*;
 pub use self::noderef::*;
+pub use self::observers::*;
 pub use self::portal::*;
+pub use self::presence::*;
 #[cfg(feature = "suspense")]
 pub use self::resource::*;
+pub use self::scoped_css::*;
+pub use self::shadow_root::*;
 pub use self::stable_counter::*;
 #[cfg(feature = "suspense")]
 pub use self::suspense::*;
+pub use self::template::*;
 pub use self::view::*;
 
 /// We add this to make the macros from `sycamore-macro` work properly.
@@ -84,10 +105,17 @@ pub mod rt {
     pub use sycamore_reactive::*;
     #[allow(unused_imports)] // Needed for macro support.
     pub use web_sys;
+    #[allow(unused_imports)] // Needed for macro support.
+    pub use web_sys::Event;
 
     #[cfg(feature = "suspense")]
     pub use crate::WrapAsync;
-    pub use crate::{View, bind, custom_element, tags};
+    #[cfg(feature = "hot-reload")]
+    pub use crate::hot_reload;
+    pub use crate::{
+        Indexed, Keyed, NodeRefArg, ScopedCss, SelectElementRef, SelectNodeRef, Template, View,
+        bind, custom_element, events, tags,
+    };
 }
 
 /// Re-export of `js-sys` and `wasm-bindgen` for convenience.
@@ -219,6 +247,10 @@ pub type HtmlNode = HydrateNode;
 /// type.
 pub type Children = sycamore_core::Children<View>;
 
+/// A type alias for [`ChildrenFn`](sycamore_core::ChildrenFn) automatically selecting the correct
+/// node type.
+pub type ChildrenFn = sycamore_core::ChildrenFn<View>;
+
 /// Create a new effect, but only if we are not in SSR mode.
 pub fn create_client_effect(f: impl FnMut() + 'static) {
     if is_not_ssr!() {
@@ -0,0 +1,130 @@
+//! Event listeners on global objects and existing nodes that are removed when the current scope is
+//! disposed.
+//!
+//! Use these instead of adding a listener with `web_sys` directly, which requires either keeping
+//! the [`Closure`] alive or leaking it with [`Closure::forget`].
+
+use crate::events::{EventDescriptor, EventHandler};
+use crate::*;
+
+/// Adds an event listener to `target`. The listener is removed when the current scope is
+/// disposed.
+///
+/// This does nothing in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{events, use_event_listener};
+/// #[component]
+/// fn Component() -> View {
+///     let body = document().body().unwrap();
+///     use_event_listener(&body, events::click, |ev: events::MouseEvent| {
+///         console_log!("clicked at {}, {}", ev.client_x(), ev.client_y());
+///     });
+///     view! {}
+/// }
+/// ```
+pub fn use_event_listener<E: EventDescriptor, R>(
+    target: &web_sys::EventTarget,
+    _: E,
+    mut handler: impl EventHandler<E, R>,
+) {
+    if is_ssr!() {
+        return;
+    }
+    let scope = use_current_scope(); // Run handler inside the current scope.
+    let closure = Closure::<dyn FnMut(web_sys::Event)>::new(move |ev: web_sys::Event| {
+        scope.run_in(|| handler.call(ev.unchecked_into()));
+    });
+    target
+        .add_event_listener_with_callback(E::NAME, closure.as_ref().unchecked_ref())
+        .unwrap_throw();
+    let target = target.clone();
+    on_cleanup(move || {
+        target
+            .remove_event_listener_with_callback(E::NAME, closure.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+}
+
+/// Adds an event listener to the global [`web_sys::Window`] object. The listener is removed when
+/// the current scope is disposed.
+///
+/// This does nothing in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{events, use_window_event};
+/// #[component]
+/// fn WindowWidth() -> View {
+///     let width = create_signal(window().inner_width().unwrap().as_f64().unwrap());
+///     use_window_event(events::resize, move |_| {
+///         width.set(window().inner_width().unwrap().as_f64().unwrap());
+///     });
+///     view! { "Width: " (width.get()) }
+/// }
+/// ```
+pub fn use_window_event<E: EventDescriptor, R>(event: E, handler: impl EventHandler<E, R>) {
+    if is_not_ssr!() {
+        use_event_listener(&window(), event, handler);
+    }
+}
+
+/// Adds an event listener to the global [`web_sys::Document`] object. The listener is removed when
+/// the current scope is disposed.
+///
+/// This does nothing in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{events, use_document_event};
+/// #[component]
+/// fn Shortcuts() -> View {
+///     use_document_event(events::keydown, |ev: events::KeyboardEvent| {
+///         if ev.key() == "Escape" {
+///             console_log!("escape pressed");
+///         }
+///     });
+///     view! {}
+/// }
+/// ```
+pub fn use_document_event<E: EventDescriptor, R>(event: E, handler: impl EventHandler<E, R>) {
+    if is_not_ssr!() {
+        use_event_listener(&document(), event, handler);
+    }
+}
+
+/// Adds an event listener to the node of `node_ref` once the component is mounted. The listener
+/// is removed when the current scope is disposed.
+///
+/// This is useful for nodes that are not created with the `view!` macro, or for listening to
+/// events on a node from a parent component. Nothing happens if the node ref is not set when the
+/// component is mounted.
+///
+/// This does nothing in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{events, use_node_ref_event};
+/// #[component]
+/// fn Component() -> View {
+///     let div_ref = create_node_ref();
+///     use_node_ref_event(div_ref, events::scroll, |_| console_log!("scrolled"));
+///     view! { div(r#ref=div_ref) }
+/// }
+/// ```
+pub fn use_node_ref_event<T: JsCast + 'static, E: EventDescriptor + 'static, R>(
+    node_ref: NodeRef<T>,
+    event: E,
+    handler: impl EventHandler<E, R>,
+) {
+    on_mount(move || {
+        if let Some(node) = node_ref.try_get() {
+            use_event_listener(node.unchecked_ref(), event, handler);
+        }
+    });
+}
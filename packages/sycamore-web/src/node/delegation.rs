@@ -0,0 +1,169 @@
+//! Event delegation. Delegated events are handled by a single listener on a root node instead of
+//! a listener on every element. The handlers are stored on the elements themselves and are called
+//! by the listener on the root while walking up from the target of the event.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::{Array, Function, Object, Reflect};
+use wasm_bindgen::intern;
+
+use crate::*;
+
+thread_local! {
+    /// The root nodes that were passed to [`delegate_events`], with the names of their delegated
+    /// events. The listeners are only added once a view is rendered into the root.
+    static DELEGATION_ROOTS: RefCell<Vec<(web_sys::Node, Vec<&'static str>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// The property on an event which holds the position in the composed path up to which the event
+/// was already dispatched to delegated handlers. This prevents handlers from being called twice
+/// when delegation roots are nested.
+const DISPATCHED_KEY: &str = "$$sycamoreDispatched";
+
+/// Returns the property on a node which holds the delegated handlers for `event`.
+fn handlers_key(event: &str) -> JsValue {
+    intern(&format!("$$sycamore:{event}")).into()
+}
+
+pub(crate) fn delegate_events(root: &web_sys::Node, events: &[&'static str]) {
+    DELEGATION_ROOTS.with_borrow_mut(|roots| {
+        let delegated = match roots.iter_mut().find(|(node, _)| node == root) {
+            Some((_, delegated)) => delegated,
+            None => {
+                roots.push((root.clone(), Vec::new()));
+                &mut roots.last_mut().unwrap().1
+            }
+        };
+        for &event in events {
+            if !delegated.contains(&event) {
+                delegated.push(event);
+            }
+        }
+    });
+}
+
+/// The events that are delegated for the view that is being rendered. This is provided as a
+/// context by the render functions, so that only the views rendered into a delegation root use
+/// delegated handlers.
+#[derive(Clone, Default)]
+pub(crate) struct DelegatedEvents(Rc<[&'static str]>);
+
+/// Adds the listeners for the events that are delegated to `root` and calls `f` with the
+/// [`DelegatedEvents`] context. The listeners are removed when the current scope is disposed.
+pub(crate) fn with_delegated_events<T>(root: &web_sys::Node, f: impl FnOnce() -> T) -> T {
+    let events = DELEGATION_ROOTS.with_borrow(|roots| {
+        roots
+            .iter()
+            .find(|(node, _)| node == root)
+            .map(|(_, events)| events.clone())
+    });
+    let Some(events) = events else {
+        return f();
+    };
+
+    for &event in &events {
+        let key = handlers_key(event);
+        let listener = Closure::<dyn Fn(web_sys::Event)>::new({
+            let root = root.clone();
+            move |ev| dispatch(&root, &key, &ev)
+        });
+        root.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+            .unwrap();
+        let root = root.clone();
+        on_cleanup(move || {
+            root.remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                .unwrap();
+            drop(listener);
+        });
+    }
+    provide_context_in_new_scope(DelegatedEvents(events.into()), f)
+}
+
+/// Calls `f` without delegated events, e.g. for views which are mounted outside of the delegation
+/// root.
+pub(crate) fn without_delegated_events<T>(f: impl FnOnce() -> T) -> T {
+    provide_context_in_new_scope(DelegatedEvents::default(), f)
+}
+
+/// Returns whether `event` is delegated in the current scope.
+pub(crate) fn is_delegated(event: &str) -> bool {
+    try_use_context::<DelegatedEvents>().is_some_and(|delegated| delegated.0.contains(&event))
+}
+
+/// Adds a handler for a delegated event to `node`. The handler is removed when the current scope
+/// is disposed.
+pub(crate) fn add_delegated_handler(
+    node: &web_sys::Node,
+    event: &str,
+    handler: Closure<dyn FnMut(web_sys::Event)>,
+) {
+    let key = handlers_key(event);
+    let handlers = match Reflect::get(node, &key).unwrap().dyn_into::<Array>() {
+        Ok(handlers) => handlers,
+        Err(_) => {
+            let handlers = Array::new();
+            Reflect::set(node, &key, &handlers).unwrap();
+            handlers
+        }
+    };
+    handlers.push(handler.as_ref());
+
+    let node = node.clone();
+    on_cleanup(move || {
+        let handlers = Reflect::get(&node, &key).unwrap().unchecked_into::<Array>();
+        let f: &JsValue = handler.as_ref();
+        let handlers = handlers.filter(&mut |handler, _, _| handler != *f);
+        Reflect::set(&node, &key, &handlers).unwrap();
+        drop(handler);
+    });
+}
+
+/// Calls the delegated handlers of the nodes between the target of `ev` and `root`, stopping
+/// early if a handler stops the propagation of the event.
+fn dispatch(root: &web_sys::Node, key: &JsValue, ev: &web_sys::Event) {
+    let root: &JsValue = root.as_ref();
+    let path = ev.composed_path();
+    let dispatched_key = JsValue::from_str(DISPATCHED_KEY);
+    let mut index = Reflect::get(ev, &dispatched_key)
+        .unwrap()
+        .as_f64()
+        .unwrap_or_default() as u32;
+
+    while index < path.length() {
+        let node = path.get(index);
+        index += 1;
+
+        if let Ok(handlers) = Reflect::get(&node, key).unwrap().dyn_into::<Array>()
+            && handlers.length() > 0
+        {
+            // Make `currentTarget` return the node whose handlers are called instead of the root.
+            let descriptor = Object::new();
+            Reflect::set(&descriptor, &"configurable".into(), &JsValue::TRUE).unwrap();
+            Reflect::set(&descriptor, &"value".into(), &node).unwrap();
+            Reflect::define_property(
+                ev.unchecked_ref::<Object>(),
+                &"currentTarget".into(),
+                &descriptor,
+            )
+            .unwrap();
+            // Copy the handlers since they can be removed while they are called.
+            for handler in Array::from(&handlers) {
+                handler
+                    .unchecked_ref::<Function>()
+                    .call1(&node, ev)
+                    .unwrap();
+            }
+            if ev.cancel_bubble() {
+                break;
+            }
+        }
+        if node == *root {
+            break;
+        }
+    }
+
+    Reflect::set(ev, &dispatched_key, &index.into()).unwrap();
+    Reflect::delete_property(ev.unchecked_ref::<Object>(), &"currentTarget".into()).unwrap();
+}
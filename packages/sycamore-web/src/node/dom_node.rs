@@ -154,6 +154,47 @@ impl ViewHtmlNode for DomNode {
         }
     }
 
+    fn set_class(&mut self, name: Cow<'static, str>, value: MaybeDyn<bool>) {
+        let class_list = self.raw.unchecked_ref::<web_sys::Element>().class_list();
+        if let Some(value) = value.as_static() {
+            if *value {
+                class_list.add_1(&name).unwrap();
+            }
+        } else {
+            create_effect(move || {
+                class_list.toggle_with_force(&name, value.get()).unwrap();
+            });
+        }
+    }
+
+    fn add_classes(&mut self, classes: StringAttribute) {
+        let class_list = self.raw.unchecked_ref::<web_sys::Element>().class_list();
+        if let Some(classes) = classes.as_static() {
+            update_classes(&class_list, &[], classes.as_deref());
+        } else {
+            let mut prev = Vec::new();
+            create_effect(move || {
+                prev = update_classes(&class_list, &prev, classes.get_clone().as_deref());
+            });
+        }
+    }
+
+    fn set_style_property(&mut self, name: Cow<'static, str>, value: StringAttribute) {
+        let style = style_declaration(&self.raw);
+        if let Some(value) = value.as_static() {
+            if let Some(value) = value {
+                style.set_property(&name, value).unwrap();
+            }
+        } else {
+            create_effect(move || match value.get_clone() {
+                Some(value) => style.set_property(&name, &value).unwrap(),
+                None => {
+                    style.remove_property(&name).unwrap();
+                }
+            });
+        }
+    }
+
     fn set_property(&mut self, name: Cow<'static, str>, value: MaybeDyn<JsValue>) {
         if let Some(value) = value.as_static() {
             assert!(js_sys::Reflect::set(&self.raw, &name.as_ref().into(), value).unwrap_throw())
@@ -174,12 +215,41 @@ impl ViewHtmlNode for DomNode {
         handler: impl FnMut(web_sys::Event) + 'static,
     ) {
         let cb = Closure::wrap(Box::new(handler) as Box<dyn FnMut(_)>);
+        if super::delegation::is_delegated(&name) {
+            super::delegation::add_delegated_handler(&self.raw, &name, cb);
+            return;
+        }
         self.raw
             .add_event_listener_with_callback(&name, cb.as_ref().unchecked_ref())
             .unwrap();
         on_cleanup(|| drop(cb));
     }
 
+    fn set_event_handler_with_options(
+        &mut self,
+        name: Cow<'static, str>,
+        options: events::EventOptions,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        // Delegated handlers can not have listener options, so a native listener is used instead.
+        if !options.has_listener_options() {
+            return self.set_event_handler(name, handler);
+        }
+        let cb = Closure::wrap(Box::new(handler) as Box<dyn FnMut(_)>);
+        let listener_options = web_sys::AddEventListenerOptions::new();
+        listener_options.set_capture(options.capture);
+        listener_options.set_passive(options.passive);
+        listener_options.set_once(options.once);
+        self.raw
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                &name,
+                cb.as_ref().unchecked_ref(),
+                &listener_options,
+            )
+            .unwrap();
+        on_cleanup(|| drop(cb));
+    }
+
     fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
         self.raw
             .unchecked_ref::<web_sys::Element>()
@@ -194,3 +264,31 @@ impl ViewHtmlNode for DomNode {
         Self { raw: node }
     }
 }
+
+/// Returns the inline style of an element. This works for both HTML and SVG elements.
+pub(crate) fn style_declaration(node: &web_sys::Node) -> web_sys::CssStyleDeclaration {
+    js_sys::Reflect::get(node, &"style".into())
+        .unwrap()
+        .unchecked_into()
+}
+
+/// Replaces the classes in `prev` with the space separated classes in `classes`. Returns the new
+/// list of classes.
+pub(crate) fn update_classes(
+    class_list: &web_sys::DomTokenList,
+    prev: &[String],
+    classes: Option<&str>,
+) -> Vec<String> {
+    let classes = classes
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    for class in prev.iter().filter(|class| !classes.contains(class)) {
+        class_list.remove_1(class).unwrap();
+    }
+    for class in &classes {
+        class_list.add_1(class).unwrap();
+    }
+    classes
+}
@@ -26,17 +26,64 @@ pub fn render_to(view: impl FnOnce() -> View, parent: &web_sys::Node) {
 /// It is expected that this function will be called inside a reactive root, usually created using
 /// [`create_root`].
 pub fn render_in_scope(view: impl FnOnce() -> View, parent: &web_sys::Node) {
-    if is_ssr!() {
+    is_ssr! {
+        let _ = view;
+        let _ = parent;
         panic!("`render_in_scope` is not available in SSR mode");
-    } else {
+    }
+    is_not_ssr! {
         IS_HYDRATING.set(false);
-        let nodes = view().nodes;
+        let nodes = delegation::with_delegated_events(parent, view).nodes;
         for node in nodes {
             parent.append_child(node.as_web_sys()).unwrap();
         }
     }
 }
 
+/// Handle the given events with a single listener on `root` instead of a listener on every
+/// element.
+///
+/// This only applies to the views that are rendered into `root` afterwards, e.g. with
+/// [`render_to`] or `hydrate_to`. Event handlers for these events in those views, e.g. with
+/// `on:click`, are stored on the element and are called by the listener on `root` when the event
+/// bubbles up to it. The handlers are called from the target of the event upwards, and calling
+/// [`stop_propagation`](web_sys::Event::stop_propagation) in a handler prevents the handlers of
+/// the ancestors from being called. `current_target` returns the element of the handler. The
+/// listeners on `root` are removed when the reactive scope of the render call is disposed.
+///
+/// This should be called before rendering, usually with the same node that the view is rendered
+/// to. Keep in mind that:
+/// - Only events which bubble can be delegated.
+/// - Views rendered into other nodes, as well as the contents of a [`Portal2`](crate::Portal2),
+///   are not affected and always use a listener on every element.
+/// - Native listeners on the elements inside of `root` are called before any delegated handler,
+///   since the event only reaches the listener on `root` after it has bubbled up.
+///
+/// This does nothing in SSR mode.
+pub fn delegate_events(root: &web_sys::Node, events: &[&'static str]) {
+    is_ssr! {
+        let _ = root;
+        let _ = events;
+    }
+    is_not_ssr! {
+        delegation::delegate_events(root, events);
+    }
+}
+
+/// Calls `f` in a new scope in which no events are delegated. This is used for views that are
+/// mounted outside of the node that they are rendered into.
+#[cfg_not_ssr]
+pub(crate) fn without_delegated_events<T>(f: impl FnOnce() -> T) -> T {
+    delegation::without_delegated_events(f)
+}
+
+/// Calls `f` in a new scope in which no events are delegated. This is used for views that are
+/// mounted outside of the node that they are rendered into.
+#[cfg_ssr]
+pub(crate) fn without_delegated_events<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
 /// Render a [`View`] under a `parent` node by reusing existing nodes (client side
 /// hydration).
 ///
@@ -91,23 +138,12 @@ pub fn hydrate_in_scope(view: impl FnOnce() -> View, parent: &web_sys::Node) {
             .unchecked_ref::<web_sys::Element>()
             .query_selector_all("[data-hk]")
             .unwrap();
-
-        HYDRATE_NODES.with(|nodes| {
-            let mut nodes = nodes.borrow_mut();
-            let len = existing_nodes.length();
-            for i in 0..len {
-                let node = existing_nodes.get(i).unwrap();
-                let hk = node.unchecked_ref::<web_sys::Element>().get_attribute("data-hk").unwrap();
-                let key = HydrationKey::parse(&hk).expect("could not parse hydration key");
-                let node = HydrateNode::from_web_sys(node);
-                nodes.insert(key, node);
-            }
-        });
+        collect_hydrate_nodes(existing_nodes);
 
         IS_HYDRATING.set(true);
         provide_context(mode);
         provide_context(HydrationRegistry::new());
-        let nodes = view().nodes;
+        let nodes = delegation::with_delegated_events(parent, view).nodes;
         // We need to append `nodes` to the `parent` so that the top level nodes also get properly
         // hydrated.
         let mut parent = HydrateNode::from_web_sys(parent.clone());
@@ -126,3 +162,22 @@ pub fn hydrate_in_scope(view: impl FnOnce() -> View, parent: &web_sys::Node) {
         });
     }
 }
+
+/// Adds the nodes with a `data-hk` attribute to `HYDRATE_NODES`. The nodes inside shadow roots
+/// that are attached to these nodes, e.g. by [`ShadowRoot`](crate::ShadowRoot), are added as well
+/// since they are not found by `querySelectorAll`.
+#[cfg(feature = "hydrate")]
+#[cfg_not_ssr]
+fn collect_hydrate_nodes(existing_nodes: web_sys::NodeList) {
+    for i in 0..existing_nodes.length() {
+        let node = existing_nodes.get(i).unwrap();
+        let el = node.unchecked_ref::<web_sys::Element>();
+        let hk = el.get_attribute("data-hk").unwrap();
+        let key = HydrationKey::parse(&hk).expect("could not parse hydration key");
+        if let Some(shadow_root) = el.shadow_root() {
+            collect_hydrate_nodes(shadow_root.query_selector_all("[data-hk]").unwrap());
+        }
+        let node = HydrateNode::from_web_sys(node);
+        HYDRATE_NODES.with(|nodes| nodes.borrow_mut().insert(key, node));
+    }
+}
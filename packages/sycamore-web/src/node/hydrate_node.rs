@@ -245,6 +245,82 @@ impl ViewHtmlNode for HydrateNode {
         }
     }
 
+    fn set_class(&mut self, name: Cow<'static, str>, value: MaybeDyn<bool>) {
+        if IS_HYDRATING.get() {
+            // Noop if value is static since the class is already set.
+            if value.as_static().is_none() {
+                let class_list = self
+                    .as_web_sys()
+                    .unchecked_ref::<web_sys::Element>()
+                    .class_list();
+                create_effect_initial(move || {
+                    let _ = value.track(); // Track dependencies of value.
+                    (
+                        Box::new(move || {
+                            class_list.toggle_with_force(&name, value.get()).unwrap();
+                        }),
+                        (),
+                    )
+                });
+            }
+        } else {
+            self.0.unwrap_mut().set_class(name, value);
+        }
+    }
+
+    fn add_classes(&mut self, classes: StringAttribute) {
+        if IS_HYDRATING.get() {
+            // Noop if value is static since the classes are already set.
+            if classes.as_static().is_none() {
+                let class_list = self
+                    .as_web_sys()
+                    .unchecked_ref::<web_sys::Element>()
+                    .class_list();
+                create_effect_initial(move || {
+                    // The classes that were rendered on the server.
+                    let mut prev = classes
+                        .get_clone()
+                        .unwrap_or_default()
+                        .split_whitespace()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>();
+                    (
+                        Box::new(move || {
+                            prev =
+                                update_classes(&class_list, &prev, classes.get_clone().as_deref());
+                        }),
+                        (),
+                    )
+                });
+            }
+        } else {
+            self.0.unwrap_mut().add_classes(classes);
+        }
+    }
+
+    fn set_style_property(&mut self, name: Cow<'static, str>, value: StringAttribute) {
+        if IS_HYDRATING.get() {
+            // Noop if value is static since the style is already set.
+            if value.as_static().is_none() {
+                let style = style_declaration(self.as_web_sys());
+                create_effect_initial(move || {
+                    let _ = value.track(); // Track dependencies of value.
+                    (
+                        Box::new(move || match value.get_clone() {
+                            Some(value) => style.set_property(&name, &value).unwrap(),
+                            None => {
+                                style.remove_property(&name).unwrap();
+                            }
+                        }),
+                        (),
+                    )
+                });
+            }
+        } else {
+            self.0.unwrap_mut().set_style_property(name, value);
+        }
+    }
+
     fn set_property(&mut self, name: Cow<'static, str>, value: MaybeDyn<JsValue>) {
         self.0.unwrap_mut().set_property(name, value);
     }
@@ -257,6 +333,17 @@ impl ViewHtmlNode for HydrateNode {
         self.0.unwrap_mut().set_event_handler(name, handler);
     }
 
+    fn set_event_handler_with_options(
+        &mut self,
+        name: Cow<'static, str>,
+        options: events::EventOptions,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.0
+            .unwrap_mut()
+            .set_event_handler_with_options(name, options, handler);
+    }
+
     fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
         // If we are hydrating, inner HTML should already be set.
         if !IS_HYDRATING.get() {
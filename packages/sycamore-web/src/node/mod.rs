@@ -6,6 +6,7 @@ use std::num::NonZeroU32;
 use crate::*;
 
 is_not_ssr!(
+    mod delegation;
     mod dom_node;
     #[cfg(feature = "hydrate")]
     mod hydrate_node;
@@ -18,6 +19,8 @@ mod ssr_render;
 
 // We add this so that we get IDE support in Rust Analyzer.
 #[cfg(rust_analyzer)]
+mod delegation;
+#[cfg(rust_analyzer)]
 mod dom_node;
 #[cfg(rust_analyzer)]
 mod hydrate_node;
@@ -51,6 +54,15 @@ pub trait ViewHtmlNode: ViewNode {
     fn set_attribute(&mut self, name: Cow<'static, str>, value: StringAttribute);
     /// Set a boolean HTML attribute.
     fn set_bool_attribute(&mut self, name: Cow<'static, str>, value: BoolAttribute);
+    /// Add or remove a single class of an element.
+    fn set_class(&mut self, name: Cow<'static, str>, value: MaybeDyn<bool>);
+    /// Add the space separated classes in `classes` to the classes of an element. Unlike setting
+    /// the `class` attribute, this does not remove the classes that are already set. If `classes`
+    /// is dynamic, the classes that were added previously are removed when it changes.
+    fn add_classes(&mut self, classes: StringAttribute);
+    /// Set a single CSS property in the inline style of an element. The property is removed if
+    /// `value` is `None`.
+    fn set_style_property(&mut self, name: Cow<'static, str>, value: StringAttribute);
     /// Set a JS property on an element.
     fn set_property(&mut self, name: Cow<'static, str>, value: MaybeDyn<JsValue>);
     /// Set an event handler on an element.
@@ -59,6 +71,15 @@ pub trait ViewHtmlNode: ViewNode {
         name: Cow<'static, str>,
         handler: impl FnMut(web_sys::Event) + 'static,
     );
+    /// Set an event handler on an element with the listener options `capture`, `passive` and
+    /// `once` of `options`. The other options, such as `prevent_default` and `key`, are applied by
+    /// the caller in the handler.
+    fn set_event_handler_with_options(
+        &mut self,
+        name: Cow<'static, str>,
+        options: events::EventOptions,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    );
     /// Set the inner HTML value of an element.
     fn set_inner_html(&mut self, inner_html: Cow<'static, str>);
 
@@ -111,6 +132,17 @@ impl HydrationRegistry {
         key
     }
 
+    /// Get `n` consecutive hydration keys and increment the internal state. Returns the first
+    /// key.
+    pub fn next_keys(self, n: u32) -> HydrationKey {
+        let key = self.next_key.get_untracked();
+        self.next_key.set_silent(HydrationKey {
+            suspense: key.suspense,
+            element: key.element + n,
+        });
+        key
+    }
+
     /// Run the given function within a suspense scope.
     ///
     /// This sets the suspense key to the passed value and resets the element key to 0.
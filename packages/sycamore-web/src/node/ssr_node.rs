@@ -6,6 +6,7 @@ use once_cell::sync::Lazy;
 
 use crate::*;
 
+#[non_exhaustive]
 pub enum SsrNode {
     Element {
         tag: Cow<'static, str>,
@@ -23,6 +24,17 @@ pub enum SsrNode {
         text: Cow<'static, str>,
     },
     Marker,
+    /// Pre-rendered HTML of the static part of an element tree. This is created by the `view!`
+    /// macro. The HTML can open elements that are closed by a later `Static` node, with the dynamic
+    /// children of the elements in between.
+    ///
+    /// The HTML is split into chunks at the places where the hydration keys of the elements are
+    /// inserted. `key` is the hydration key of the first element and the other elements use the
+    /// keys that directly follow it.
+    Static {
+        chunks: &'static [&'static str],
+        key: Option<HydrationKey>,
+    },
     /// SSR by default does not update to any dynamic changes in the view. This special node allows
     /// dynamically changing the view tree before it is rendered.
     ///
@@ -125,7 +137,11 @@ impl ViewHtmlNode for SsrNode {
         match self {
             Self::Element { attributes, .. } => {
                 if let Some(value) = value.evaluate() {
-                    attributes.push((name, value))
+                    // Setting an attribute again replaces the old value, like in the DOM.
+                    match attributes.iter_mut().find(|(attr, _)| *attr == name) {
+                        Some((_, old)) => *old = value,
+                        None => attributes.push((name, value)),
+                    }
                 }
             }
             _ => panic!("can only set attribute on an element"),
@@ -141,6 +157,83 @@ impl ViewHtmlNode for SsrNode {
         }
     }
 
+    fn set_class(&mut self, name: Cow<'static, str>, value: MaybeDyn<bool>) {
+        match self {
+            Self::Element { attributes, .. } => {
+                if value.evaluate() {
+                    // Merge with the `class` attribute if it is already set.
+                    match attributes.iter_mut().find(|(attr, _)| attr == "class") {
+                        Some((_, class)) if !class.is_empty() => {
+                            let class = class.to_mut();
+                            class.push(' ');
+                            class.push_str(&name);
+                        }
+                        Some((_, class)) => *class = name,
+                        None => attributes.push(("class".into(), name)),
+                    }
+                }
+            }
+            _ => panic!("can only set class on an element"),
+        }
+    }
+
+    fn add_classes(&mut self, classes: StringAttribute) {
+        match self {
+            Self::Element { attributes, .. } => {
+                let Some(classes) = classes.evaluate() else {
+                    return;
+                };
+                // Merge with the `class` attribute if it is already set. Classes that are already
+                // set are not added again, like in the DOM.
+                match attributes.iter_mut().find(|(attr, _)| attr == "class") {
+                    Some((_, class)) => {
+                        for new in classes.split_whitespace() {
+                            if !class.split_whitespace().any(|class| class == new) {
+                                let class = class.to_mut();
+                                if !class.is_empty() {
+                                    class.push(' ');
+                                }
+                                class.push_str(new);
+                            }
+                        }
+                    }
+                    None => attributes.push((
+                        "class".into(),
+                        classes.split_whitespace().collect::<Vec<_>>().join(" ").into(),
+                    )),
+                }
+            }
+            _ => panic!("can only set class on an element"),
+        }
+    }
+
+    fn set_style_property(&mut self, name: Cow<'static, str>, value: StringAttribute) {
+        match self {
+            Self::Element { attributes, .. } => {
+                if let Some(value) = value.evaluate() {
+                    let declaration = format!("{name}: {value};");
+                    // Merge with the `style` attribute if it is already set.
+                    match attributes.iter_mut().find(|(attr, _)| attr == "style") {
+                        Some((_, style)) => {
+                            let style = style.to_mut();
+                            let trimmed = style.trim_end().len();
+                            style.truncate(trimmed);
+                            if !style.is_empty() {
+                                if !style.ends_with(';') {
+                                    style.push(';');
+                                }
+                                style.push(' ');
+                            }
+                            style.push_str(&declaration);
+                        }
+                        None => attributes.push(("style".into(), declaration.into())),
+                    }
+                }
+            }
+            _ => panic!("can only set style on an element"),
+        }
+    }
+
     fn set_property(&mut self, _name: Cow<'static, str>, _value: MaybeDyn<JsValue>) {
         // Noop in SSR mode.
     }
@@ -153,6 +246,15 @@ impl ViewHtmlNode for SsrNode {
         // Noop in SSR mode.
     }
 
+    fn set_event_handler_with_options(
+        &mut self,
+        _name: Cow<'static, str>,
+        _options: events::EventOptions,
+        _handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        // Noop in SSR mode.
+    }
+
     fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
         match self {
             Self::Element {
@@ -253,6 +355,22 @@ pub(crate) fn render_recursive(node: &SsrNode, buf: &mut String) {
         SsrNode::Marker => {
             buf.push_str("<!--/-->");
         }
+        SsrNode::Static { chunks, key } => {
+            for (i, chunk) in chunks.iter().enumerate() {
+                if i > 0
+                    && let Some(key) = key
+                {
+                    let key = HydrationKey {
+                        suspense: key.suspense,
+                        element: key.element + i as u32 - 1,
+                    };
+                    buf.push_str(" data-hk=\"");
+                    buf.push_str(&key.to_string());
+                    buf.push('"');
+                }
+                buf.push_str(chunk);
+            }
+        }
         SsrNode::Dynamic { view } => {
             render_recursive_view(&view.lock().unwrap(), buf);
         }
@@ -373,6 +491,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prerendered_static_view() {
+        check(
+            move || {
+                sycamore_macro::view! {
+                    div(class="container") {
+                        p { "Hello " "<World>" }
+                        br {}
+                    }
+                }
+            },
+            expect![[
+                r#"<div class="container" data-hk="0.0"><p data-hk="0.1">Hello &lt;World&gt;</p><br data-hk="0.2"></div>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn prerendered_static_subtrees() {
+        check(
+            move || {
+                let value = create_signal(0);
+                sycamore_macro::view! {
+                    div {
+                        p { "Before" }
+                        (value)
+                        span(class="after") { i { "After" } }
+                    }
+                }
+            },
+            expect![[
+                r#"<div data-hk="0.0"><p data-hk="0.1">Before</p><!--/-->0<!--/--><span class="after" data-hk="0.2"><i data-hk="0.3">After</i></span></div>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn prerendered_with_dynamic_elements() {
+        check(
+            move || {
+                let class = create_signal("dynamic");
+                sycamore_macro::view! {
+                    div {
+                        p { "Before" }
+                        span(class=class) { i { "Dynamic" } }
+                        b { "After" }
+                    }
+                }
+            },
+            expect![[
+                r#"<div data-hk="0.0"><p data-hk="0.1">Before</p><span class="dynamic" data-hk="0.2"><i data-hk="0.3">Dynamic</i></span><b data-hk="0.4">After</b></div>"#
+            ]],
+        );
+    }
+
     #[test]
     fn dynamic_text() {
         check(
@@ -387,4 +560,132 @@ mod tests {
             expect![[r#"<p data-hk="0.0"><!--/-->1<!--/--></p>"#]],
         );
     }
+
+    #[test]
+    fn class_and_style_directives() {
+        check(
+            move || {
+                let active = create_signal(true);
+                sycamore_macro::view! {
+                    div(class:active=active.get(), class="a b", class:hidden=false) {
+                        p(style:color="red", style="display: block", style:margin=None::<&str>)
+                        span(class:only=true, style:font-weight="bold")
+                    }
+                }
+            },
+            expect![[
+                r#"<div class="a b active" data-hk="0.0"><p style="display: block; color: red;" data-hk="0.1"></p><span class="only" style="font-weight: bold;" data-hk="0.2"></span></div>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn html_syntax() {
+        let html = render_to_string(|| {
+            let count = create_signal(1);
+            sycamore_macro::html! {
+                <div class="container" data-count={count.get().to_string()}>
+                    <p>"Count: " {count}</p>
+                    <input r#type="checkbox" checked>
+                    if count.get() > 0 { <span>"Positive"</span> }
+                </div>
+            }
+        });
+        let view = render_to_string(|| {
+            let count = create_signal(1);
+            sycamore_macro::view! {
+                div(class="container", data-count=count.get().to_string()) {
+                    p { "Count: " (count) }
+                    input(r#type="checkbox", checked=true)
+                    if count.get() > 0 { span { "Positive" } }
+                }
+            }
+        });
+        assert_eq!(html, view);
+    }
+
+    #[test]
+    fn html_syntax_unquoted_text() {
+        let html = render_to_string(|| {
+            let name = create_signal("World");
+            sycamore_macro::html! {
+                <p>
+                    Hello, <b>{name}</b> and the
+                    well-known (and [bracketed]) world!
+                </p>
+                <p>Count: "1"</p>
+            }
+        });
+        let view = render_to_string(|| {
+            let name = create_signal("World");
+            sycamore_macro::view! {
+                p { "Hello, " b { (name) } " and the well-known (and [bracketed]) world!" }
+                p { "Count: " "1" }
+            }
+        });
+        assert_eq!(html, view);
+    }
+
+    #[test]
+    fn control_flow() {
+        check(
+            move || {
+                let logged_in = create_signal(false);
+                let items = create_signal(vec![1, 2]);
+                let view = sycamore_macro::view! {
+                    if logged_in.get() {
+                        p { "Welcome" }
+                    } else {
+                        p { "Log in" }
+                    }
+                    match items.with(Vec::len) {
+                        0 => { "Empty" }
+                        n if n > 2 => { "Many" }
+                        _ => { "Few" }
+                    }
+                    ul {
+                        for item in items.get_clone() key(*item) {
+                            li { (item) }
+                        }
+                    }
+                };
+                logged_in.set(true);
+                view
+            },
+            expect![[r#"<!--/--><p data-hk="0.4">Welcome</p><!--/--><!--/-->Few<!--/--><ul data-hk="0.1"><!--/--><li data-hk="0.2">1</li><li data-hk="0.3">2</li><!--/--></ul>"#]],
+        );
+    }
+
+    #[test]
+    fn children_fn_and_slots() {
+        #[sycamore_macro::component(inline_props)]
+        fn Repeat(count: usize, children: ChildrenFn) -> View {
+            (0..count)
+                .map(|_| children.call())
+                .collect::<Vec<_>>()
+                .into()
+        }
+
+        #[sycamore_macro::component(inline_props)]
+        fn Layout(header: Children, children: Children) -> View {
+            sycamore_macro::view! {
+                header { (header) }
+                main { (children) }
+            }
+        }
+
+        check(
+            move || {
+                sycamore_macro::view! {
+                    Layout {
+                        slot:header { "Title" }
+                        Repeat(count=2) {
+                            p { "Item" }
+                        }
+                    }
+                }
+            },
+            expect![[r#"<header data-hk="0.0">Title</header><main data-hk="0.1"><p data-hk="0.2">Item</p><p data-hk="0.3">Item</p></main>"#]],
+        );
+    }
 }
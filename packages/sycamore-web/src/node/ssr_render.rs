@@ -56,6 +56,7 @@ pub fn render_to_string_in_scope(view: impl FnOnce() -> View) -> String {
 
         let handle = create_child_scope(|| {
             provide_context(HydrationRegistry::new());
+            provide_context(CssRegistry::new());
             provide_context(SsrMode::Sync);
 
             let prev = IS_HYDRATING.replace(true);
@@ -118,6 +119,7 @@ pub async fn render_to_string_await_suspense(f: impl FnOnce() -> View) -> String
                 root.run_in(|| {
                     handle = Some(create_child_scope(|| {
                         provide_context(HydrationRegistry::new());
+                        provide_context(CssRegistry::new());
                         provide_context(SsrMode::Blocking);
 
                         view = f();
@@ -230,6 +232,7 @@ pub fn render_to_string_stream(
             root.run_in(|| {
                 // We run this in a new scope so that we can dispose everything after we render it.
                 provide_context(HydrationRegistry::new());
+                provide_context(CssRegistry::new());
                 provide_context(SsrMode::Streaming);
                 let suspense_state = SuspenseStream { futures: futures.clone() };
 
@@ -5,25 +5,35 @@
 //! You can create a [`NodeRef`] by using [`create_node_ref`].
 
 use std::fmt;
+use std::marker::PhantomData;
 
 use crate::*;
 
-/// A reference to a [`web_sys::Node`].
+/// A reference to a node of type `T`, e.g. [`web_sys::HtmlInputElement`].
 /// This allows imperative access to the node.
 ///
+/// When a node ref created with [`create_node_ref`] is attached to an element with `r#ref` in the
+/// `view!` macro, its type is inferred from the element. A node ref with an explicit type, e.g. an
+/// untyped [`NodeRef`] which refers to a [`web_sys::Node`], can be attached to any element that is
+/// a `T`. This is checked at compile time.
+///
 /// # Example
 /// ```
 /// # use sycamore::prelude::*;
 /// #[component]
 /// fn Component() -> View {
-///     let div_ref = create_node_ref();
-///     view! {
-///         div(r#ref=div_ref)
-///     }
+///     // This is a `NodeRef<web_sys::HtmlInputElement>`.
+///     let input_ref = create_node_ref();
+///     let view = view! {
+///         input(r#ref=input_ref)
+///     };
+///     on_mount(move || {
+///         input_ref.get().focus().unwrap();
+///     });
+///     view
 /// }
 /// ```
-#[derive(PartialEq, Eq, Clone, Copy)]
-pub struct NodeRef(Signal<Option<web_sys::Node>>);
+pub struct NodeRef<T = web_sys::Node>(Signal<Option<web_sys::Node>>, PhantomData<T>);
 
 impl NodeRef {
     /// Alias to [`create_node_ref`].
@@ -31,9 +41,30 @@ impl NodeRef {
         create_node_ref()
     }
 
-    /// Gets the raw node stored inside the node ref.
+    /// Returns a node ref to the same node with a more specific type. The type is checked when the
+    /// node is accessed.
     ///
-    /// This attempts to cast the node to the specified type.
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use web_sys::HtmlInputElement;
+    /// # fn Component(node_ref: NodeRef) -> View {
+    /// let input_ref = node_ref.cast::<HtmlInputElement>();
+    /// on_mount(move || {
+    ///     let value = input_ref.get().value();
+    /// });
+    /// view! {
+    ///     input(r#ref=node_ref)
+    /// }
+    /// # }
+    /// ```
+    pub fn cast<U: JsCast>(self) -> NodeRef<U> {
+        NodeRef(self.0, PhantomData)
+    }
+}
+
+impl<T: JsCast> NodeRef<T> {
+    /// Gets the node stored inside the node ref.
     ///
     /// # Example
     /// Node refs are generally meant to be accessed in callbacks or in `on_mount`. Accessing the
@@ -54,18 +85,22 @@ impl NodeRef {
     /// ```
     ///
     /// # Panics
-    /// Panics if the node ref is not set yet or is the wrong type.
+    /// Panics if the node ref is not set yet or if the node is not a `T`.
     ///
     /// For a non panicking version, see [`NodeRef::try_get`].
     #[track_caller]
-    pub fn get(&self) -> web_sys::Node {
-        self.try_get().expect("NodeRef is not set")
+    pub fn get(&self) -> T {
+        let node = self.0.get_clone().expect("NodeRef is not set");
+        node.dyn_into()
+            .unwrap_or_else(|_| panic!("NodeRef is not a `{}`", std::any::type_name::<T>()))
     }
 
-    /// Tries to get the raw web_sys node stored inside the node ref. Returns `None` if the node
-    /// ref has not yet been set (i.e. the node has not yet been rendered into the DOM).
-    pub fn try_get(&self) -> Option<web_sys::Node> {
-        self.0.get_clone()
+    /// Tries to get the node stored inside the node ref. Returns `None` if the node ref has not
+    /// yet been set (i.e. the node has not yet been rendered into the DOM) or if the node is not a
+    /// `T`. The latter can only happen if the node was set with [`NodeRef::set`] or through a node
+    /// ref returned by [`NodeRef::cast`].
+    pub fn try_get(&self) -> Option<T> {
+        self.0.get_clone().and_then(|node| node.dyn_into().ok())
     }
 
     /// Sets the node ref with the specified node.
@@ -90,13 +125,29 @@ impl NodeRef {
     }
 }
 
-impl Default for NodeRef {
+impl<T: JsCast> Default for NodeRef<T> {
     fn default() -> Self {
-        Self::new()
+        NodeRef(create_signal(None), PhantomData)
+    }
+}
+
+impl<T> Clone for NodeRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeRef<T> {}
+
+impl<T> PartialEq for NodeRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
     }
 }
 
-impl fmt::Debug for NodeRef {
+impl<T> Eq for NodeRef<T> {}
+
+impl<T> fmt::Debug for NodeRef<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("NodeRef").field(&self.0.get_clone()).finish()
     }
@@ -107,16 +158,62 @@ impl fmt::Debug for NodeRef {
 /// Create a new [`NodeRef`].
 ///
 /// The node ref does not point to anything until it is set, either by assigning it to a node in the
-/// view or by explicitly calling [`NodeRef::set`].
+/// view or by explicitly calling [`NodeRef::set`]. The type of the node is inferred from the
+/// element the node ref is assigned to with `r#ref`, or it can be specified explicitly.
 ///
 /// # Example
 /// ```
 /// # use sycamore::prelude::*;
 /// # fn Component() -> View {
 /// let node_ref: NodeRef = create_node_ref();
+/// let input_ref = create_node_ref::<web_sys::HtmlInputElement>();
 /// # view! {}
 /// # }
 /// ```
-pub fn create_node_ref() -> NodeRef {
-    NodeRef(create_signal(None))
+pub fn create_node_ref<T: JsCast>() -> NodeRef<T> {
+    NodeRef::default()
+}
+
+/// A node ref that is assigned to an element with `r#ref` in the `view!` macro. The type of the
+/// node ref is selected with [`SelectElementRef`] and [`SelectNodeRef`]:
+///
+/// ```ignore
+/// (&NodeRefArg::new(node_ref, tags::input)).select()
+/// ```
+///
+/// If the type of the node ref is not known yet, [`SelectElementRef`] applies and gives the node
+/// ref the type of the element. Otherwise, the node ref is passed on as is with [`SelectNodeRef`].
+#[doc(hidden)]
+pub struct NodeRefArg<T, E>(NodeRef<T>, PhantomData<E>);
+
+impl<T, E> NodeRefArg<T, E> {
+    /// `tag` is the function which creates the element. It is only used for its type.
+    pub fn new<B: GlobalProps<Element = E>>(
+        node_ref: NodeRef<T>,
+        _tag: impl FnOnce() -> B,
+    ) -> Self {
+        Self(node_ref, PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait SelectElementRef<E> {
+    fn select(&self) -> NodeRef<E>;
+}
+
+impl<E> SelectElementRef<E> for NodeRefArg<E, E> {
+    fn select(&self) -> NodeRef<E> {
+        self.0
+    }
+}
+
+#[doc(hidden)]
+pub trait SelectNodeRef<T> {
+    fn select(&self) -> NodeRef<T>;
+}
+
+impl<T, E> SelectNodeRef<T> for &NodeRefArg<T, E> {
+    fn select(&self) -> NodeRef<T> {
+        self.0
+    }
 }
@@ -0,0 +1,116 @@
+//! Reactive observers for the size and the visibility of elements.
+
+use js_sys::Array;
+
+use crate::*;
+
+/// The size of the content box of an element, in CSS pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ElementSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Returns a signal with the size of the element of `node_ref` that is updated whenever the
+/// element is resized.
+///
+/// The element is observed with a `ResizeObserver` once the component is mounted, and the observer
+/// is disconnected when the current scope is disposed. The size is zero until the element is
+/// observed for the first time, and always in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_element_size;
+/// #[component]
+/// fn Component() -> View {
+///     let div_ref = create_node_ref();
+///     let size = use_element_size(div_ref);
+///     view! {
+///         div(r#ref=div_ref) {
+///             "Width: " (size.get().width)
+///         }
+///     }
+/// }
+/// ```
+pub fn use_element_size<T>(node_ref: NodeRef<T>) -> ReadSignal<ElementSize>
+where
+    T: JsCast + 'static,
+{
+    let size = create_signal(ElementSize::default());
+    on_mount(move || {
+        let Some(el) = node_ref.try_get() else {
+            return;
+        };
+        observe_resize(el.unchecked_ref(), move |entries| {
+            if let Some(entry) = entries.iter().last() {
+                let rect = entry
+                    .unchecked_into::<web_sys::ResizeObserverEntry>()
+                    .content_rect();
+                size.set(ElementSize {
+                    width: rect.width(),
+                    height: rect.height(),
+                });
+            }
+        });
+    });
+    *size
+}
+
+/// Calls `f` with the entries of a `ResizeObserver` whenever `el` is resized. The observer is
+/// disconnected when the current scope is disposed.
+pub(crate) fn observe_resize(el: &web_sys::Element, f: impl Fn(Array) + 'static) {
+    let callback = Closure::<dyn Fn(Array)>::new(f);
+    let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()).unwrap_throw();
+    observer.observe(el);
+    on_cleanup(move || {
+        observer.disconnect();
+        drop(callback);
+    });
+}
+
+/// Returns a signal that is `true` while the element of `node_ref` is visible in the viewport.
+///
+/// The element is observed with an `IntersectionObserver` once the component is mounted, and the
+/// observer is disconnected when the current scope is disposed. The signal is `false` until the
+/// element is observed for the first time, and always in SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_intersection;
+/// #[component]
+/// fn LazyImage() -> View {
+///     let img_ref = create_node_ref();
+///     let visible = use_intersection(img_ref);
+///     // Only start loading the image once it is scrolled into view.
+///     view! {
+///         img(r#ref=img_ref, src=visible.get().then_some("/image.png"))
+///     }
+/// }
+/// ```
+pub fn use_intersection<T>(node_ref: NodeRef<T>) -> ReadSignal<bool>
+where
+    T: JsCast + 'static,
+{
+    let is_intersecting = create_signal(false);
+    on_mount(move || {
+        let Some(el) = node_ref.try_get() else {
+            return;
+        };
+        let callback = Closure::<dyn Fn(Array)>::new(move |entries: Array| {
+            if let Some(entry) = entries.iter().last() {
+                let entry = entry.unchecked_into::<web_sys::IntersectionObserverEntry>();
+                is_intersecting.set(entry.is_intersecting());
+            }
+        });
+        let observer =
+            web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref()).unwrap_throw();
+        observer.observe(el.unchecked_ref());
+        on_cleanup(move || {
+            observer.disconnect();
+            drop(callback);
+        });
+    });
+    *is_intersecting
+}
@@ -18,7 +18,8 @@ pub fn Portal<'a, T: Into<View> + Default>(selector: &'a str, children: T) -> Vi
         let start_node = start.as_web_sys().clone();
         let end = HtmlNode::create_marker_node();
         let end_node = end.as_web_sys().clone();
-        let children: View = (start, children.into(), end).into();
+        // The portal is not inside of the delegation root of its parent view.
+        let children: View = (start, without_delegated_events(|| children.into()), end).into();
 
         let nodes = children.as_web_sys();
         for node in &nodes {
@@ -60,6 +61,8 @@ pub fn Portal2(selector: impl AsRef<str>, children: Children) -> View {
             let start_node = start.as_web_sys().clone();
             let end = HtmlNode::create_marker_node();
             let end_node = end.as_web_sys().clone();
+            // The portal is not inside of the delegation root of its parent view.
+            let children = without_delegated_events(|| children.call());
             let children: View = (start, children, end).into();
 
             let nodes = children.as_web_sys();
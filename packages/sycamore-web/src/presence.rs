@@ -0,0 +1,88 @@
+//! Hooks for animating the nodes that are added and removed by [`Show`] and [`Keyed`].
+
+use crate::*;
+
+/// Lets a component such as `sycamore::motion::Presence` animate the nodes that [`Show`] and
+/// [`Keyed`] add and remove.
+///
+/// The handle is provided as a context. [`Show`] and [`Keyed`] use the handle of the closest
+/// component that provides one. Instead of removing their nodes right away, they call
+/// [`leave`](Self::leave) for every node and only remove it once the hook is done with it. The
+/// handle is not passed on to the children of [`Show`] and the items of [`Keyed`], so nested
+/// components remove their nodes right away.
+#[derive(Clone, Default)]
+pub struct PresenceHandle {
+    hooks: Option<Rc<PresenceHooks>>,
+}
+
+/// A hook that is called with a node and a function that removes it.
+type LeaveHook = Box<dyn Fn(&web_sys::Node, Box<dyn FnOnce()>)>;
+
+struct PresenceHooks {
+    appear: bool,
+    enter: Box<dyn Fn(&web_sys::Node)>,
+    leave: LeaveHook,
+}
+
+impl PresenceHandle {
+    /// Creates a new handle.
+    ///
+    /// `enter` is called with every node that is added. `leave` is called with every node that is
+    /// removed, along with a function that removes it. If `appear` is `true`, `enter` is also
+    /// called with the nodes that are there when the component is mounted.
+    pub fn new(
+        appear: bool,
+        enter: impl Fn(&web_sys::Node) + 'static,
+        leave: impl Fn(&web_sys::Node, Box<dyn FnOnce()>) + 'static,
+    ) -> Self {
+        Self {
+            hooks: Some(Rc::new(PresenceHooks {
+                appear,
+                enter: Box::new(enter),
+                leave: Box::new(leave),
+            })),
+        }
+    }
+
+    /// Calls the `enter` hook for a node that was added.
+    pub fn enter(&self, node: &web_sys::Node) {
+        if let Some(hooks) = &self.hooks {
+            (hooks.enter)(node);
+        }
+    }
+
+    /// Calls the `enter` hook for a node that was there when the component was mounted, if the
+    /// handle was created with `appear` set.
+    pub fn appear(&self, node: &web_sys::Node) {
+        if let Some(hooks) = &self.hooks
+            && hooks.appear
+        {
+            (hooks.enter)(node);
+        }
+    }
+
+    /// Calls the `leave` hook for a node that is removed. `remove` should remove the node from the
+    /// DOM. It is called right away if there is no hook.
+    pub fn leave(&self, node: &web_sys::Node, remove: impl FnOnce() + 'static) {
+        match &self.hooks {
+            Some(hooks) => (hooks.leave)(node, Box::new(remove)),
+            None => remove(),
+        }
+    }
+}
+
+/// Returns the [`PresenceHandle`] that the nodes of the current component should be animated
+/// with, if any.
+pub fn use_presence() -> Option<PresenceHandle> {
+    try_use_context::<PresenceHandle>().filter(|presence| presence.hooks.is_some())
+}
+
+/// Runs `f` in a new scope that does not pass `presence` on to nested [`Show`] and [`Keyed`]
+/// components.
+pub(crate) fn without_presence<U>(presence: &Option<PresenceHandle>, f: impl FnOnce() -> U) -> U {
+    if presence.is_some() {
+        provide_context_in_new_scope(PresenceHandle::default(), f)
+    } else {
+        f()
+    }
+}
@@ -0,0 +1,204 @@
+//! Scoped CSS, which is created with the [`css!`](sycamore_macro::css) macro.
+
+use sycamore_macro::component;
+
+use crate::*;
+
+/// CSS whose selectors only match the elements of the views that it is applied to.
+///
+/// This is created with the [`css!`](sycamore_macro::css) macro and applied to a view with a
+/// `#![css(...)]` attribute at the start of the `view!` macro.
+///
+/// The CSS is added to the document the first time that it is used. When rendering on the server,
+/// the CSS is collected instead and rendered by [`ScopedStyles`].
+#[derive(Clone)]
+pub struct ScopedCss {
+    attribute: &'static str,
+    css: &'static str,
+    vars: Vec<(&'static str, StringAttribute)>,
+}
+
+impl ScopedCss {
+    /// Creates scoped CSS from CSS whose selectors are already scoped with `attribute`. Use the
+    /// [`css!`](sycamore_macro::css) macro instead.
+    #[doc(hidden)]
+    pub fn new(
+        attribute: &'static str,
+        css: &'static str,
+        vars: Vec<(&'static str, StringAttribute)>,
+    ) -> Self {
+        Self {
+            attribute,
+            css,
+            vars,
+        }
+    }
+
+    /// The attribute that is added to the elements that the CSS applies to, e.g.
+    /// `data-css-1a2b3c4d`.
+    pub fn attribute(&self) -> &'static str {
+        self.attribute
+    }
+
+    /// The CSS with the scoped selectors.
+    pub fn css(&self) -> &'static str {
+        self.css
+    }
+
+    /// Adds the CSS to the document if it was not already added and returns the
+    /// [`attribute`](Self::attribute). This is called by the `view!` macro.
+    pub fn register(&self) -> &'static str {
+        is_ssr! {
+            if let Some(registry) = try_use_context::<CssRegistry>() {
+                registry.add(self.attribute, self.css);
+            }
+        }
+        is_not_ssr! {
+            inject(self.attribute, self.css);
+        }
+        self.attribute
+    }
+
+    /// The custom properties of the variables that are interpolated with `$name`, with their
+    /// values.
+    pub(crate) fn vars(&self) -> &[(&'static str, StringAttribute)] {
+        &self.vars
+    }
+}
+
+/// The attribute of the `<style>` elements of scoped CSS. This is set to the attribute of the
+/// [`ScopedCss`] so that the styles are only added once.
+const STYLE_ATTRIBUTE: &str = "data-scoped-css";
+
+/// Adds a `<style>` element with `css` to the `<head>` of the document, unless this was already
+/// done or the styles were rendered on the server.
+#[cfg_not_ssr]
+fn inject(attribute: &'static str, css: &'static str) {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+
+    thread_local! {
+        static INJECTED: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+    if !INJECTED.with(|injected| injected.borrow_mut().insert(attribute)) {
+        return;
+    }
+
+    let head = document().head().unwrap_throw();
+    let selector = format!("style[{STYLE_ATTRIBUTE}=\"{attribute}\"]");
+    if head.query_selector(&selector).unwrap_throw().is_some() {
+        return;
+    }
+    let style = document().create_element("style").unwrap_throw();
+    style
+        .set_attribute(STYLE_ATTRIBUTE, attribute)
+        .unwrap_throw();
+    style.set_text_content(Some(css));
+    head.append_child(&style).unwrap_throw();
+}
+
+/// The scoped CSS that is used while rendering on the server, in order of first use.
+#[cfg_ssr]
+#[derive(Clone, Copy)]
+pub(crate) struct CssRegistry(Signal<Vec<(&'static str, &'static str)>>);
+
+#[cfg_ssr]
+impl CssRegistry {
+    pub fn new() -> Self {
+        Self(create_signal(Vec::new()))
+    }
+
+    fn add(self, attribute: &'static str, css: &'static str) {
+        if !self
+            .0
+            .with_untracked(|styles| styles.iter().any(|(other, _)| *other == attribute))
+        {
+            self.0.update(|styles| styles.push((attribute, css)));
+        }
+    }
+}
+
+/// Component that renders the scoped CSS that is used by the page in `<style>` elements. Should
+/// be rendered into the `<head>` of the document.
+///
+/// Without this component, the scoped CSS is only added once the page is hydrated. The CSS that is
+/// used after this component is rendered is also included, since the component is updated until
+/// the page is rendered to a string. This does not include the CSS that is only used inside of
+/// suspense boundaries that are streamed.
+#[component]
+pub fn ScopedStyles() -> View {
+    is_ssr! {
+        let Some(registry) = try_use_context::<CssRegistry>() else {
+            return View::new();
+        };
+        View::from_dynamic(move || {
+            // The styles are not hydrated since they are updated after the initial render.
+            let is_hydrating = IS_HYDRATING.replace(false);
+            let styles = registry.0.with(|styles| {
+                styles
+                    .iter()
+                    .map(|&(attribute, css)| {
+                        View::from(
+                            tags::style()
+                                .attr(STYLE_ATTRIBUTE, attribute)
+                                .dangerously_set_inner_html(css),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            });
+            IS_HYDRATING.set(is_hydrating);
+            View::from(styles)
+        })
+    }
+    is_not_ssr! {
+        View::new()
+    }
+}
+
+#[cfg(test)]
+#[cfg_ssr]
+mod tests {
+    use expect_test::expect;
+    use sycamore_macro::{css, view};
+
+    use super::*;
+
+    #[component(inline_props)]
+    fn Card(children: Children) -> View {
+        let styles = css!(".card { padding: 1em; }");
+        view! {
+            #![css(styles)]
+            div(class="card") { (children) }
+        }
+    }
+
+    #[component]
+    fn Page() -> View {
+        let color = create_signal("red");
+        let styles = css!("p { color: $color; }");
+        view! {
+            #![css(styles)]
+            main {
+                Card {
+                    p { "Inside" }
+                }
+                Card {}
+            }
+        }
+    }
+
+    #[test]
+    fn render_scoped_css() {
+        let html = render_to_string(|| {
+            view! {
+                head {
+                    ScopedStyles {}
+                }
+                body {
+                    Page {}
+                }
+            }
+        });
+        expect![[r#"<head data-hk="0.0"><!--/--><style data-scoped-css="data-css-cf959f0a">p[data-css-cf959f0a]{color: var(--color);}</style><style data-scoped-css="data-css-0ae5ec40">.card[data-css-0ae5ec40]{padding: 1em;}</style><!--/--></head><body data-hk="0.1"><main style="--color: red;" data-css-cf959f0a data-hk="0.2"><div class="card" data-css-0ae5ec40 data-hk="0.3"><p data-css-cf959f0a data-hk="0.4">Inside</p></div><div class="card" data-css-0ae5ec40 data-hk="0.5"></div></main></body>"#]].assert_eq(&html);
+    }
+}
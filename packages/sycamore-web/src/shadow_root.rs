@@ -0,0 +1,103 @@
+//! Definition for the [`ShadowRoot`] component.
+
+use sycamore_macro::component;
+
+use crate::*;
+
+/// Renders its children into an open shadow root that is attached to the parent element. The
+/// styles inside the shadow root do not leak out and the styles of the page do not leak in.
+///
+/// In SSR mode, the children are rendered inside a `<template shadowrootmode="open">` element
+/// (declarative shadow DOM), so that the browser attaches the shadow root while parsing the page
+/// and the encapsulated content is visible before the app is hydrated. When hydrating, the children
+/// are hydrated inside this existing shadow root.
+///
+/// An element can only have a single shadow root, so `ShadowRoot` should not be used more than once
+/// in the same parent element. The shadow root is emptied when the component is destroyed.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::ShadowRoot;
+/// #[component]
+/// fn Card() -> View {
+///     view! {
+///         div {
+///             ShadowRoot {
+///                 style { "p { color: red; }" }
+///                 p { "Only this paragraph is red." }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component(inline_props)]
+pub fn ShadowRoot(children: Children) -> View {
+    if is_ssr!() {
+        let template = tags::template()
+            .attr("shadowrootmode", "open")
+            .children(children);
+        // The marker is used to find the parent element on the client.
+        return View::from((template, HtmlNode::create_marker_node()));
+    }
+
+    let hydrating = is_hydrating();
+    if hydrating {
+        // The `<template>` element has already been replaced by the shadow root in the browser,
+        // so skip its hydration key.
+        let _ = use_context::<HydrationRegistry>().next_key();
+    }
+    // Create the children now so that they are hydrated in the same order as they were rendered
+    // on the server. They are appended once the parent element is known.
+    let children = children.call();
+    let marker = HtmlNode::create_marker_node();
+    let marker_node = marker.as_web_sys().clone();
+
+    on_mount(move || {
+        let Some(host) = marker_node.parent_element() else {
+            console_warn!("`ShadowRoot` has no parent element, shadow root will not be rendered");
+            return;
+        };
+        let shadow_root = host.shadow_root().unwrap_or_else(|| {
+            let init = web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open);
+            host.attach_shadow(&init).unwrap_throw()
+        });
+
+        let mut parent = HtmlNode::from_web_sys(shadow_root.clone().into());
+        let prev = IS_HYDRATING.replace(hydrating);
+        for node in children.nodes {
+            parent.append_child(node);
+        }
+        IS_HYDRATING.set(prev);
+
+        on_cleanup(move || {
+            while let Some(child) = shadow_root.first_child() {
+                shadow_root.remove_child(&child).unwrap_throw();
+            }
+        });
+    });
+    marker.into()
+}
+
+#[cfg(test)]
+#[cfg_ssr]
+mod tests {
+    use expect_test::expect;
+
+    use super::*;
+
+    #[test]
+    fn render_declarative_shadow_root() {
+        let html = render_to_string(|| {
+            view! {
+                div(id="host") {
+                    ShadowRoot {
+                        p { "Shadow" }
+                    }
+                    span { "Light" }
+                }
+            }
+        });
+        expect![[r#"<div id="host" data-hk="0.0"><template shadowrootmode="open" data-hk="0.1"><p data-hk="0.2">Shadow</p></template><!--/--><span data-hk="0.3">Light</span></div>"#]].assert_eq(&html);
+    }
+}
@@ -0,0 +1,115 @@
+//! Static HTML templates that are cloned instead of creating every node one at a time. This is
+//! used by the `view!` macro.
+
+use std::cell::OnceCell;
+use std::fmt;
+
+use crate::*;
+
+/// The static HTML skeleton of a view.
+///
+/// The HTML is parsed into a `<template>` element the first time it is needed. Every instance of
+/// the view is then created by cloning the parsed nodes, after which the dynamic parts (attributes,
+/// event handlers, child views, ...) are filled in.
+///
+/// Templates are only used when rendering to the DOM. In SSR mode and while hydrating,
+/// [`Template::instantiate`] returns `None` and the elements should be created using the element
+/// builders instead. In SSR mode, the static parts of element trees can be pre-rendered to HTML at
+/// compile time and turned into views with [`Template::prerendered`].
+pub struct Template {
+    html: &'static str,
+    content: OnceCell<web_sys::Node>,
+}
+
+impl fmt::Debug for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Template")
+            .field("html", &self.html)
+            .finish()
+    }
+}
+
+impl Template {
+    /// Create a new template from a string of HTML. The HTML should contain exactly one root
+    /// node.
+    pub const fn new(html: &'static str) -> Self {
+        Self {
+            html,
+            content: OnceCell::new(),
+        }
+    }
+
+    /// Returns whether templates can be used. This is only the case when rendering to the DOM
+    /// without hydrating.
+    #[inline]
+    pub fn is_enabled() -> bool {
+        if is_ssr!() {
+            false
+        } else if cfg!(feature = "hydrate") {
+            !is_hydrating()
+        } else {
+            true
+        }
+    }
+
+    /// Returns a new copy of the root node of the template, or `None` if templates cannot be used.
+    pub fn instantiate(&self) -> Option<web_sys::Node> {
+        if !Self::is_enabled() {
+            return None;
+        }
+        let content = self.content.get_or_init(|| {
+            let template = document()
+                .create_element("template")
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlTemplateElement>();
+            template.set_inner_html(self.html);
+            template
+                .content()
+                .first_child()
+                .expect("template should not be empty")
+        });
+        Some(content.clone_node_with_deep(true).unwrap())
+    }
+
+    /// Wraps a node of an instantiated template into the element type that is created by `tag`.
+    /// This allows setting attributes on the node in the same way as with the element builders.
+    pub fn element<T: FromHtmlNode>(_tag: fn() -> T, node: web_sys::Node) -> T {
+        T::from_html_node(HtmlNode::from_web_sys(node))
+    }
+
+    /// Returns a view of pre-rendered HTML in SSR mode, or `None` otherwise. The HTML is a static
+    /// part of an element tree and the dynamic parts are rendered as views in between.
+    ///
+    /// `chunks` is the HTML split at the places where the hydration keys of the elements are
+    /// inserted, so there is one more chunk than there are elements.
+    #[cfg_ssr]
+    pub fn prerendered(chunks: &'static [&'static str]) -> Option<View> {
+        let key = IS_HYDRATING.get().then(|| {
+            let reg: HydrationRegistry = use_context();
+            reg.next_keys(chunks.len() as u32 - 1)
+        });
+        Some(View::from_node(SsrNode::Static { chunks, key }))
+    }
+
+    /// Returns a view of pre-rendered HTML in SSR mode, or `None` otherwise.
+    #[cfg_not_ssr]
+    #[inline]
+    pub fn prerendered(_chunks: &'static [&'static str]) -> Option<View> {
+        None
+    }
+
+    /// Replaces the `marker` node of an instantiated template with `view`.
+    pub fn insert(marker: web_sys::Node, view: impl Into<View>) {
+        let parent = marker.parent_node().unwrap();
+        for node in view.into().as_web_sys() {
+            parent.insert_before(&node, Some(&marker)).unwrap();
+        }
+        parent.remove_child(&marker).unwrap();
+    }
+}
+
+/// Implemented for element types that can be created from an existing [`HtmlNode`].
+pub trait FromHtmlNode {
+    /// Wrap an existing [`HtmlNode`]. The node must be an element of the right type.
+    fn from_html_node(node: HtmlNode) -> Self;
+}
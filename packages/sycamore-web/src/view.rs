@@ -3,7 +3,7 @@
 use std::fmt;
 
 use smallvec::{SmallVec, smallvec};
-use sycamore_core::Children;
+use sycamore_core::{Children, ChildrenFn};
 
 use crate::*;
 
@@ -77,6 +77,12 @@ impl<T> From<Children<Self>> for View<T> {
     }
 }
 
+impl<T> From<ChildrenFn<Self>> for View<T> {
+    fn from(children: ChildrenFn<Self>) -> Self {
+        children.call()
+    }
+}
+
 impl<T> From<Vec<View<T>>> for View<T> {
     fn from(nodes: Vec<View<T>>) -> Self {
         View {
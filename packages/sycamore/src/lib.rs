@@ -7,9 +7,16 @@
 //!
 //! ## Feature Flags
 //!
+//! - `check-nesting` - Makes it an error to nest elements in a way that is not allowed by HTML in
+//!   the `view!` and `html!` macros, such as a `div` inside of a `p`. The browser changes the
+//!   nesting of such elements when it parses the HTML, which breaks hydration.
+//!
 //! - `hydrate` - Enables hydration support in DOM nodes. By default, hydration is disabled to
 //!   reduce binary size.
 //!
+//! - `hot-reload` - Registers components so that they can be replaced by a dev server while the
+//!   app is running. This should only be enabled in development.
+//!
 //! - `serde` - Enables serializing and deserializing `Signal`s and other wrapper types using
 //!   `serde`.
 //!
@@ -19,6 +26,11 @@
 //! - `nightly` - Enables nightly-only features. This makes it slightly more ergonomic to use
 //!   signals.
 //!
+//! - `template` - Makes the `view!` and `html!` macros create elements by cloning a `<template>`
+//!   of the static HTML of every element tree instead of creating every element one at a time.
+//!   This is only used when rendering to the DOM. SSR and hydration always use the element
+//!   builders.
+//!
 //! - `wasm-bindgen-interning` (_default_) - Enables interning for `wasm-bindgen` strings. This
 //!   improves performance at a slight cost in binary size. If you want to minimize the size of the
 //!   result `.wasm` binary, you might want to disable this.
@@ -67,11 +79,12 @@ pub mod futures {
     pub use sycamore_futures::*;
 }
 
-#[cfg(feature = "hydrate")]
-pub use sycamore_web::{hydrate, hydrate_in_scope, hydrate_to};
 pub use sycamore_web::{
-    render, render_in_scope, render_to, render_to_string, render_to_string_in_scope,
+    delegate_events, render, render_in_scope, render_to, render_to_string,
+    render_to_string_in_scope,
 };
+#[cfg(feature = "hydrate")]
+pub use sycamore_web::{hydrate, hydrate_in_scope, hydrate_to};
 #[cfg(feature = "suspense")]
 pub use sycamore_web::{render_to_string_await_suspense, render_to_string_stream};
 
@@ -93,9 +106,9 @@ pub mod prelude {
     pub use sycamore_web::tags::svg_attributes::*;
     #[cfg(feature = "web")]
     pub use sycamore_web::{
-        Attributes, Children, GlobalAttributes, GlobalProps, HtmlGlobalAttributes, Indexed, Keyed,
-        NodeRef, SvgGlobalAttributes, View, console_dbg, console_log, create_node_ref, document,
-        is_not_ssr, is_ssr, on_mount, window,
+        Attributes, Children, ChildrenFn, GlobalAttributes, GlobalProps, HtmlGlobalAttributes,
+        Indexed, Keyed, NodeRef, SvgGlobalAttributes, View, console_dbg, console_log,
+        create_node_ref, document, is_not_ssr, is_ssr, on_mount, window,
     };
 
     pub use crate::reactive::*;
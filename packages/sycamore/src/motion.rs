@@ -1,8 +1,13 @@
 //! Utilities for smooth transitions and animations.
 
+#[cfg(feature = "web")]
+mod presence;
+
 use std::cell::OnceCell;
 use std::rc::Rc;
 
+#[cfg(feature = "web")]
+pub use self::presence::*;
 use crate::reactive::*;
 
 /// Type returned by [`create_raf`] and [`create_raf_loop`].
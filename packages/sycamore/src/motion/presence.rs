@@ -0,0 +1,354 @@
+//! Enter and leave animations for DOM nodes.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use wasm_bindgen::prelude::*;
+use web_sys::js_sys;
+
+use crate::reactive::*;
+use crate::web::*;
+use crate::{Props, component};
+
+#[wasm_bindgen]
+extern "C" {
+    /// [`web_sys::Element`] with the methods of the Web Animations API, which are unstable in
+    /// `web-sys`.
+    #[wasm_bindgen(extends = web_sys::Element)]
+    type AnimatableElement;
+    #[wasm_bindgen(method)]
+    fn animate(
+        this: &AnimatableElement,
+        keyframes: &js_sys::Array,
+        options: &js_sys::Object,
+    ) -> web_sys::Animation;
+    #[wasm_bindgen(method, js_name = "getAnimations")]
+    fn get_animations(this: &AnimatableElement) -> js_sys::Array;
+}
+
+/// An animation that is played by [`Presence`] when an element enters or leaves the DOM.
+#[derive(Clone, Debug)]
+pub struct Animation(AnimationKind);
+
+#[derive(Clone, Debug)]
+enum AnimationKind {
+    Class(String),
+    Keyframes(Keyframes),
+}
+
+impl Animation {
+    /// An animation that adds `class` to the element and removes it once all the CSS animations and
+    /// transitions of the element have finished. The class should start a CSS animation, e.g.
+    /// `.fade-in { animation: fade-in 200ms ease-out; }`.
+    pub fn class(class: impl Into<String>) -> Self {
+        Self(AnimationKind::Class(class.into()))
+    }
+
+    /// Plays the animation on `element` and calls `done` once it has finished. If `leave` is
+    /// `true`, the final state of the animation is kept until `done` has been called.
+    ///
+    /// Returns a function that cancels the animation.
+    fn play(
+        &self,
+        element: &web_sys::Element,
+        leave: bool,
+        done: impl FnOnce() + 'static,
+    ) -> Box<dyn Fn()> {
+        let animatable = element.unchecked_ref::<AnimatableElement>();
+        match &self.0 {
+            AnimationKind::Class(class) => {
+                element.class_list().add_1(class).unwrap_throw();
+                // This flushes the styles, so it includes the animations started by the class.
+                let animations = animatable.get_animations();
+                let remove_class = {
+                    let element = element.clone();
+                    let class = class.clone();
+                    move || element.class_list().remove_1(&class).unwrap_throw()
+                };
+                wait_for_animations(animations, {
+                    let remove_class = remove_class.clone();
+                    move || {
+                        done();
+                        remove_class();
+                    }
+                });
+                Box::new(remove_class)
+            }
+            AnimationKind::Keyframes(keyframes) => {
+                let options = js_sys::Object::new();
+                let set = |key: &str, value: JsValue| {
+                    js_sys::Reflect::set(&options, &key.into(), &value).unwrap_throw();
+                };
+                set("duration", keyframes.duration_ms.into());
+                set("easing", keyframes.easing.as_str().into());
+                if leave {
+                    set("fill", "forwards".into());
+                }
+                let animation = animatable.animate(&keyframes.to_js(), &options);
+                let animations = js_sys::Array::new();
+                animations.push(&animation);
+                wait_for_animations(animations, {
+                    let animation = animation.clone();
+                    move || {
+                        done();
+                        // Drop the final state of the animation, since `Show` keeps the element
+                        // around and might show it again.
+                        if leave {
+                            animation.cancel();
+                        }
+                    }
+                });
+                Box::new(move || animation.cancel())
+            }
+        }
+    }
+}
+
+impl From<Keyframes> for Animation {
+    fn from(keyframes: Keyframes) -> Self {
+        Self(AnimationKind::Keyframes(keyframes))
+    }
+}
+
+/// An animation between keyframes that is played with the Web Animations API.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use sycamore::easing;
+/// # use sycamore::motion::Keyframes;
+/// let fade_in = Keyframes::new(Duration::from_millis(200))
+///     .frame([("opacity", "0"), ("transform", "translateY(-8px)")])
+///     .frame([("opacity", "1"), ("transform", "none")])
+///     .easing(easing::cubic_out);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Keyframes {
+    frames: Vec<Vec<(String, String)>>,
+    duration_ms: f64,
+    easing: String,
+}
+
+impl Keyframes {
+    /// Creates an animation without any frames that lasts for `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            frames: Vec::new(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            easing: "linear".to_string(),
+        }
+    }
+
+    /// Adds a frame with the given CSS properties. The frames are evenly spaced over the duration
+    /// of the animation.
+    ///
+    /// The properties can be written in kebab-case (`background-color`) or in camelCase
+    /// (`backgroundColor`).
+    pub fn frame<K, V>(mut self, properties: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let frame = properties
+            .into_iter()
+            .map(|(property, value)| (to_camel_case(property.as_ref()), value.into()))
+            .collect();
+        self.frames.push(frame);
+        self
+    }
+
+    /// Sets the easing function of the animation, e.g. one of the functions in
+    /// [`easing`](crate::easing). Defaults to [`linear`](crate::easing::linear).
+    ///
+    /// The function is sampled into a CSS `linear()` easing function.
+    pub fn easing(mut self, easing: impl Fn(f32) -> f32) -> Self {
+        self.easing = css_easing(easing);
+        self
+    }
+
+    fn to_js(&self) -> js_sys::Array {
+        let frames = js_sys::Array::new();
+        for frame in &self.frames {
+            let object = js_sys::Object::new();
+            for (property, value) in frame {
+                js_sys::Reflect::set(&object, &property.into(), &value.into()).unwrap_throw();
+            }
+            frames.push(&object);
+        }
+        frames
+    }
+}
+
+/// Converts a CSS property to the camelCase name that is used by the Web Animations API. Custom
+/// properties are left as is.
+fn to_camel_case(property: &str) -> String {
+    if property.starts_with("--") {
+        return property.to_string();
+    }
+    let mut parts = property.split('-');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+/// Samples `easing` into a CSS `linear()` easing function.
+fn css_easing(easing: impl Fn(f32) -> f32) -> String {
+    const SAMPLES: u32 = 40;
+    let points = (0..=SAMPLES)
+        .map(|i| {
+            let value = easing(i as f32 / SAMPLES as f32);
+            format!("{}", (value * 1000.0).round() / 1000.0)
+        })
+        .collect::<Vec<_>>();
+    format!("linear({})", points.join(", "))
+}
+
+/// Calls `done` once all the `web_sys::Animation`s in `animations` have finished or have been
+/// canceled.
+fn wait_for_animations(animations: js_sys::Array, done: impl FnOnce() + 'static) {
+    let remaining = Rc::new(Cell::new(animations.length()));
+    if remaining.get() == 0 {
+        done();
+        return;
+    }
+    let mut done = Some(done);
+    let callback = Closure::<dyn FnMut()>::new(move || {
+        remaining.set(remaining.get().saturating_sub(1));
+        if remaining.get() == 0
+            && let Some(done) = done.take()
+        {
+            done();
+        }
+    })
+    .into_js_value();
+    for animation in animations.iter() {
+        let animation = animation.unchecked_into::<web_sys::Animation>();
+        animation.set_onfinish(Some(callback.unchecked_ref()));
+        animation.set_oncancel(Some(callback.unchecked_ref()));
+    }
+}
+
+/// Props for [`Presence`].
+#[derive(Props, Debug)]
+pub struct PresenceProps {
+    /// The animation that is played when an element is added.
+    #[prop(setter(into))]
+    enter: Option<Animation>,
+    /// The animation that is played when an element is removed. The element is only removed once
+    /// the animation has finished.
+    #[prop(setter(into))]
+    leave: Option<Animation>,
+    /// Whether to also play the enter animation for the elements that are there when the component
+    /// is mounted.
+    #[prop(default)]
+    appear: bool,
+    children: Children,
+}
+
+/// Animates the elements that are added and removed by the [`Show`] and [`Keyed`] components in
+/// its children.
+///
+/// `Presence` provides a [`PresenceHandle`] to its children. The closest [`Show`] or [`Keyed`]
+/// inside of it plays the enter animation on the top-level elements that it adds and keeps the
+/// elements that it removes in the DOM until their leave animation has finished. The elements
+/// that are rendered by the children of the `Show` or the items of the `Keyed` are not animated
+/// themselves. If an element is shown again while it is leaving, the leave animation is canceled.
+///
+/// Nothing is animated when rendering on the server.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use sycamore::prelude::*;
+/// # use sycamore::motion::{Animation, Keyframes, Presence};
+/// # use sycamore::web::Show;
+/// # fn App() -> View {
+/// let visible = create_signal(true);
+/// view! {
+///     Presence(
+///         enter=Animation::class("fade-in"),
+///         leave=Keyframes::new(Duration::from_millis(200))
+///             .frame([("opacity", "1")])
+///             .frame([("opacity", "0")]),
+///     ) {
+///         Show(when=visible) {
+///             p { "Hello!" }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Presence(props: PresenceProps) -> View {
+    let PresenceProps {
+        enter,
+        leave,
+        appear,
+        children,
+    } = props;
+
+    if is_ssr!() {
+        return children.call();
+    }
+
+    // The elements that are leaving, along with a function that cancels their leave animation.
+    let leaving = Rc::new(RefCell::new(Vec::<(web_sys::Element, Box<dyn Fn()>)>::new()));
+    let cancel_leave = {
+        let leaving = Rc::clone(&leaving);
+        move |element: &web_sys::Element| {
+            let canceled = {
+                let mut leaving = leaving.borrow_mut();
+                let index = leaving.iter().position(|(leaving, _)| leaving == element);
+                index.map(|index| leaving.remove(index))
+            };
+            if let Some((_, cancel)) = canceled {
+                cancel();
+            }
+        }
+    };
+
+    let handle = PresenceHandle::new(
+        appear,
+        move |node| {
+            let Some(element) = node.dyn_ref::<web_sys::Element>() else {
+                return;
+            };
+            cancel_leave(element);
+            if let Some(enter) = &enter {
+                let _ = enter.play(element, false, || {});
+            }
+        },
+        move |node, remove| {
+            let (Some(leave), Some(element)) = (&leave, node.dyn_ref::<web_sys::Element>()) else {
+                remove();
+                return;
+            };
+            let finished = Rc::new(Cell::new(false));
+            let done = {
+                let leaving = Rc::clone(&leaving);
+                let element = element.clone();
+                let finished = Rc::clone(&finished);
+                move || {
+                    finished.set(true);
+                    leaving
+                        .borrow_mut()
+                        .retain(|(leaving, _)| *leaving != element);
+                    remove();
+                }
+            };
+            let cancel = leave.play(element, true, done);
+            // The animation might have finished right away, e.g. if there is no CSS for a class.
+            if !finished.get() {
+                leaving.borrow_mut().push((element.clone(), cancel));
+            }
+        },
+    );
+    provide_context_in_new_scope(handle, || children.call())
+}
@@ -56,3 +56,28 @@ fn component_cleanup_on_root_destroyed() {
 
     assert_cleanup_called(move || root.dispose());
 }
+
+mod actions {
+    pub fn title(el: web_sys::Element, title: &'static str) -> impl FnOnce() {
+        el.set_attribute("title", title).unwrap();
+        move || el.remove_attribute("title").unwrap()
+    }
+}
+
+#[wasm_bindgen_test]
+pub async fn test_cleanup_of_action() {
+    let root = create_root(|| {
+        sycamore::render_in_scope(
+            || view! { div(use:actions::title="Title") },
+            &test_container(),
+        );
+    });
+    let el = query("div");
+    // Actions run once the element is mounted.
+    assert_eq!(el.get_attribute("title"), None);
+    tick().await;
+    assert_eq!(el.get_attribute("title").as_deref(), Some("Title"));
+
+    root.dispose();
+    assert_eq!(el.get_attribute("title"), None);
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use sycamore::motion::{Keyframes, Presence};
+use sycamore::web::Show;
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn show_without_presence() {
+    let _ = create_root(|| {
+        let visible = create_signal(true);
+        let text = create_signal("Hello");
+        let node = view! {
+            Show(when=visible) {
+                p { (text.get()) }
+            }
+        };
+        let c = test_container();
+        sycamore::render_in_scope(|| node, &c);
+
+        let p = query("p");
+        visible.set(false);
+        assert_eq!(c.query_selector_all("p").unwrap().length(), 0);
+
+        // The hidden children are still updated.
+        text.set("World");
+        visible.set(true);
+        assert_eq!(query("p"), p);
+        assert_text_content!(c, "World");
+    });
+}
+
+#[wasm_bindgen_test]
+async fn show_toggled_quickly_while_leaving() {
+    let c = test_container();
+
+    let mut visible = None;
+    let root = create_root(|| {
+        let signal = create_signal(true);
+        visible = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Presence(
+                        leave=Keyframes::new(Duration::from_secs(60))
+                            .frame([("opacity", "1")])
+                            .frame([("opacity", "0")]),
+                    ) {
+                        Show(when=signal) {
+                            p { "Hello" }
+                        }
+                    }
+                }
+            },
+            &c,
+        );
+    });
+    let visible = visible.unwrap();
+    tick().await;
+    let p = query("p");
+
+    // The leave animation that was started before the children were shown again must not hide
+    // them when it finishes.
+    visible.set(false);
+    visible.set(true);
+    tick().await;
+    finish_animations();
+    next_frame().await;
+    assert_eq!(c.query_selector_all("p").unwrap().length(), 1);
+    assert_eq!(query("p"), p);
+
+    // Only the last leave animation hides the children.
+    visible.set(false);
+    visible.set(true);
+    visible.set(false);
+    tick().await;
+    assert_eq!(query("p"), p);
+    finish_animations();
+    next_frame().await;
+    assert_eq!(c.query_selector_all("p").unwrap().length(), 0);
+
+    visible.set(true);
+    tick().await;
+    assert_eq!(query("p"), p);
+    assert_text_content!(c, "Hello");
+
+    root.dispose();
+}
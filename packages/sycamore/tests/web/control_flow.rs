@@ -0,0 +1,180 @@
+use super::*;
+
+#[wasm_bindgen_test]
+fn if_else() {
+    let _ = create_root(|| {
+        let count = create_signal(0);
+        let node = view! {
+            div {
+                if count.get() == 0 {
+                    "Zero"
+                } else if count.get() < 0 {
+                    "Negative"
+                } else {
+                    "Positive"
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let div = query("div");
+        assert_text_content!(div, "Zero");
+
+        count.set(-1);
+        assert_text_content!(div, "Negative");
+
+        count.set(1);
+        assert_text_content!(div, "Positive");
+    });
+}
+
+#[wasm_bindgen_test]
+fn if_branch_is_not_recreated() {
+    let _ = create_root(|| {
+        let count = create_signal(1);
+        let created = create_signal(0);
+        let node = view! {
+            div {
+                if count.get() > 0 {
+                    ({ created.set(created.get_untracked() + 1); "Positive" })
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let div = query("div");
+        assert_text_content!(div, "Positive");
+        assert_eq!(created.get(), 1);
+
+        count.set(2);
+        assert_text_content!(div, "Positive");
+        assert_eq!(created.get(), 1);
+
+        count.set(0);
+        assert_text_content!(div, "");
+
+        count.set(3);
+        assert_text_content!(div, "Positive");
+        assert_eq!(created.get(), 2);
+    });
+}
+
+#[wasm_bindgen_test]
+fn match_arms() {
+    let _ = create_root(|| {
+        let value = create_signal(None);
+        let node = view! {
+            div {
+                match value.get() {
+                    Some(n) if n > 9 => { "Big " (n) }
+                    Some(n) => { "Small " (n) }
+                    None => { "Nothing" }
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let div = query("div");
+        assert_text_content!(div, "Nothing");
+
+        value.set(Some(1));
+        assert_text_content!(div, "Small 1");
+
+        value.set(Some(10));
+        assert_text_content!(div, "Big 10");
+    });
+}
+
+#[wasm_bindgen_test]
+fn match_arm_bindings_are_updated() {
+    let _ = create_root(|| {
+        let value = create_signal(Some(1));
+        let node = view! {
+            div {
+                match value.get() {
+                    Some(n) => { "Some " (n) }
+                    None => { "Nothing" }
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let div = query("div");
+        assert_text_content!(div, "Some 1");
+
+        // The same arm matches, but with a different binding.
+        value.set(Some(2));
+        assert_text_content!(div, "Some 2");
+    });
+}
+
+#[wasm_bindgen_test]
+fn if_let() {
+    let _ = create_root(|| {
+        let value = create_signal(Some(1));
+        let node = view! {
+            div {
+                if let Some(n) = value.get() {
+                    "Some " (n)
+                } else {
+                    "Nothing"
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let div = query("div");
+        assert_text_content!(div, "Some 1");
+
+        value.set(Some(2));
+        assert_text_content!(div, "Some 2");
+
+        value.set(None);
+        assert_text_content!(div, "Nothing");
+    });
+}
+
+#[wasm_bindgen_test]
+fn for_keyed() {
+    let _ = create_root(|| {
+        let items = create_signal(vec![1, 2]);
+        let node = view! {
+            ul {
+                for item in items.get_clone() key(*item) {
+                    li { (item) }
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let ul = query("ul");
+        assert_text_content!(ul, "12");
+
+        items.update(|items| items.push(3));
+        assert_text_content!(ul, "123");
+
+        items.update(|items| items.swap(0, 2));
+        assert_text_content!(ul, "321");
+    });
+}
+
+#[wasm_bindgen_test]
+fn for_indexed() {
+    let _ = create_root(|| {
+        let items = create_signal(vec!["a", "b"]);
+        let node = view! {
+            ul {
+                for item in items {
+                    li { (item) }
+                }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let ul = query("ul");
+        assert_text_content!(ul, "ab");
+
+        items.update(|items| items.remove(0));
+        assert_text_content!(ul, "b");
+    });
+}
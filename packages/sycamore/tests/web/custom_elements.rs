@@ -0,0 +1,79 @@
+use sycamore::web::{WebComponent, define_custom_element};
+
+use super::*;
+
+#[derive(Props, CustomElementProps)]
+struct GreetingProps {
+    name: ReadSignal<Option<String>>,
+    show_title: ReadSignal<bool>,
+}
+
+#[component]
+fn Greeting(props: GreetingProps) -> View {
+    let name = props.name;
+    view! {
+        (if props.show_title.get() {
+            view! { h1 { "Title" } }
+        } else {
+            view! {}
+        })
+        p { "Hello " (name.get_clone().unwrap_or_default()) }
+    }
+}
+
+struct GreetingElement;
+
+impl WebComponent for GreetingElement {
+    type Props = GreetingProps;
+    const STYLES: &'static str = "p { color: red; }";
+
+    fn render(props: GreetingProps) -> View {
+        view! { Greeting(name=props.name, show_title=props.show_title) }
+    }
+}
+
+struct LightGreeting;
+
+impl WebComponent for LightGreeting {
+    type Props = ();
+    const SHADOW_ROOT: bool = false;
+
+    fn render(_: ()) -> View {
+        view! { p { "Hello" } }
+    }
+}
+
+#[wasm_bindgen_test]
+fn custom_element_renders_into_shadow_root() {
+    define_custom_element::<GreetingElement>("test-greeting");
+    test_container().set_inner_html(r#"<test-greeting name="World"></test-greeting>"#);
+
+    let el = query("test-greeting");
+    let shadow = el.shadow_root().unwrap();
+    let style = shadow.query_selector("style").unwrap().unwrap();
+    assert_text_content!(style, "p { color: red; }");
+    let p = shadow.query_selector("p").unwrap().unwrap();
+    assert_text_content!(p, "Hello World");
+
+    el.set_attribute("name", "Sycamore").unwrap();
+    assert_text_content!(p, "Hello Sycamore");
+
+    // Nodes that are added after the first render are removed as well.
+    el.set_attribute("show-title", "").unwrap();
+    assert!(shadow.query_selector("h1").unwrap().is_some());
+    el.remove();
+    assert_eq!(shadow.child_nodes().length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn custom_element_without_shadow_root() {
+    define_custom_element::<LightGreeting>("test-light-greeting");
+    test_container().set_inner_html("<test-light-greeting></test-light-greeting>");
+
+    let el = query("test-light-greeting");
+    assert!(el.shadow_root().is_none());
+    assert_text_content!(el, "Hello");
+
+    el.remove();
+    assert_eq!(el.child_nodes().length(), 0);
+}
@@ -0,0 +1,114 @@
+use web_sys::EventInit;
+
+use super::*;
+
+/// An event that is not used by the other tests, since the test container stays delegated.
+const EVENT: &str = "auxclick";
+
+fn dispatch(selectors: &str) {
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let ev = Event::new_with_event_init_dict(EVENT, &init).unwrap();
+    query(selectors).dispatch_event(&ev).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn delegated_handlers_are_called_from_target_upwards() {
+    let _ = create_root(|| {
+        let root = test_container();
+        sycamore::delegate_events(&root, &[EVENT]);
+
+        let calls = create_signal(Vec::new());
+        let node = view! {
+            div(on:auxclick=move |ev: web_sys::MouseEvent| {
+                assert_eq!(ev.current_target().unwrap().unchecked_into::<Element>().tag_name(), "DIV");
+                calls.update(|calls| calls.push("div"));
+            }) {
+                button(on:auxclick=move |_| calls.update(|calls| calls.push("button"))) { "Click" }
+            }
+        };
+        sycamore::render_in_scope(|| node, &root);
+
+        dispatch("button");
+        assert_eq!(calls.get_clone(), ["button", "div"]);
+    });
+}
+
+#[wasm_bindgen_test]
+fn delegated_stop_propagation() {
+    let _ = create_root(|| {
+        let root = test_container();
+        sycamore::delegate_events(&root, &[EVENT]);
+
+        let calls = create_signal(Vec::new());
+        let node = view! {
+            div(on:auxclick=move |_| calls.update(|calls| calls.push("div"))) {
+                button(on:auxclick=move |ev: web_sys::MouseEvent| {
+                    ev.stop_propagation();
+                    calls.update(|calls| calls.push("button"));
+                }) { "Click" }
+            }
+        };
+        sycamore::render_in_scope(|| node, &root);
+
+        dispatch("button");
+        assert_eq!(calls.get_clone(), ["button"]);
+    });
+}
+
+#[wasm_bindgen_test]
+fn delegated_handlers_are_removed_on_cleanup() {
+    let root = test_container();
+    sycamore::delegate_events(&root, &[EVENT]);
+
+    let calls = create_signal(0);
+    let handle = create_root(|| {
+        let node = view! {
+            button(on:auxclick=move |_| calls.set(calls.get() + 1)) { "Click" }
+        };
+        sycamore::render_in_scope(|| node, &root);
+    });
+
+    dispatch("button");
+    assert_eq!(calls.get(), 1);
+
+    // The button is still in the DOM but its handler should be removed.
+    handle.dispose();
+    dispatch("button");
+    assert_eq!(calls.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn delegation_only_applies_to_its_root() {
+    let container = test_container();
+    let delegated = document().create_element("div").unwrap();
+    let other = document().create_element("div").unwrap();
+    container.append_child(&delegated).unwrap();
+    container.append_child(&other).unwrap();
+    sycamore::delegate_events(&delegated, &[EVENT]);
+
+    let calls = create_signal(Vec::new());
+    let handle = create_root(|| {
+        sycamore::render_in_scope(
+            || view! { button(id="delegated", on:auxclick=move |_| calls.update(|calls| calls.push("delegated"))) },
+            &delegated,
+        );
+    });
+    let _ = create_root(|| {
+        sycamore::render_in_scope(
+            || view! { button(id="other", on:auxclick=move |_| calls.update(|calls| calls.push("other"))) },
+            &other,
+        );
+    });
+
+    // The handler in the other root is a native listener, so it is called even though the event
+    // does not bubble up to the delegation root.
+    dispatch("#other");
+    dispatch("#delegated");
+    assert_eq!(calls.get_clone(), ["other", "delegated"]);
+
+    // The listener on the delegation root is removed with its scope.
+    handle.dispose();
+    dispatch("#delegated");
+    assert_eq!(calls.get_clone(), ["other", "delegated"]);
+}
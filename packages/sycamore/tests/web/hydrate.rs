@@ -1,6 +1,6 @@
 use expect_test::{Expect, expect};
-use sycamore::web::Portal2;
 use sycamore::web::tags::*;
+use sycamore::web::{Portal2, ShadowRoot};
 
 use super::*;
 
@@ -301,3 +301,46 @@ mod portal {
         });
     }
 }
+
+mod shadow_root {
+    use super::*;
+    fn v(state: ReadSignal<i32>) -> View {
+        view! {
+            div {
+                ShadowRoot {
+                    p { (state.get()) }
+                }
+                span { "Light" }
+            }
+        }
+    }
+    static EXPECT: Expect = expect![[
+        r#"<div data-hk="0.0"><template shadowrootmode="open" data-hk="0.1"><p data-hk="0.2"><!--/-->0<!--/--></p></template><!--/--><span data-hk="0.3">Light</span></div>"#
+    ]];
+    #[test]
+    fn ssr() {
+        check(|| v(*create_signal(0)), &EXPECT);
+    }
+    #[wasm_bindgen_test]
+    async fn test() {
+        let c = test_container();
+        set_html_unsafe(&c, EXPECT.data());
+        let shadow = query("div").shadow_root().unwrap();
+        let p = shadow.query_selector("p").unwrap().unwrap();
+
+        let mut state = None;
+        let _ = create_root(|| {
+            let signal = create_signal(0);
+            state = Some(signal);
+            sycamore::hydrate_in_scope(|| v(*signal), &c);
+        });
+        let state = state.unwrap();
+        tick().await;
+
+        // The existing nodes are hydrated instead of being rendered again.
+        assert_eq!(shadow.query_selector("p").unwrap().unwrap(), p);
+        assert_text_content!(p, "0");
+        state.set(1);
+        assert_text_content!(p, "1");
+    }
+}
@@ -0,0 +1,36 @@
+use sycamore::web::{events, use_document_event, use_window_event};
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn window_and_document_listeners_are_removed_on_cleanup() {
+    let window_events = create_signal(0);
+    let document_events = create_signal(0);
+    let root = create_root(|| {
+        use_window_event(events::resize, move |_| {
+            window_events.set(window_events.get() + 1)
+        });
+        use_document_event(events::visibilitychange, move |_| {
+            document_events.set(document_events.get() + 1)
+        });
+    });
+
+    window()
+        .dispatch_event(&Event::new("resize").unwrap())
+        .unwrap();
+    document()
+        .dispatch_event(&Event::new("visibilitychange").unwrap())
+        .unwrap();
+    assert_eq!(window_events.get(), 1);
+    assert_eq!(document_events.get(), 1);
+
+    root.dispose();
+    window()
+        .dispatch_event(&Event::new("resize").unwrap())
+        .unwrap();
+    document()
+        .dispatch_event(&Event::new("visibilitychange").unwrap())
+        .unwrap();
+    assert_eq!(window_events.get(), 1);
+    assert_eq!(document_events.get(), 1);
+}
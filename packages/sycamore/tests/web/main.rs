@@ -1,10 +1,19 @@
 pub mod cleanup;
+pub mod components;
+pub mod control_flow;
+pub mod custom_elements;
+pub mod delegation;
 pub mod hydrate;
 pub mod indexed;
 pub mod keyed;
+pub mod listeners;
 pub mod portal;
+pub mod presence;
 pub mod render;
+pub mod scoped_css;
+pub mod shadow_root;
 pub mod svg;
+pub mod template;
 
 mod utils;
 
@@ -12,7 +21,9 @@ use sycamore::prelude::*;
 use utils::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
-use web_sys::{Element, Event, HtmlElement, HtmlInputElement};
+use web_sys::{
+    Element, Event, HtmlElement, HtmlInputElement, HtmlOptionElement, HtmlSelectElement,
+};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -283,6 +294,53 @@ fn static_property() {
     });
 }
 
+#[wasm_bindgen_test]
+fn class_directive() {
+    let _ = create_root(|| {
+        let active = create_signal(false);
+
+        let node = view! {
+            span(class:active=active.get(), class="item", class:is-static=true)
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let span = query("span");
+
+        assert_eq!(span.class_name(), "item is-static");
+
+        active.set(true);
+        assert_eq!(span.class_name(), "item is-static active");
+
+        active.set(false);
+        assert_eq!(span.class_name(), "item is-static");
+    });
+}
+
+#[wasm_bindgen_test]
+fn style_directive() {
+    let _ = create_root(|| {
+        let color = create_signal(Some("red"));
+
+        let node = view! {
+            span(style:color=color.get(), style="display: block", style:font-weight="bold")
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let span = query("span");
+
+        assert_eq!(
+            span.get_attribute("style").unwrap(),
+            "display: block; font-weight: bold; color: red;"
+        );
+
+        color.set(None);
+        assert_eq!(
+            span.get_attribute("style").unwrap(),
+            "display: block; font-weight: bold;"
+        );
+    });
+}
+
 #[wasm_bindgen_test]
 fn two_way_bind_to_props() {
     let _ = create_root(|| {
@@ -327,6 +385,165 @@ fn two_way_bind_to_value_as_number() {
     });
 }
 
+#[wasm_bindgen_test]
+fn two_way_bind_to_select_multiple() {
+    let _ = create_root(|| {
+        let selected = create_signal(vec!["b".to_string()]);
+
+        let node = view! {
+            select(multiple=true, bind:value=selected) {
+                option(value="a") { "A" }
+                option(value="b") { "B" }
+                option(value="c") { "C" }
+            }
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let select: HtmlSelectElement = query_into("select");
+        let option = |i| {
+            select
+                .item(i)
+                .unwrap()
+                .unchecked_into::<HtmlOptionElement>()
+        };
+        assert!(!option(0).selected());
+        assert!(option(1).selected());
+
+        selected.set(vec!["a".to_string(), "c".to_string()]);
+        assert!(option(0).selected());
+        assert!(!option(1).selected());
+        assert!(option(2).selected());
+
+        option(0).set_selected(false);
+        select
+            .dispatch_event(&Event::new("change").unwrap())
+            .unwrap();
+        assert_eq!(selected.get_clone(), vec!["c".to_string()]);
+    });
+}
+
+#[wasm_bindgen_test]
+fn two_way_bind_to_radio_group() {
+    let _ = create_root(|| {
+        let color = create_signal("red".to_string());
+
+        // The value must be read after it is set, even if `bind:group` comes first.
+        let node = view! {
+            input(r#type="radio", name="color", bind:group=color, value="red")
+            input(r#type="radio", name="color", bind:group=color, value="blue")
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let red: HtmlInputElement = query_into("input[value=red]");
+        let blue: HtmlInputElement = query_into("input[value=blue]");
+        assert!(red.checked());
+        assert!(!blue.checked());
+
+        color.set("blue".to_string());
+        assert!(!red.checked());
+        assert!(blue.checked());
+
+        red.click();
+        assert_eq!(color.get_clone(), "red");
+    });
+}
+
+#[wasm_bindgen_test]
+fn two_way_bind_to_checkbox_group() {
+    let _ = create_root(|| {
+        let numbers = create_signal(vec![2]);
+
+        let node = view! {
+            input(r#type="checkbox", value="1", bind:group=numbers)
+            input(r#type="checkbox", value="2", bind:group=numbers)
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let one: HtmlInputElement = query_into("input[value='1']");
+        let two: HtmlInputElement = query_into("input[value='2']");
+        assert!(!one.checked());
+        assert!(two.checked());
+
+        one.click();
+        assert_eq!(numbers.get_clone(), vec![2, 1]);
+
+        two.click();
+        assert_eq!(numbers.get_clone(), vec![1]);
+
+        numbers.set(vec![]);
+        assert!(!one.checked());
+    });
+}
+
+#[wasm_bindgen_test]
+fn two_way_bind_to_text_content() {
+    let _ = create_root(|| {
+        let text = create_signal("Hello".to_string());
+
+        let node = view! {
+            div(contenteditable="true", bind:textContent=text)
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let div = query("div");
+        assert_text_content!(div, "Hello");
+
+        div.set_text_content(Some("World"));
+        div.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(text.get_clone(), "World");
+    });
+}
+
+#[wasm_bindgen_test]
+fn two_way_bind_to_text_content_keeps_children_when_typing() {
+    let _ = create_root(|| {
+        let text = create_signal("Hello".to_string());
+
+        let node = view! {
+            div(contenteditable="true", bind:textContent=text)
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+        let div = query("div");
+        let text_node = div.first_child().unwrap();
+
+        // Typing changes the existing text node instead of replacing it.
+        text_node.set_node_value(Some("Hello!"));
+        div.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(text.get_clone(), "Hello!");
+        assert!(div.first_child().unwrap().is_same_node(Some(&text_node)));
+
+        text.set("Bye".to_string());
+        assert_text_content!(div, "Bye");
+    });
+}
+
+#[wasm_bindgen_test]
+fn event_modifiers() {
+    let _ = create_root(|| {
+        let div_clicks = create_signal(0);
+        let button_clicks = create_signal(0);
+
+        let node = view! {
+            div(on:click.self_only=move |_| div_clicks.set(div_clicks.get() + 1)) {
+                button(on:click.once=move |_| button_clicks.set(button_clicks.get() + 1)) {
+                    "Click"
+                }
+            }
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+
+        query_into::<HtmlElement>("button").click();
+        query_into::<HtmlElement>("button").click();
+        assert_eq!(button_clicks.get(), 1);
+        assert_eq!(div_clicks.get(), 0);
+
+        query_into::<HtmlElement>("div").click();
+        assert_eq!(div_clicks.get(), 1);
+    });
+}
+
 #[wasm_bindgen_test]
 fn noderefs() {
     let _ = create_root(|| {
@@ -344,6 +561,43 @@ fn noderefs() {
     });
 }
 
+#[wasm_bindgen_test]
+fn typed_noderefs() {
+    let _ = create_root(|| {
+        let input_ref = create_node_ref::<HtmlInputElement>();
+        let untyped_ref: NodeRef = create_node_ref();
+        let node = view! {
+            input(r#ref=input_ref, value="Hello")
+            p(r#ref=untyped_ref)
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+
+        assert_eq!(input_ref.get().value(), "Hello");
+        assert_eq!(
+            untyped_ref.get(),
+            query("p").unchecked_into::<web_sys::Node>()
+        );
+        // The node is a `<p>`, not an `<input>`.
+        assert!(untyped_ref.cast::<HtmlInputElement>().try_get().is_none());
+    });
+}
+
+#[wasm_bindgen_test]
+fn noderef_type_is_inferred_from_element() {
+    let _ = create_root(|| {
+        let input_ref = create_node_ref();
+        let node = view! {
+            input(r#ref=input_ref, value="Hello")
+        };
+
+        sycamore::render_in_scope(|| node, &test_container());
+
+        // `input_ref` is a `NodeRef<HtmlInputElement>` without any annotation.
+        assert_eq!(input_ref.get().value(), "Hello");
+    });
+}
+
 #[wasm_bindgen_test]
 fn noderef_reactivity_test() {
     let _ = create_root(|| {
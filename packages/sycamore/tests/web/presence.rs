@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use sycamore::motion::{Animation, Keyframes, Presence};
+use sycamore::web::{Keyed, Show};
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn presence_keeps_element_until_leave_animation_finishes() {
+    let c = test_container();
+
+    let mut visible = None;
+    let root = create_root(|| {
+        let signal = create_signal(true);
+        visible = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Presence(
+                        enter=Animation::class("entering"),
+                        leave=Keyframes::new(Duration::from_secs(60))
+                            .frame([("opacity", "1")])
+                            .frame([("opacity", "0")]),
+                    ) {
+                        Show(when=signal) {
+                            p { "Hello" }
+                        }
+                    }
+                }
+            },
+            &c,
+        );
+    });
+    let visible = visible.unwrap();
+    tick().await;
+
+    let p = query("p");
+    visible.set(false);
+    tick().await;
+    // The paragraph itself is left in place while it leaves.
+    assert_eq!(c.query_selector_all("p").unwrap().length(), 1);
+    assert_eq!(query("p"), p);
+
+    finish_animations();
+    next_frame().await;
+    assert_eq!(c.query_selector_all("p").unwrap().length(), 0);
+
+    visible.set(true);
+    tick().await;
+    // There is no CSS for the class, so it is removed right away.
+    let p = query("p");
+    assert!(!p.class_list().contains("entering"));
+
+    root.dispose();
+}
+
+#[wasm_bindgen_test]
+async fn presence_cancels_leave_when_shown_again() {
+    let c = test_container();
+
+    let mut visible = None;
+    let root = create_root(|| {
+        let signal = create_signal(true);
+        visible = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Presence(
+                        leave=Keyframes::new(Duration::from_secs(60))
+                            .frame([("opacity", "1")])
+                            .frame([("opacity", "0")]),
+                    ) {
+                        Show(when=signal) {
+                            p { "Hello" }
+                        }
+                    }
+                }
+            },
+            &c,
+        );
+    });
+    let visible = visible.unwrap();
+    tick().await;
+
+    let p = query("p");
+    visible.set(false);
+    tick().await;
+    visible.set(true);
+    tick().await;
+    next_frame().await;
+    assert_eq!(c.query_selector_all("p").unwrap().length(), 1);
+    assert_eq!(query("p"), p);
+    assert_text_content!(c, "Hello");
+
+    root.dispose();
+}
+
+#[wasm_bindgen_test]
+async fn presence_keyed_reordering() {
+    let c = test_container();
+
+    let mut list = None;
+    let root = create_root(|| {
+        let signal = create_signal(vec![1, 2, 3]);
+        list = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    ul {
+                        Presence(
+                            leave=Keyframes::new(Duration::from_secs(60))
+                                .frame([("opacity", "1")])
+                                .frame([("opacity", "0")]),
+                        ) {
+                            Keyed(
+                                list=signal,
+                                view=|x| view! { li { (x) } },
+                                key=|x| *x,
+                            )
+                        }
+                    }
+                }
+            },
+            &c,
+        );
+    });
+    let list = list.unwrap();
+    tick().await;
+    let item_2 = c.query_selector("li:nth-child(2)").unwrap().unwrap();
+
+    // Moving items around does not play the leave animation.
+    list.set(vec![3, 2, 1]);
+    tick().await;
+    assert_text_content!(query("ul"), "321");
+    finish_animations();
+    next_frame().await;
+    assert_text_content!(query("ul"), "321");
+
+    // A removed item stays after the item that was in front of it while it leaves.
+    list.set(vec![1, 3]);
+    tick().await;
+    assert_text_content!(query("ul"), "132");
+    list.set(vec![3, 1]);
+    tick().await;
+    assert_text_content!(query("ul"), "321");
+    assert_eq!(
+        c.query_selector("li:nth-child(2)").unwrap().unwrap(),
+        item_2
+    );
+
+    finish_animations();
+    next_frame().await;
+    assert_text_content!(query("ul"), "31");
+
+    root.dispose();
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[component(inline_props)]
+fn Scoped(color: ReadSignal<&'static str>) -> View {
+    let styles = css!("p { color: $color; }");
+    view! {
+        #![css(styles)]
+        div {
+            p { "Scoped" }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn scoped_css_is_added_once_and_applied_to_elements() {
+    let c = test_container();
+
+    let mut color = None;
+    let root = create_root(|| {
+        let signal = create_signal("red");
+        color = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Scoped(color=*signal)
+                    Scoped(color=*signal)
+                }
+            },
+            &c,
+        );
+    });
+    let color = color.unwrap();
+
+    let attribute = css!("p { color: $color; }").attribute();
+    let styles = document()
+        .head()
+        .unwrap()
+        .query_selector_all(&format!("style[data-scoped-css=\"{attribute}\"]"))
+        .unwrap();
+    assert_eq!(styles.length(), 1);
+    assert!(query("p").has_attribute(attribute));
+
+    let div = query("div");
+    assert_eq!(div.get_attribute("style").as_deref(), Some("--color: red;"));
+    color.set("blue");
+    assert_eq!(
+        div.get_attribute("style").as_deref(),
+        Some("--color: blue;")
+    );
+
+    root.dispose();
+}
@@ -0,0 +1,42 @@
+use sycamore::web::ShadowRoot;
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn shadow_root_renders_children_into_shadow_root() {
+    let c = test_container();
+
+    let mut count = None;
+    let root = create_root(|| {
+        let signal = create_signal(0);
+        count = Some(signal);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    div {
+                        ShadowRoot {
+                            style { "p { color: red; }" }
+                            p { (signal.get()) }
+                        }
+                        span { "Light" }
+                    }
+                }
+            },
+            &c,
+        );
+    });
+    let count = count.unwrap();
+    tick().await;
+
+    let host = query("div");
+    let shadow = host.shadow_root().unwrap();
+    assert_eq!(host.inner_html(), "<!----><span>Light</span>");
+    let p = shadow.query_selector("p").unwrap().unwrap();
+    assert_text_content!(p, "0");
+
+    count.set(1);
+    assert_text_content!(p, "1");
+
+    root.dispose();
+    assert_eq!(shadow.child_nodes().length(), 0);
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+#[wasm_bindgen_test]
+fn static_template() {
+    sycamore::render_to(
+        || {
+            view! {
+                div(class="container", data-test="static") {
+                    h1 { "Hello " "World!" }
+                    p { "Text" br {} "More text" }
+                }
+            }
+        },
+        &test_container(),
+    );
+    assert_eq!(
+        query("div").outer_html(),
+        r#"<div class="container" data-test="static"><h1>Hello World!</h1><p>Text<br>More text</p></div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn template_with_dynamic_parts() {
+    let _ = create_root(|| {
+        let count = create_signal(0);
+        let id = create_signal("a");
+        let node = view! {
+            div {
+                span(id=id.get()) { "Count: " (count.get()) "!" }
+                button(on:click=move |_| count.set(count.get() + 1)) { "Increment" }
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        assert_text_content!(query("span"), "Count: 0!");
+        assert_eq!(query("span").id(), "a");
+
+        query_into::<HtmlElement>("button").click();
+        assert_text_content!(query("span"), "Count: 1!");
+
+        id.set("b");
+        assert_eq!(query("span").id(), "b");
+    });
+}
+
+#[wasm_bindgen_test]
+fn template_instances_are_independent() {
+    let _ = create_root(|| {
+        let item = |text: &'static str| {
+            view! {
+                li(class="item") { "Item " (text) }
+            }
+        };
+        let node = view! {
+            ul {
+                (item("a"))
+                (item("b"))
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        assert_eq!(
+            query("ul").inner_html(),
+            r#"<li class="item">Item a</li><li class="item">Item b</li>"#
+        );
+    });
+}
+
+// The view is rejected at compile time when nesting is checked.
+#[cfg(not(feature = "check-nesting"))]
+#[wasm_bindgen_test]
+fn template_with_nested_elements_that_cannot_be_parsed() {
+    sycamore::render_to(
+        || {
+            view! {
+                p { "Before" div { "Block" } "After" }
+            }
+        },
+        &test_container(),
+    );
+    assert_eq!(
+        query("p").outer_html(),
+        "<p>Before<div>Block</div>After</p>"
+    );
+}
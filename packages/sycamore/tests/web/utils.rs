@@ -1,5 +1,6 @@
 use sycamore::prelude::*;
 use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::js_sys;
 use web_sys::{Element, HtmlElement};
 
 /// Query the `Document` for the first `Element` that matches the selectors.
@@ -36,6 +37,55 @@ pub(crate) fn query_into<T: AsRef<HtmlElement> + JsCast>(selectors: &str) -> T {
         .expect("element found should be of the same type as used for the generic T")
 }
 
+/// Waits until the microtasks that are already queued, e.g. `on_mount` callbacks, have run.
+///
+/// This is a test utility function only!
+pub(crate) async fn tick() {
+    let promise = js_sys::Promise::resolve(&wasm_bindgen::JsValue::UNDEFINED);
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
+
+/// Waits until the next animation frame, after the events of the finished animations have been
+/// dispatched.
+///
+/// This is a test utility function only!
+pub(crate) async fn next_frame() {
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        window().request_animation_frame(&resolve).unwrap();
+    });
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
+
+/// Finishes all the animations that are running in the document.
+///
+/// This is a test utility function only!
+pub(crate) fn finish_animations() {
+    let get_animations = js_sys::Reflect::get(&document(), &"getAnimations".into()).unwrap();
+    let animations = get_animations
+        .unchecked_into::<js_sys::Function>()
+        .call0(&document())
+        .unwrap()
+        .unchecked_into::<js_sys::Array>();
+    for animation in animations.iter() {
+        animation
+            .unchecked_into::<web_sys::Animation>()
+            .finish()
+            .unwrap();
+    }
+}
+
+/// Sets the HTML of `element` with `setHTMLUnsafe`, which, unlike `innerHTML`, attaches the
+/// declarative shadow roots in `html`.
+///
+/// This is a test utility function only!
+pub(crate) fn set_html_unsafe(element: &Element, html: &str) {
+    let set_html_unsafe = js_sys::Reflect::get(element, &"setHTMLUnsafe".into()).unwrap();
+    set_html_unsafe
+        .unchecked_into::<js_sys::Function>()
+        .call1(element, &html.into())
+        .unwrap();
+}
+
 /// Asserts that the text content of a `web_sys::Node` is equal to the
 /// right expression.
 macro_rules! assert_text_content {
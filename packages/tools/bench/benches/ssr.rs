@@ -17,6 +17,43 @@ pub fn bench(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("ssr_static", |b| {
+        b.iter(|| {
+            #[component]
+            fn App() -> View {
+                view! {
+                    header(class="header") {
+                        nav {
+                            ul(class="nav-links") {
+                                li { a(href="/") { "Home" } }
+                                li { a(href="/docs") { "Docs" } }
+                                li { a(href="/blog") { "Blog" } }
+                            }
+                        }
+                    }
+                    main(class="content") {
+                        h1 { "A mostly static page" }
+                        p {
+                            "Most of this page does not depend on any "
+                            strong { "reactive state" }
+                            " and can be rendered ahead of time."
+                        }
+                        ul {
+                            li { "First" }
+                            li { "Second" }
+                            li { "Third" }
+                        }
+                    }
+                    footer(class="footer") {
+                        p { "Copyright" }
+                    }
+                }
+            }
+
+            let _ssr = sycamore::render_to_string(App);
+        })
+    });
+
     c.bench_function("ssr_medium", |b| {
         b.iter(|| {
             #[component(inline_props)]